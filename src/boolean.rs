@@ -0,0 +1,57 @@
+/// Textual or numeric representation used by a data source to store boolean values in a column
+/// which is not of a native boolean type. E.g. `CHAR(1)` with `'Y'` and `'N'`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoolEncoding {
+    /// `1` represents `true` and `0` represents `false`. Works for integer columns (e.g. `TINYINT`)
+    /// as well as for text columns.
+    ZeroOne,
+    /// `Y` represents `true` and `N` represents `false`.
+    YN,
+    /// `T` represents `true` and `F` represents `false`.
+    TF,
+    /// `true` represents `true` and `false` represents `false`.
+    TrueFalse,
+}
+
+impl BoolEncoding {
+    /// Maximum length of the textual representation of a value in bytes.
+    pub(crate) fn max_str_len(self) -> usize {
+        match self {
+            BoolEncoding::ZeroOne | BoolEncoding::YN | BoolEncoding::TF => 1,
+            BoolEncoding::TrueFalse => 5,
+        }
+    }
+
+    /// Textual representation of the boolean value.
+    pub(crate) fn repr(self, value: bool) -> &'static str {
+        match (self, value) {
+            (BoolEncoding::ZeroOne, true) => "1",
+            (BoolEncoding::ZeroOne, false) => "0",
+            (BoolEncoding::YN, true) => "Y",
+            (BoolEncoding::YN, false) => "N",
+            (BoolEncoding::TF, true) => "T",
+            (BoolEncoding::TF, false) => "F",
+            (BoolEncoding::TrueFalse, true) => "true",
+            (BoolEncoding::TrueFalse, false) => "false",
+        }
+    }
+
+    /// Interprets the text as a boolean value. Surrounding whitespaces are ignored and the
+    /// comparison is case insensitive. `None` if the text is not a valid representation.
+    pub(crate) fn parse(self, text: &[u8]) -> Option<bool> {
+        let text = text.trim_ascii();
+        let (true_repr, false_repr): (&[u8], &[u8]) = match self {
+            BoolEncoding::ZeroOne => (b"1", b"0"),
+            BoolEncoding::YN => (b"Y", b"N"),
+            BoolEncoding::TF => (b"T", b"F"),
+            BoolEncoding::TrueFalse => (b"TRUE", b"FALSE"),
+        };
+        if text.eq_ignore_ascii_case(true_repr) {
+            Some(true)
+        } else if text.eq_ignore_ascii_case(false_repr) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+}
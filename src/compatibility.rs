@@ -0,0 +1,133 @@
+use arrow::datatypes::{DataType, Schema};
+use odbc_api::Connection;
+
+use crate::{arrow_schema_from, odbc_writer::quote_table_name, Error};
+
+/// A difference between an arrow schema and the columns of a table, which could cause inserting
+/// arrow batches into that table to fail. See [`check_insert_compatibility`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Incompatibility {
+    /// The arrow schema contains a field, for which there is no column in the table.
+    MissingColumn(String),
+    /// The table contains a column, which is not part of the arrow schema. Inserting succeeds
+    /// only if the column is nullable or has a default value.
+    ExtraColumn(String),
+    /// The arrow data type of the field is unlikely to be convertible into the type of the table
+    /// column.
+    TypeMismatch {
+        /// Name of the column in the table
+        column: String,
+        /// Data type of the field in the arrow schema
+        arrow: DataType,
+        /// Arrow data type inferred from the relational type of the table column
+        table: DataType,
+    },
+}
+
+/// Compares an arrow schema against the columns of a table, before inserting into it. The columns
+/// of the table are queried using `SELECT * FROM <table> WHERE 1=0` and mapped to arrow types, the
+/// same way [`arrow_schema_from`] does. Fields and columns are matched by name, ignoring case,
+/// since most databases treat identifiers case insensitive.
+///
+/// Types are compared by category, rather than exactly, since the database converts e.g. a 32 Bit
+/// integer into a `BIGINT` column just fine. Categories are integers, floating point numbers,
+/// decimals, text, binary, booleans, dates, times of day, timestamps and durations. Table columns
+/// inferred as text (this includes types without a native arrow representation) are assumed to be
+/// compatible with any arrow type.
+///
+/// An empty result indicates no incompatibilities have been found. This does not guarantee the
+/// insert to succeed, e.g. values could still be too large for their columns.
+///
+/// The table name may be qualified with a schema, e.g. `dbo.Customers`. Each part of it is wrapped
+/// in double quotes, if it contains characters not valid in an unquoted identifier, so the name can
+/// not alter the query.
+pub fn check_insert_compatibility(
+    connection: &Connection<'_>,
+    schema: &Schema,
+    table_name: &str,
+) -> Result<Vec<Incompatibility>, Error> {
+    let table = quote_table_name(table_name);
+    let sql = format!("SELECT * FROM {table} WHERE 1=0");
+    let mut cursor = connection
+        .execute(&sql, ())
+        .map_err(|source| Error::Execute {
+            source,
+            sql: sql.clone(),
+        })?
+        .ok_or(Error::NoResultSet { sql })?;
+    let table_schema = arrow_schema_from(&mut cursor, false)?;
+
+    let mut incompatibilities = Vec::new();
+    for field in schema.fields() {
+        let column = table_schema
+            .fields()
+            .iter()
+            .find(|column| column.name().eq_ignore_ascii_case(field.name()));
+        match column {
+            None => incompatibilities.push(Incompatibility::MissingColumn(field.name().clone())),
+            Some(column) if !is_convertible(field.data_type(), column.data_type()) => {
+                incompatibilities.push(Incompatibility::TypeMismatch {
+                    column: column.name().clone(),
+                    arrow: field.data_type().clone(),
+                    table: column.data_type().clone(),
+                })
+            }
+            Some(_) => (),
+        }
+    }
+    for column in table_schema.fields() {
+        let is_in_schema = schema
+            .fields()
+            .iter()
+            .any(|field| field.name().eq_ignore_ascii_case(column.name()));
+        if !is_in_schema {
+            incompatibilities.push(Incompatibility::ExtraColumn(column.name().clone()));
+        }
+    }
+    Ok(incompatibilities)
+}
+
+/// `true` if values of the arrow type can be inserted into a column, whose relational type maps to
+/// `table`.
+fn is_convertible(arrow: &DataType, table: &DataType) -> bool {
+    matches!(table, DataType::Utf8) || category(arrow).is_some_and(|c| Some(c) == category(table))
+}
+
+/// Groups data types which the database is able to convert into each other.
+#[derive(PartialEq, Eq)]
+enum Category {
+    Integer,
+    Float,
+    Decimal,
+    Text,
+    Binary,
+    Boolean,
+    Date,
+    Time,
+    Timestamp,
+    Duration,
+}
+
+fn category(data_type: &DataType) -> Option<Category> {
+    let category = match data_type {
+        DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64 => Category::Integer,
+        DataType::Float16 | DataType::Float32 | DataType::Float64 => Category::Float,
+        DataType::Decimal128(..) | DataType::Decimal256(..) => Category::Decimal,
+        DataType::Utf8 | DataType::LargeUtf8 => Category::Text,
+        DataType::Binary | DataType::LargeBinary | DataType::FixedSizeBinary(_) => Category::Binary,
+        DataType::Boolean => Category::Boolean,
+        DataType::Date32 | DataType::Date64 => Category::Date,
+        DataType::Time32(_) | DataType::Time64(_) => Category::Time,
+        DataType::Timestamp(..) => Category::Timestamp,
+        DataType::Duration(_) => Category::Duration,
+        _ => return None,
+    };
+    Some(category)
+}
@@ -6,7 +6,7 @@ use arrow::{
         ArrowPrimitiveType, Time32MillisecondType, Time64MicrosecondType, Time64NanosecondType,
     },
 };
-use chrono::{DateTime, Datelike, NaiveDate, Timelike};
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, Timelike};
 use odbc_api::{
     buffers::{AnySliceMut, BufferDesc, TextColumnSliceMut},
     sys::{Date, Time, Timestamp},
@@ -14,59 +14,73 @@ use odbc_api::{
 
 use crate::{odbc_writer::WriteStrategy, reader::MappingError, WriterError};
 
-/// Transform date to days since unix epoch as i32
-pub fn days_since_epoch(date: &Date) -> i32 {
+/// Transform date to days since unix epoch as i32. Fails for dates which do not exist in the
+/// calendar, like the zero date `0000-00-00` permitted by MySQL.
+pub fn days_since_epoch(date: &Date) -> Result<i32, MappingError> {
     let unix_epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
-    let date =
-        NaiveDate::from_ymd_opt(date.year as i32, date.month as u32, date.day as u32).unwrap();
-    let duration = date.signed_duration_since(unix_epoch);
-    duration.num_days().try_into().unwrap()
+    let naive_date = naive_date(date.year, date.month, date.day)?;
+    let duration = naive_date.signed_duration_since(unix_epoch);
+    Ok(duration.num_days().try_into().unwrap())
 }
 
-pub fn seconds_since_epoch(from: &Timestamp) -> i64 {
-    let ndt = NaiveDate::from_ymd_opt(from.year as i32, from.month as u32, from.day as u32)
-        .unwrap()
+/// Fails for dates which do not exist in the calendar, like the zero date `0000-00-00`.
+fn naive_date(year: i16, month: u16, day: u16) -> Result<NaiveDate, MappingError> {
+    NaiveDate::from_ymd_opt(year as i32, month as u32, day as u32)
+        .ok_or(MappingError::InvalidDate { year, month, day })
+}
+
+/// Fails for invalid times of day, e.g. with more than 23 hours.
+fn invalid_time(from: &Timestamp) -> MappingError {
+    MappingError::InvalidTime {
+        value: format!("{:02}:{:02}:{:02}", from.hour, from.minute, from.second),
+    }
+}
+
+/// Fails for timestamps with the same dates as [`days_since_epoch`], e.g. the zero timestamp
+/// `0000-00-00 00:00:00` permitted by MySQL.
+pub fn seconds_since_epoch(from: &Timestamp) -> Result<i64, MappingError> {
+    let ndt = naive_date(from.year, from.month, from.day)?
         .and_hms_opt(from.hour as u32, from.minute as u32, from.second as u32)
-        .unwrap();
-    ndt.and_utc().timestamp()
+        .ok_or_else(|| invalid_time(from))?;
+    Ok(ndt.and_utc().timestamp())
 }
 
-pub fn ms_since_epoch(from: &Timestamp) -> i64 {
-    let ndt = NaiveDate::from_ymd_opt(from.year as i32, from.month as u32, from.day as u32)
-        .unwrap()
+/// Interprets `fraction` strictly as nanoseconds, as demanded by the ODBC specification. Fails for
+/// fractions of a full second or more, which some drivers return if they use a different unit.
+/// Chrono would accept these as leap seconds, shifting the result by orders of magnitude. Fails
+/// also for the same dates as [`days_since_epoch`].
+fn naive_date_time(from: &Timestamp) -> Result<NaiveDateTime, MappingError> {
+    if from.fraction >= 1_000_000_000 {
+        return Err(MappingError::InvalidTimestampFraction {
+            fraction: from.fraction,
+        });
+    }
+    naive_date(from.year, from.month, from.day)?
         .and_hms_nano_opt(
             from.hour as u32,
             from.minute as u32,
             from.second as u32,
             from.fraction,
         )
-        .unwrap();
-    ndt.and_utc().timestamp_millis()
+        .ok_or_else(|| invalid_time(from))
 }
 
-pub fn us_since_epoch(from: &Timestamp) -> i64 {
-    let ndt = NaiveDate::from_ymd_opt(from.year as i32, from.month as u32, from.day as u32)
-        .unwrap()
-        .and_hms_nano_opt(
-            from.hour as u32,
-            from.minute as u32,
-            from.second as u32,
-            from.fraction,
-        )
-        .unwrap();
-    ndt.and_utc().timestamp_micros()
+/// Transform date to milliseconds since unix epoch, like arrow `Date64`. Fails for the same dates
+/// as [`days_since_epoch`].
+pub fn date_ms_since_epoch(date: &Date) -> Result<i64, MappingError> {
+    Ok(i64::from(days_since_epoch(date)?) * 86_400_000)
+}
+
+pub fn ms_since_epoch(from: &Timestamp) -> Result<i64, MappingError> {
+    Ok(naive_date_time(from)?.and_utc().timestamp_millis())
+}
+
+pub fn us_since_epoch(from: &Timestamp) -> Result<i64, MappingError> {
+    Ok(naive_date_time(from)?.and_utc().timestamp_micros())
 }
 
 pub fn ns_since_epoch(from: &Timestamp) -> Result<i64, MappingError> {
-    let ndt = NaiveDate::from_ymd_opt(from.year as i32, from.month as u32, from.day as u32)
-        .unwrap()
-        .and_hms_nano_opt(
-            from.hour as u32,
-            from.minute as u32,
-            from.second as u32,
-            from.fraction,
-        )
-        .unwrap();
+    let ndt = naive_date_time(from)?;
 
     // The dates that can be represented as nanoseconds are between 1677-09-21T00:12:44.0 and
     // 2262-04-11T23:47:16.854775804
@@ -246,3 +260,118 @@ where
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use odbc_api::sys::{Date, Timestamp};
+
+    use crate::MappingError;
+
+    use super::{
+        days_since_epoch, ms_since_epoch, ns_since_epoch, seconds_since_epoch, us_since_epoch,
+    };
+
+    fn timestamp(fraction: u32) -> Timestamp {
+        Timestamp {
+            year: 2021,
+            month: 4,
+            day: 9,
+            hour: 18,
+            minute: 57,
+            second: 50,
+            fraction,
+        }
+    }
+
+    #[test]
+    fn fraction_is_interpreted_as_nanoseconds() {
+        let ts = timestamp(123_456_789);
+
+        assert_eq!(1_617_994_670_123, ms_since_epoch(&ts).unwrap());
+        assert_eq!(1_617_994_670_123_456, us_since_epoch(&ts).unwrap());
+        assert_eq!(1_617_994_670_123_456_789, ns_since_epoch(&ts).unwrap());
+    }
+
+    #[test]
+    fn fraction_of_a_full_second_is_invalid() {
+        let ts = timestamp(1_000_000_000);
+
+        for result in [
+            ms_since_epoch(&ts),
+            us_since_epoch(&ts),
+            ns_since_epoch(&ts),
+        ] {
+            assert!(matches!(
+                result,
+                Err(MappingError::InvalidTimestampFraction {
+                    fraction: 1_000_000_000
+                })
+            ));
+        }
+    }
+
+    #[test]
+    fn days_since_epoch_of_valid_date() {
+        let date = Date {
+            year: 1970,
+            month: 1,
+            day: 2,
+        };
+
+        assert_eq!(1, days_since_epoch(&date).unwrap());
+    }
+
+    #[test]
+    fn zero_date_is_invalid() {
+        let date = Date {
+            year: 0,
+            month: 0,
+            day: 0,
+        };
+
+        assert!(matches!(
+            days_since_epoch(&date),
+            Err(MappingError::InvalidDate {
+                year: 0,
+                month: 0,
+                day: 0
+            })
+        ));
+    }
+
+    #[test]
+    fn zero_timestamp_is_invalid() {
+        let ts = Timestamp {
+            year: 0,
+            month: 0,
+            day: 0,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            fraction: 0,
+        };
+
+        assert!(matches!(
+            seconds_since_epoch(&ts),
+            Err(MappingError::InvalidDate {
+                year: 0,
+                month: 0,
+                day: 0
+            })
+        ));
+        for result in [
+            ms_since_epoch(&ts),
+            us_since_epoch(&ts),
+            ns_since_epoch(&ts),
+        ] {
+            assert!(matches!(
+                result,
+                Err(MappingError::InvalidDate {
+                    year: 0,
+                    month: 0,
+                    day: 0
+                })
+            ));
+        }
+    }
+}
@@ -1,19 +1,94 @@
 use arrow::{
     array::{Array, Decimal128Array, Decimal256Array},
-    datatypes::{ArrowPrimitiveType, Decimal256Type},
+    datatypes::{ArrowPrimitiveType, Decimal128Type, Decimal256Type, DecimalType},
 };
 use odbc_api::buffers::{AnySliceMut, BufferDesc};
 
 use crate::{odbc_writer::WriteStrategy, WriterError};
 
+/// What to do with digits of a decimal, which exceed the scale of the target column. See
+/// [`crate::OdbcWriterBuilder::with_decimal_scale`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecimalInsertPolicy {
+    /// Emit [`WriterError::DecimalScaleExceeded`] if any of the exceeding digits is not zero.
+    Error,
+    /// Round to the nearest value. Ties are rounded to the value with an even last digit.
+    RoundHalfEven,
+    /// Discard the exceeding digits, rounding towards zero.
+    Truncate,
+}
+
 pub struct NullableDecimal128AsText {
     precision: u8,
     scale: i8,
+    /// `Some` if values are rounded to a smaller scale before they are written.
+    round_to: Option<(i8, DecimalInsertPolicy)>,
 }
 
 impl NullableDecimal128AsText {
     pub fn new(precision: u8, scale: i8) -> Self {
-        Self { precision, scale }
+        Self {
+            precision,
+            scale,
+            round_to: None,
+        }
+    }
+
+    /// Round values to `scale`, if it is smaller than the scale of the arrow field.
+    pub fn with_rounding(mut self, scale: i8, policy: DecimalInsertPolicy) -> Self {
+        if scale < self.scale {
+            self.round_to = Some((scale, policy));
+        }
+        self
+    }
+
+    /// Scale of the text sent to the data source.
+    fn target_scale(&self) -> i8 {
+        self.round_to.map_or(self.scale, |(scale, _)| scale)
+    }
+
+    /// Value with the scale of the text sent to the data source.
+    fn rescale(&self, value: i128) -> Result<i128, WriterError> {
+        let Some((target_scale, policy)) = self.round_to else {
+            return Ok(value);
+        };
+        let exponent = (self.scale as i32 - target_scale as i32)
+            .try_into()
+            .unwrap();
+        // Should the factor exceed the range of `i128`, every value is smaller than half of it.
+        let (quotient, remainder, is_above_half, is_half) = match 10i128.checked_pow(exponent) {
+            Some(factor) => {
+                let remainder = value % factor;
+                // Compare the remainder with half of the factor, without truncating the half.
+                let twice_remainder = remainder.unsigned_abs() * 2;
+                let factor_abs = factor.unsigned_abs();
+                (
+                    value / factor,
+                    remainder,
+                    twice_remainder > factor_abs,
+                    twice_remainder == factor_abs,
+                )
+            }
+            None => (0, value, false, false),
+        };
+        let rescaled = match policy {
+            DecimalInsertPolicy::Truncate => quotient,
+            DecimalInsertPolicy::Error if remainder != 0 => {
+                return Err(WriterError::DecimalScaleExceeded {
+                    value: Decimal128Type::format_decimal(value, self.precision, self.scale),
+                    scale: target_scale,
+                })
+            }
+            DecimalInsertPolicy::Error => quotient,
+            DecimalInsertPolicy::RoundHalfEven => {
+                if is_above_half || (is_half && quotient % 2 != 0) {
+                    quotient + value.signum()
+                } else {
+                    quotient
+                }
+            }
+        };
+        Ok(rescaled)
     }
 }
 
@@ -43,7 +118,7 @@ fn len_text(scale: i8, precision: u8) -> usize {
 impl WriteStrategy for NullableDecimal128AsText {
     fn buffer_desc(&self) -> BufferDesc {
         BufferDesc::Text {
-            max_str_len: len_text(self.scale, self.precision),
+            max_str_len: len_text(self.target_scale(), self.precision),
         }
     }
 
@@ -53,15 +128,17 @@ impl WriteStrategy for NullableDecimal128AsText {
         column_buf: AnySliceMut<'_>,
         array: &dyn Array,
     ) -> Result<(), WriterError> {
-        let length = len_text(self.scale, self.precision);
+        let scale = self.target_scale();
+        let length = len_text(scale, self.precision);
 
         let from = array.as_any().downcast_ref::<Decimal128Array>().unwrap();
         let mut to = column_buf.as_text_view().unwrap();
 
         for (index, cell) in from.iter().enumerate() {
             if let Some(value) = cell {
+                let value = self.rescale(value)?;
                 let buf = to.set_mut(index + param_offset, length);
-                write_i128_as_decimal(value, self.precision, self.scale, buf)
+                write_i128_as_decimal(value, self.precision, scale, buf)
             } else {
                 to.set_cell(index + param_offset, None)
             }
@@ -1,5 +1,10 @@
+use std::time::Duration;
+
+use arrow::datatypes::DataType;
 use thiserror::Error;
 
+use odbc_api::handles::State;
+
 use crate::reader::ColumnFailure;
 
 /// A variation of things which can go wrong then creating an [`crate::OdbcReader`].
@@ -8,6 +13,49 @@ pub enum Error {
     /// Failure to retrieve the number of columns from the result set.
     #[error("Unable to retrieve number of columns in result set.\n{0}")]
     UnableToRetrieveNumCols(odbc_api::Error),
+    /// Failure to bind the transit buffer to the cursor.
+    #[error("Unable to bind buffer to cursor.\n{0}")]
+    UnableToBindBuffer(odbc_api::Error),
+    /// Failure to execute the SQL statement given to [`crate::read`].
+    #[error("Failed to execute SQL statement. SQL:\n{sql}\n{source}")]
+    Execute {
+        source: odbc_api::Error,
+        sql: String,
+    },
+    /// The SQL statement given to [`crate::read`] did not produce a result set.
+    #[error("SQL statement did not produce a result set. SQL:\n{sql}")]
+    NoResultSet { sql: String },
+    /// Failure to query the name of the database management system from the connection.
+    #[error("Unable to retrieve name of database management system.\n{0}")]
+    UnableToRetrieveDbmsName(odbc_api::Error),
+    /// Failure to set the query timeout of the statement.
+    #[error("Unable to set query timeout.\n{0}")]
+    UnableToSetQueryTimeout(odbc_api::Error),
+    /// The query timeout expired while gathering the metadata required to infer the arrow schema.
+    #[error(
+        "Schema inference did not complete within the query timeout of {timeout:?}. The driver \
+        did not return the metadata of the result set in time.\n{source}"
+    )]
+    InferenceTimeout {
+        timeout: Duration,
+        source: odbc_api::Error,
+    },
+    /// The deadline for reading the entire result set passed, before all batches have been
+    /// fetched. See [`crate::OdbcReaderBuilder::with_deadline`].
+    #[error("Deadline for reading the result set exceeded.")]
+    DeadlineExceeded,
+    /// Failure to advance the cursor to the next result set. E.g. a later statement of a batch
+    /// failed, after the earlier ones succeeded.
+    #[error(
+        "Unable to advance to the result set of statement {statement_index} (zero based) of the \
+        batch.\n{source}"
+    )]
+    MoreResults {
+        /// Index of the statement within the batch, whose result set could not be advanced to.
+        /// `0` refers to the first statement. Statements without result set count, too.
+        statement_index: usize,
+        source: odbc_api::Error,
+    },
     /// Indicates that the error is related to a specify column.
     #[error(
         "There is a problem with the SQL type of the column with name: {} and index {}:\n{source}",
@@ -22,6 +70,64 @@ pub enum Error {
         // Cause of the error
         source: ColumnFailure,
     },
+    /// The result set has more columns than allowed to be bound to the cursor at once. See
+    /// [`crate::OdbcReaderBuilder::with_max_bound_columns`].
+    #[error(
+        "The result set has {num_columns} columns, yet at most {max_bound_columns} columns are \
+        allowed to be bound at once. Fetching columns incrementally is not supported, since the \
+        cursor can only be traversed once and most drivers do not support fetching unbound \
+        columns of block cursors. Consider splitting the query into multiple queries, each \
+        selecting a subset of the columns."
+    )]
+    TooManyColumns {
+        num_columns: usize,
+        max_bound_columns: usize,
+    },
+    /// The schema passed to [`crate::OdbcReaderBuilder::validate_schema`] has a different number of
+    /// fields than the result set has columns.
+    #[error(
+        "The schema has {num_fields} fields, yet the result set has {num_columns} columns. Each \
+        field must correspond to exactly one column."
+    )]
+    SchemaColumnCountMismatch {
+        num_fields: usize,
+        num_columns: usize,
+    },
+    /// Columns streamed piece by piece (see
+    /// [`crate::OdbcReaderBuilder::with_long_data_streaming`]) require the transit buffer to be
+    /// unbound after each row, which concurrent and asynchronous readers do not support.
+    #[error(
+        "Streaming long data using SQLGetData is only supported by `OdbcReader`. Concurrent and \
+        asynchronous readers fetch into buffers which stay bound to the cursor."
+    )]
+    LongDataStreamingUnsupported,
+    /// An option has been set on the builder, which the asynchronous reader does not support. See
+    /// [`crate::OdbcReaderBuilder::build_async`].
+    #[error("The asynchronous reader does not support `{option}`.")]
+    UnsupportedByAsyncReader {
+        /// Name of the builder method setting the option.
+        option: &'static str,
+    },
+    /// [`crate::merge_sorted_readers`] has been called without any readers.
+    #[error("At least one reader is required to merge sorted readers.")]
+    NoReadersToMerge,
+    /// A reader passed to [`crate::merge_sorted_readers`] has a schema different from the first
+    /// one.
+    #[error(
+        "The schema of the reader at index {reader_index} differs from the schema of the first \
+        reader. Only readers sharing the same schema can be merged."
+    )]
+    MergeSchemaMismatch { reader_index: usize },
+    /// The sort column passed to [`crate::merge_sorted_readers`] is not part of the schema.
+    #[error("Readers can not be merged by column '{column}', since it is not part of the schema.")]
+    MergeSortColumnNotFound { column: String },
+    /// The sort column passed to [`crate::merge_sorted_readers`] is neither of a primitive nor a
+    /// string type.
+    #[error(
+        "Readers can not be merged by column '{column}' of type {data_type}. Only primitive and \
+        string types are supported as sort keys."
+    )]
+    UnsupportedMergeSortType { column: String, data_type: DataType },
     /// Failure during constructing an OdbcReader, if it turns out the buffer memory size limit is
     /// too small.
     #[error(
@@ -58,3 +164,26 @@ pub enum Error {
     )]
     EncodingInvalid { source: std::string::FromUtf8Error },
 }
+
+impl Error {
+    /// Maps errors caused by an expired query timeout (SQLSTATE `HYT00`) during schema inference to
+    /// [`Error::InferenceTimeout`]. Other errors are returned unchanged.
+    pub(crate) fn into_inference_timeout(self, timeout: Duration) -> Error {
+        match self {
+            Error::UnableToRetrieveNumCols(source)
+            | Error::ColumnFailure {
+                source: ColumnFailure::FailedToDescribeColumn(source),
+                ..
+            } if is_timeout(&source) => Error::InferenceTimeout { timeout, source },
+            other => other,
+        }
+    }
+}
+
+/// `true` if the error has been caused by an expired timeout.
+fn is_timeout(error: &odbc_api::Error) -> bool {
+    matches!(
+        error,
+        odbc_api::Error::Diagnostics { record, .. } if record.state == State(*b"HYT00")
+    )
+}
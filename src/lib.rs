@@ -41,6 +41,8 @@
 //!     Ok(())
 //! }
 //! ```
+mod boolean;
+mod compatibility;
 mod date_time;
 mod decimal;
 mod error;
@@ -54,10 +56,19 @@ pub use arrow;
 pub use odbc_api;
 
 pub use self::{
+    boolean::BoolEncoding,
+    compatibility::{check_insert_compatibility, Incompatibility},
+    decimal::DecimalInsertPolicy,
     error::Error,
-    odbc_writer::{insert_into_table, insert_statement_from_schema, OdbcWriter, WriterError},
+    odbc_writer::{
+        copy_cursor_to_table, insert_batches, insert_batches_counted, insert_into_table,
+        insert_into_table_counted, insert_statement_from_schema, ColumnTransform,
+        IdentifierQuoting, OdbcWriter, OdbcWriterBuilder, WriterError,
+    },
     reader::{
-        BufferAllocationOptions, ColumnFailure, ConcurrentOdbcReader, OdbcReader, OdbcReaderBuilder,
+        merge_sorted_readers, read, AsyncOdbcReader, BufferAllocationOptions, BufferPool,
+        ColumnFailure, ColumnStats, ConcurrentOdbcReader, ErrorAction, InvalidUtf8Policy,
+        MappingError, MergeSorted, NullFill, OdbcReader, OdbcReaderBuilder, TextEncoding,
     },
-    schema::arrow_schema_from,
+    schema::{arrow_schema_from, describe_mapping, odbc_schema_from, ColumnMapping, Fidelity},
 };
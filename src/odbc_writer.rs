@@ -1,14 +1,15 @@
 use std::{borrow::Cow, cmp::min};
 
+use log::warn;
 use thiserror::Error;
 
 use arrow::{
-    array::Array,
+    array::{Array, ArrayRef},
     datatypes::{
-        DataType, Date32Type, Date64Type, Field, Float16Type, Float32Type, Float64Type, Int16Type,
-        Int32Type, Int64Type, Int8Type, Schema, Time32MillisecondType, Time32SecondType,
-        Time64MicrosecondType, Time64NanosecondType, TimeUnit, TimestampMicrosecondType,
-        TimestampMillisecondType, TimestampNanosecondType, TimestampSecondType, UInt8Type,
+        i256, DataType, Date32Type, Date64Type, Decimal128Type, Decimal256Type, Field, Float16Type,
+        Float32Type, Float64Type, Int16Type, Int32Type, Int64Type, Int8Type, Schema,
+        Time32MillisecondType, Time32SecondType, Time64MicrosecondType, Time64NanosecondType,
+        TimeUnit, UInt16Type, UInt32Type, UInt8Type,
     },
     error::ArrowError,
     record_batch::{RecordBatch, RecordBatchReader},
@@ -20,24 +21,32 @@ use odbc_api::{
 };
 
 use crate::{
-    date_time::{
-        epoch_to_date, epoch_to_timestamp_ms, epoch_to_timestamp_ns, epoch_to_timestamp_s,
-        epoch_to_timestamp_us, sec_since_midnight_to_time, NullableTimeAsText,
-    },
-    decimal::{NullableDecimal128AsText, NullableDecimal256AsText},
+    date_time::{epoch_to_date, sec_since_midnight_to_time, NullableTimeAsText},
+    decimal::{DecimalInsertPolicy, NullableDecimal128AsText, NullableDecimal256AsText},
+    BoolEncoding,
 };
 
 use self::{
-    binary::VariadicBinary,
-    boolean::boolean_to_bit,
+    binary::{LargeVariadicBinary, VariadicBinary},
+    boolean::{boolean_to_bit, BooleanToText},
+    dictionary::DictionaryToValues,
+    epoch::TimestampToEpoch,
     map_arrow_to_odbc::MapArrowToOdbc,
+    null::NullAsText,
     text::{LargeUtf8ToNativeText, Utf8ToNativeText},
+    timestamp::timestamp_with_precision,
+    unsigned::UInt64AsText,
 };
 
 mod binary;
 mod boolean;
+mod dictionary;
+mod epoch;
 mod map_arrow_to_odbc;
+mod null;
 mod text;
+mod timestamp;
+mod unsigned;
 
 /// Fastest and most convinient way to stream the contents of arrow record batches into a database
 /// table. For usecase there you want to insert repeatedly into the same table from different
@@ -53,31 +62,178 @@ pub fn insert_into_table(
     table_name: &str,
     batch_size: usize,
 ) -> Result<(), WriterError> {
+    insert_into_table_counted(connection, batches, table_name, batch_size)?;
+    Ok(())
+}
+
+/// Like [`insert_into_table`], but returns the number of rows inserted.
+pub fn insert_into_table_counted(
+    connection: &Connection,
+    batches: &mut impl RecordBatchReader,
+    table_name: &str,
+    batch_size: usize,
+) -> Result<usize, WriterError> {
     let schema = batches.schema();
     let mut inserter =
         OdbcWriter::with_connection(connection, schema.as_ref(), table_name, batch_size)?;
-    inserter.write_all(batches)
+    inserter.write_all_counted(batches)
+}
+
+/// Copies the result set of a reader into a database table, e.g. from a `SELECT` statement on one
+/// connection into a table on another one. The insert statement is derived from the schema of the
+/// reader, so the column names of the table must match the field names. Batches are inserted as
+/// they are fetched, so the result set is never materialized in memory as a whole. Rows are sent
+/// to the database in chunks of up to `batch_size` rows.
+///
+/// Returns the number of rows inserted.
+///
+/// # Example
+///
+/// ```no_run
+/// use arrow_odbc::{copy_cursor_to_table, odbc_api::Connection, OdbcReaderBuilder};
+///
+/// fn copy(source: &Connection, target: &Connection) -> Result<(), anyhow::Error> {
+///     let cursor = source
+///         .execute("SELECT id, name FROM Customers", ())?
+///         .expect("SELECT statement must produce a cursor");
+///     let reader = OdbcReaderBuilder::new().build(cursor)?;
+///     let num_rows = copy_cursor_to_table(reader, target, "CustomersCopy", 1000)?;
+///     println!("Copied {num_rows} rows");
+///     Ok(())
+/// }
+/// ```
+///
+/// **Note:**
+///
+/// If table or column names are derived from user input, be sure to sanatize the input in order to
+/// prevent SQL injection attacks.
+pub fn copy_cursor_to_table(
+    mut reader: impl RecordBatchReader,
+    connection: &Connection,
+    table_name: &str,
+    batch_size: usize,
+) -> Result<usize, WriterError> {
+    insert_into_table_counted(connection, &mut reader, table_name, batch_size)
+}
+
+/// Inserts record batches, which are already in memory, into a database table. Other than
+/// [`insert_into_table`] this does not require a [`RecordBatchReader`]. Rows are sent to the
+/// database in chunks of up to `row_capacity` rows, independent of the sizes of the batches.
+///
+/// Every batch must have the same field names and data types as `schema`. Fields of the batches
+/// may only be nullable if the field in `schema` is. This is validated for
+/// all batches before anything is inserted, so a mismatch causes
+/// [`WriterError::BatchSchemaMismatch`] without modifying the table.
+///
+/// **Note:**
+///
+/// If table or column names are derived from user input, be sure to sanatize the input in order to
+/// prevent SQL injection attacks.
+pub fn insert_batches(
+    connection: &Connection,
+    schema: &Schema,
+    batches: &[RecordBatch],
+    table_name: &str,
+    row_capacity: usize,
+) -> Result<(), WriterError> {
+    insert_batches_counted(connection, schema, batches, table_name, row_capacity)?;
+    Ok(())
+}
+
+/// Like [`insert_batches`], but returns the number of rows inserted.
+pub fn insert_batches_counted(
+    connection: &Connection,
+    schema: &Schema,
+    batches: &[RecordBatch],
+    table_name: &str,
+    row_capacity: usize,
+) -> Result<usize, WriterError> {
+    if let Some(batch_index) = batches
+        .iter()
+        .position(|batch| !fits_schema(&batch.schema(), schema))
+    {
+        return Err(WriterError::BatchSchemaMismatch { batch_index });
+    }
+    let mut writer = OdbcWriter::with_connection(connection, schema, table_name, row_capacity)?;
+    writer.write_all_counted(batches.iter().cloned().map(Ok))
+}
+
+/// `true` if the fields of the batch have the same names and data types as the fields of the
+/// writer, in the same order, and are only nullable if the field of the writer is nullable.
+fn fits_schema(batch: &Schema, writer: &Schema) -> bool {
+    batch.fields().len() == writer.fields().len()
+        && batch.fields().iter().zip(writer.fields()).all(|(b, w)| {
+            b.name() == w.name()
+                && b.data_type() == w.data_type()
+                && (w.is_nullable() || !b.is_nullable())
+        })
 }
 
 /// Generates an insert statement using the table and column names.
 ///
 /// `INSERT INTO <table> (<column_names 0>, <column_names 1>, ...) VALUES (?, ?, ...)`
-fn insert_statement_text(table: &str, column_names: &[&'_ str]) -> String {
+fn insert_statement_text(
+    table: &str,
+    column_names: &[&'_ str],
+    quoting: Option<IdentifierQuoting>,
+) -> String {
+    insert_statement_text_multi_row(table, column_names, 1, quoting)
+}
+
+/// Generates an insert statement inserting `num_rows` rows with a single execution.
+///
+/// `INSERT INTO <table> (<column_names 0>, <column_names 1>, ...) VALUES (?, ?, ...), (?, ?, ...)`
+fn insert_statement_text_multi_row(
+    table: &str,
+    column_names: &[&'_ str],
+    num_rows: usize,
+    quoting: Option<IdentifierQuoting>,
+) -> String {
     // Generate statement text from table name and headline
-    let column_names = column_names
-        .iter()
-        .map(|cn| quote_column_name(cn))
-        .collect::<Vec<_>>();
-    let columns = column_names.join(", ");
-    let values = column_names
-        .iter()
-        .map(|_| "?")
+    let columns = quoted_column_names(column_names, quoting).join(", ");
+    let row = placeholders(column_names.len());
+    let values = (0..num_rows)
+        .map(|_| format!("({row})"))
         .collect::<Vec<_>>()
         .join(", ");
     // Do not finish the statement with a semicolon. There is anecodtical evidence of IBM db2 not
     // allowing the command, because it expects now multiple statements.
     // See: <https://github.com/pacman82/arrow-odbc/issues/63>
-    format!("INSERT INTO {table} ({columns}) VALUES ({values})")
+    format!("INSERT INTO {table} ({columns}) VALUES {values}")
+}
+
+/// Generates a statement from a template by substituting the tokens `{table}`, `{columns}` and
+/// `{placeholders}`. See [`OdbcWriter::with_statement_template`].
+fn statement_from_template(
+    template: &str,
+    table: &str,
+    column_names: &[&'_ str],
+    quoting: Option<IdentifierQuoting>,
+) -> String {
+    let columns = quoted_column_names(column_names, quoting).join(", ");
+    template
+        .replace("{table}", table)
+        .replace("{columns}", &columns)
+        .replace("{placeholders}", &placeholders(column_names.len()))
+}
+
+/// Column names as they appear in generated statements.
+fn quoted_column_names<'a>(
+    column_names: &[&'a str],
+    quoting: Option<IdentifierQuoting>,
+) -> Vec<Cow<'a, str>> {
+    column_names
+        .iter()
+        .map(|cn| match quoting {
+            Some(quoting) => quoting.quote(cn),
+            None => quote_column_name(cn),
+        })
+        .collect()
+}
+
+/// One placeholder for each column, e.g. `?, ?, ?`.
+fn placeholders(num_columns: usize) -> String {
+    vec!["?"; num_columns].join(", ")
 }
 
 /// Wraps column name in quotes, if need be
@@ -89,6 +245,53 @@ fn quote_column_name(column_name: &str) -> Cow<'_, str> {
     }
 }
 
+/// Wraps each part of a possibly qualified table name, e.g. `schema.table`, in double quotes, if it
+/// contains characters not valid in an unquoted identifier. Double quotes within a part are
+/// escaped, so the name can not end the identifier early.
+pub(crate) fn quote_table_name(table_name: &str) -> String {
+    table_name
+        .split('.')
+        .map(|part| {
+            if part.contains(|c| !valid_in_column_name(c)) {
+                IdentifierQuoting::DoubleQuote.quote(part)
+            } else {
+                Cow::Borrowed(part)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Characters used to quote column names in generated insert statements. See
+/// [`OdbcWriterBuilder::with_identifier_quoting`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IdentifierQuoting {
+    /// `"column"`, as specified by the SQL standard.
+    #[default]
+    DoubleQuote,
+    /// `[column]`, e.g. for Microsoft SQL Server.
+    SquareBracket,
+    /// `` `column` ``, e.g. for MySQL and MariaDB.
+    Backtick,
+    /// Column names are inserted into the statement as they are.
+    None,
+}
+
+impl IdentifierQuoting {
+    /// Wraps the column name in quotes. Closing quote characters within the name are escaped by
+    /// doubling them.
+    fn quote(self, column_name: &str) -> Cow<'_, str> {
+        let (open, close) = match self {
+            IdentifierQuoting::DoubleQuote => ('"', '"'),
+            IdentifierQuoting::SquareBracket => ('[', ']'),
+            IdentifierQuoting::Backtick => ('`', '`'),
+            IdentifierQuoting::None => return Cow::Borrowed(column_name),
+        };
+        let escaped = column_name.replace(close, &format!("{close}{close}"));
+        Cow::Owned(format!("{open}{escaped}{close}"))
+    }
+}
+
 /// Check if this character is allowed in an unquoted column name
 fn valid_in_column_name(c: char) -> bool {
     // See:
@@ -129,7 +332,7 @@ pub fn insert_statement_from_schema(schema: &Schema, table_name: &str) -> String
     let column_names: Vec<_> = (0..num_columns)
         .map(|i| fields[i].name().as_str())
         .collect();
-    insert_statement_text(table_name, &column_names)
+    insert_statement_text(table_name, &column_names, None)
 }
 
 /// Emitted writing values from arror arrays into a table on the database
@@ -141,6 +344,8 @@ pub enum WriterError {
     ExecuteStatment(#[source] odbc_api::Error),
     #[error("An error occured rebinding a parameter buffer to the sql statement.\n{0}")]
     RebindBuffer(#[source] odbc_api::Error),
+    #[error("Failure to resolve the keys of a dictionary encoded array to its values.\n{0}")]
+    DecodeDictionary(#[source] ArrowError),
     #[error("The arrow data type {0} is not supported for insertion.")]
     UnsupportedArrowDataType(DataType),
     #[error("An error occured extracting a record batch from an error reader.\n{0}")]
@@ -151,8 +356,65 @@ pub enum WriterError {
         source: odbc_api::Error,
         sql: String,
     },
+    #[error(
+        "Timestamp {value} can not be represented as epoch in unit {unit:?} without overflowing a \
+        64 Bit integer."
+    )]
+    EpochOverflow { value: i64, unit: TimeUnit },
+    #[error(
+        "Timestamp precision must be between 0 and 9 fractional digits, yet {0} was requested."
+    )]
+    InvalidTimestampPrecision(u32),
+    #[error("Decimal {value} can not be inserted with a scale of {scale} without losing digits.")]
+    DecimalScaleExceeded { value: String, scale: i8 },
+    #[error("There is no column named '{0}' in the schema of the writer.")]
+    UnknownColumn(String),
+    #[error(
+        "The insert statement would require {requested} parameters, yet the database only \
+        supports up to {limit} parameters per statement."
+    )]
+    ParameterLimitExceeded { limit: usize, requested: usize },
+    #[error(
+        "The batch at index {batch_index} does not have the same field names and data types as the \
+        schema of the writer."
+    )]
+    BatchSchemaMismatch { batch_index: usize },
+    #[error(
+        "The schema has {num_fields} fields, yet {num_columns} column names have been specified. \
+        Each field must be inserted into exactly one column."
+    )]
+    ColumnCountMismatch {
+        num_fields: usize,
+        num_columns: usize,
+    },
+    #[error("Failure to describe the parameters of the SQL statement. SQL:\n{sql}\n{source}")]
+    DescribeParameters {
+        #[source]
+        source: odbc_api::Error,
+        sql: String,
+    },
+    #[error(
+        "The statement template must contain '{{placeholders}}' exactly once, yet it occurs \
+        {occurrences} times."
+    )]
+    InvalidStatementTemplate { occurrences: usize },
+    #[error(
+        "The writer has been created from a statement supplied by the application. It can not be \
+        retargeted to another table, since the statement for it can not be derived."
+    )]
+    RetargetUserStatement,
+    #[error("Failure to apply transformation to column '{column}'.\n{source}")]
+    TransformColumn {
+        #[source]
+        source: ArrowError,
+        column: String,
+    },
 }
 
+/// Transformation applied to the values of a column, before they are written into the parameter
+/// buffers. See [`OdbcWriter::with_column_transform`].
+pub type ColumnTransform = Box<dyn Fn(&dyn Array) -> Result<ArrayRef, ArrowError>>;
+
 /// Inserts batches from an [`arrow::record_batch::RecordBatchReader`] into a database.
 pub struct OdbcWriter<S> {
     /// Prepared statement with bound array parameter buffers. Data is copied into these buffers
@@ -163,6 +425,38 @@ pub struct OdbcWriter<S> {
     /// to the database, and need to remember how to copy the data from an arrow array to an odbc
     /// mutable buffer slice for any column.
     strategies: Vec<Box<dyn WriteStrategy>>,
+    /// Names of the columns in the arrow schema. Used to identify the columns transformations are
+    /// applied to.
+    column_names: Vec<String>,
+    /// Names of the table columns the fields are inserted into, in the order of the fields. `None`
+    /// if the statement has been supplied by the application, rather than being generated.
+    /// Remembered so the same columns are targeted when retargeting the writer.
+    target_columns: Option<Vec<String>>,
+    /// One optional transformation for each column, applied to the arrow array before it is
+    /// copied into the parameter buffer.
+    transforms: Vec<Option<ColumnTransform>>,
+    /// `Some` if rows are inserted using a multi row `VALUES` clause with scalar parameters rather
+    /// than array parameters. In this case `inserter` has a capacity of one, but binds one
+    /// parameter for each column of each row in the statement.
+    multi_row_values: Option<MultiRowValues<S>>,
+    /// Quoting of the column names in generated insert statements. Remembered so the same names are
+    /// generated when retargeting the writer to another table.
+    identifier_quoting: Option<IdentifierQuoting>,
+    /// `Some` if the statement has been generated from a template, rather than being an insert
+    /// statement. Remembered so the same statement is generated when retargeting the writer.
+    statement_template: Option<String>,
+}
+
+/// State of an [`OdbcWriter`] inserting rows using multi row `VALUES` clauses.
+struct MultiRowValues<S> {
+    /// Number of rows inserted by each execution of the multi row statement.
+    rows_per_statement: usize,
+    /// Statement inserting a single row. Used to insert the remaining rows on flush, which are not
+    /// enough to fill an entire multi row statement.
+    single_row: ColumnarBulkInserter<S, AnyBuffer>,
+    /// Rows not yet send to the database. Each element holds one array of length one for each
+    /// column.
+    pending: Vec<Vec<ArrayRef>>,
 }
 
 impl<S> OdbcWriter<S>
@@ -194,20 +488,53 @@ where
         schema: &Schema,
         statement: Prepared<S>,
     ) -> Result<Self, WriterError> {
-        let strategies: Vec<_> = schema
-            .fields()
-            .iter()
-            .map(|field| field_to_write_strategy(field.as_ref()))
-            .collect::<Result<_, _>>()?;
-        let descriptions = strategies.iter().map(|cws| cws.buffer_desc());
-        let inserter = statement
-            .into_column_inserter(row_capacity, descriptions)
-            .map_err(WriterError::BindParameterBuffers)?;
+        OdbcWriterBuilder::new().build(row_capacity, schema, statement)
+    }
 
-        Ok(Self {
-            inserter,
-            strategies,
-        })
+    /// Registers a transformation for the column named `column_name`. It is applied to the values
+    /// of each record batch, before they are copied into the parameter buffers. Use this e.g. to
+    /// uppercase codes, clamp values or redact sensitive information as part of the insert.
+    ///
+    /// The transformation must return an array of the same length and data type as its input.
+    /// Registering another transformation for the same column replaces the previous one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use arrow_odbc::{
+    ///     arrow::{array::{Array, ArrayRef, StringArray}, error::ArrowError},
+    ///     odbc_api::handles::AsStatementRef,
+    ///     OdbcWriter, WriterError,
+    /// };
+    ///
+    /// fn uppercase_code(writer: &mut OdbcWriter<impl AsStatementRef>) -> Result<(), WriterError> {
+    ///     writer.with_column_transform(
+    ///         "code",
+    ///         Box::new(|array: &dyn Array| {
+    ///             let strings = array.as_any().downcast_ref::<StringArray>().unwrap();
+    ///             let upper: StringArray = strings
+    ///                 .iter()
+    ///                 .map(|opt| opt.map(str::to_uppercase))
+    ///                 .collect();
+    ///             Ok::<ArrayRef, ArrowError>(Arc::new(upper))
+    ///         }),
+    ///     )?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_column_transform(
+        &mut self,
+        column_name: &str,
+        transform: ColumnTransform,
+    ) -> Result<&mut Self, WriterError> {
+        let index = self
+            .column_names
+            .iter()
+            .position(|name| name == column_name)
+            .ok_or_else(|| WriterError::UnknownColumn(column_name.to_owned()))?;
+        self.transforms[index] = Some(transform);
+        Ok(self)
     }
 
     /// Consumes all the batches in the record batch reader and sends them chunk by chunk to the
@@ -216,17 +543,50 @@ where
         &mut self,
         reader: impl Iterator<Item = Result<RecordBatch, ArrowError>>,
     ) -> Result<(), WriterError> {
+        self.write_all_counted(reader)?;
+        Ok(())
+    }
+
+    /// Like [`Self::write_all`], but returns the number of rows inserted. Use this e.g. to log how
+    /// many rows went to the database, without counting them separately.
+    pub fn write_all_counted(
+        &mut self,
+        reader: impl Iterator<Item = Result<RecordBatch, ArrowError>>,
+    ) -> Result<usize, WriterError> {
+        let mut num_rows = 0;
         for result in reader {
             let record_batch = result.map_err(WriterError::ReadingRecordBatch)?;
             self.write_batch(&record_batch)?;
+            num_rows += record_batch.num_rows();
         }
         self.flush()?;
-        Ok(())
+        Ok(num_rows)
+    }
+
+    /// Inserts all batches of `reader` and flushes the writer afterwards. Other than
+    /// [`Self::write_all`] the writer is guaranteed to be in a clean state after this call, even if
+    /// it fails: Rows of a failed stream which have not been sent to the database yet are
+    /// discarded, rather than being sent together with the rows of the next stream. This makes it
+    /// safe to use a single writer as a long lived sink for many independent streams, e.g. one for
+    /// each file in a loader. The statement is prepared only once and the parameter buffers are
+    /// reused without reallocation.
+    pub fn write_stream(
+        &mut self,
+        reader: impl Iterator<Item = Result<RecordBatch, ArrowError>>,
+    ) -> Result<(), WriterError> {
+        let result = self.write_all(reader);
+        if result.is_err() {
+            self.discard_pending_rows();
+        }
+        result
     }
 
     /// Consumes a single batch and sends it chunk by chunk to the database. The last batch may not
     /// be consumed until [`Self::flush`] is called.
     pub fn write_batch(&mut self, record_batch: &RecordBatch) -> Result<(), WriterError> {
+        if self.multi_row_values.is_some() {
+            return self.write_batch_multi_row(record_batch);
+        }
         let capacity = self.inserter.capacity();
         let mut remanining_rows = record_batch.num_rows();
         // The record batch may contain more rows than the capacity of our writer can hold. So we
@@ -237,13 +597,13 @@ where
             let param_offset = self.inserter.num_rows();
             self.inserter.set_num_rows(param_offset + chunk_size);
             let chunk = record_batch.slice(record_batch.num_rows() - remanining_rows, chunk_size);
-            for (index, (array, strategy)) in chunk
-                .columns()
-                .iter()
-                .zip(self.strategies.iter())
-                .enumerate()
-            {
-                strategy.write_rows(param_offset, self.inserter.column_mut(index), array)?
+            for (index, array) in chunk.columns().iter().enumerate() {
+                let array = self.transformed(index, array)?;
+                self.strategies[index].write_rows(
+                    param_offset,
+                    self.inserter.column_mut(index),
+                    array.as_ref(),
+                )?
             }
 
             // If we used up all capacity we send the parameters to the database and reset the
@@ -263,12 +623,108 @@ where
     /// we reach the last batch however, there is no "next batch" anymore. In that case we call this
     /// method in order to send the remainder of the records to the database as well.
     pub fn flush(&mut self) -> Result<(), WriterError> {
+        if let Some(multi_row_values) = &mut self.multi_row_values {
+            // Not enough rows left to fill the multi row statement. Insert them one by one.
+            for row in multi_row_values.pending.drain(..) {
+                let single_row = &mut multi_row_values.single_row;
+                single_row.set_num_rows(1);
+                for (index, (array, strategy)) in row.iter().zip(&self.strategies).enumerate() {
+                    strategy.write_rows(0, single_row.column_mut(index), array.as_ref())?;
+                }
+                single_row.execute().map_err(WriterError::ExecuteStatment)?;
+                single_row.clear();
+            }
+            return Ok(());
+        }
         self.inserter
             .execute()
             .map_err(WriterError::ExecuteStatment)?;
         self.inserter.clear();
         Ok(())
     }
+
+    /// Forgets about all rows which have been written into the buffers, but not yet been sent to
+    /// the database.
+    fn discard_pending_rows(&mut self) {
+        self.inserter.clear();
+        if let Some(multi_row_values) = &mut self.multi_row_values {
+            multi_row_values.pending.clear();
+            multi_row_values.single_row.clear();
+        }
+    }
+
+    /// Applies the transformation registered for the column, if any.
+    fn transformed(&self, index: usize, array: &ArrayRef) -> Result<ArrayRef, WriterError> {
+        if let Some(transform) = &self.transforms[index] {
+            apply_transform(transform, array.as_ref()).map_err(|source| {
+                WriterError::TransformColumn {
+                    source,
+                    column: self.column_names[index].clone(),
+                }
+            })
+        } else {
+            Ok(array.clone())
+        }
+    }
+
+    /// Buffers rows until there are enough to fill an entire multi row statement, which is then
+    /// executed.
+    fn write_batch_multi_row(&mut self, record_batch: &RecordBatch) -> Result<(), WriterError> {
+        let columns = record_batch
+            .columns()
+            .iter()
+            .enumerate()
+            .map(|(index, array)| self.transformed(index, array))
+            .collect::<Result<Vec<_>, _>>()?;
+        let multi_row_values = self.multi_row_values.as_mut().unwrap();
+        let num_columns = self.strategies.len();
+        for row_index in 0..record_batch.num_rows() {
+            let row = columns
+                .iter()
+                .map(|array| array.slice(row_index, 1))
+                .collect();
+            multi_row_values.pending.push(row);
+            if multi_row_values.pending.len() == multi_row_values.rows_per_statement {
+                self.inserter.set_num_rows(1);
+                for (row_offset, row) in multi_row_values.pending.drain(..).enumerate() {
+                    for (index, (array, strategy)) in row.iter().zip(&self.strategies).enumerate() {
+                        let param_index = row_offset * num_columns + index;
+                        strategy.write_rows(
+                            0,
+                            self.inserter.column_mut(param_index),
+                            array.as_ref(),
+                        )?;
+                    }
+                }
+                self.inserter
+                    .execute()
+                    .map_err(WriterError::ExecuteStatment)?;
+                self.inserter.clear();
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Applies the transformation and verifies, that the result can still be written using the strategy
+/// chosen for the original array.
+fn apply_transform(transform: &ColumnTransform, array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    let transformed = transform(array)?;
+    if transformed.len() != array.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Transformation returned an array of length {}, but the input has length {}.",
+            transformed.len(),
+            array.len()
+        )));
+    }
+    if transformed.data_type() != array.data_type() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Transformation returned an array of type {}, but the input is of type {}.",
+            transformed.data_type(),
+            array.data_type()
+        )));
+    }
+    Ok(transformed)
 }
 
 impl<'env> OdbcWriter<StatementConnection<'env>> {
@@ -286,11 +742,7 @@ impl<'env> OdbcWriter<StatementConnection<'env>> {
         table_name: &str,
         row_capacity: usize,
     ) -> Result<Self, WriterError> {
-        let sql = insert_statement_from_schema(schema, table_name);
-        let statement = connection
-            .into_prepared(&sql)
-            .map_err(|source| WriterError::PreparingInsertStatement { source, sql })?;
-        Self::new(row_capacity, schema, statement)
+        OdbcWriterBuilder::new().build_from_connection(connection, schema, table_name, row_capacity)
     }
 }
 
@@ -309,11 +761,536 @@ impl<'o> OdbcWriter<StatementImpl<'o>> {
         table_name: &str,
         row_capacity: usize,
     ) -> Result<Self, WriterError> {
-        let sql = insert_statement_from_schema(schema, table_name);
+        OdbcWriterBuilder::new().build_with_connection(connection, schema, table_name, row_capacity)
+    }
+
+    /// A writer which borrows the connection and inserts the fields of the schema into the
+    /// explicitly named columns of a table. `column_names` lists one target column for each field,
+    /// in the order of the fields. Columns of the table which are not listed are not part of the
+    /// insert statement, so the database fills them with their default values. Use this e.g. for
+    /// tables with identity or computed columns.
+    ///
+    /// `INSERT INTO <table> (<column_names 0>, <column_names 1>, ...) VALUES (?, ?, ...)`
+    ///
+    /// Fails with [`WriterError::ColumnCountMismatch`] if the number of column names does not match
+    /// the number of fields. Column names are quoted the same way as in [`Self::with_connection`].
+    pub fn with_connection_for_columns(
+        connection: &'o Connection<'o>,
+        schema: &Schema,
+        table_name: &str,
+        column_names: &[&str],
+        row_capacity: usize,
+    ) -> Result<Self, WriterError> {
+        OdbcWriterBuilder::new().build_with_connection_for_columns(
+            connection,
+            schema,
+            table_name,
+            column_names,
+            row_capacity,
+        )
+    }
+
+    /// A writer which borrows the connection and sends the rows using a statement generated from
+    /// `template`, rather than an `INSERT INTO` statement. Use this e.g. for idempotent loads with
+    /// `REPLACE INTO` (MySQL) or `MERGE`. The template may contain these tokens:
+    ///
+    /// * `{table}` is replaced with `table_name`.
+    /// * `{columns}` is replaced with the names of the fields, separated by commas and quoted the
+    ///   same way as in [`Self::with_connection`].
+    /// * `{placeholders}` is replaced with one placeholder (`?`) for each field, separated by
+    ///   commas.
+    ///
+    /// E.g. `REPLACE INTO {table} ({columns}) VALUES ({placeholders})`. `{table}` and `{columns}`
+    /// may occur any number of times. `{placeholders}` must occur exactly once, otherwise
+    /// [`WriterError::InvalidStatementTemplate`] is returned. The statement must have exactly one
+    /// parameter for each field, in the order of the fields. Like [`Self::with_connection`] array
+    /// parameters are used to send `row_capacity` rows at once.
+    pub fn with_statement_template(
+        connection: &'o Connection<'o>,
+        schema: &Schema,
+        table_name: &str,
+        template: &str,
+        row_capacity: usize,
+    ) -> Result<Self, WriterError> {
+        OdbcWriterBuilder::new().build_with_statement_template(
+            connection,
+            schema,
+            table_name,
+            template,
+            row_capacity,
+        )
+    }
+
+    /// A writer which borrows the connection and inserts the given schema into a table with
+    /// matching column names. Other than [`Self::with_connection`] it does not use array
+    /// parameters, but a statement with a multi row `VALUES` clause and scalar parameters:
+    ///
+    /// `INSERT INTO <table> (a, b) VALUES (?, ?), (?, ?), ...`
+    ///
+    /// Each execution inserts `rows_per_statement` rows. Rows which are not enough to fill an
+    /// entire statement are inserted one by one once [`Self::flush`] is called.
+    ///
+    /// Use this for tiny loads of a handful of rows, or with drivers which do not support array
+    /// parameters well. Binding array parameters has some setup overhead, but it is paid only once
+    /// for each statement. Since the multi row statement binds a parameter for every cell, the
+    /// overhead of binding and the size of the statement grows with the number of rows. As a rule
+    /// of thumb array parameters win starting a few dozen rows. Be also aware of the maximum number
+    /// of parameters a statement is allowed to have (e.g. 2100 for Microsoft SQL Server).
+    ///
+    /// # Panics
+    ///
+    /// If `rows_per_statement` is zero.
+    pub fn with_multi_row_values(
+        connection: &'o Connection<'o>,
+        schema: &Schema,
+        table_name: &str,
+        rows_per_statement: usize,
+    ) -> Result<Self, WriterError> {
+        OdbcWriterBuilder::new().build_with_multi_row_values(
+            connection,
+            schema,
+            table_name,
+            rows_per_statement,
+        )
+    }
+
+    /// Inserts all further batches into `table_name` instead of the current table. Rows still
+    /// pending in the parameter buffers are flushed into the current table first. The write
+    /// strategies and column transformations are reused. The target table must have a column for
+    /// each field of the schema, with the same name.
+    ///
+    /// The insert statement for the new table is prepared and the parameters of one row are
+    /// described, which forces the driver to resolve the columns. Drivers deferring the
+    /// preparation, report a table missing a column as [`WriterError::DescribeParameters`]. This
+    /// requires the driver to support `SQLDescribeParam`.
+    ///
+    /// Writers inserting into explicitly named columns (see [`Self::with_connection_for_columns`])
+    /// insert into the columns of the same names in the new table. Writers created from a statement
+    /// supplied by the application (see [`Self::new`]) can not be retargeted and fail with
+    /// [`WriterError::RetargetUserStatement`].
+    pub fn retarget(
+        &mut self,
+        connection: &'o Connection<'o>,
+        table_name: &str,
+    ) -> Result<(), WriterError> {
+        if self.target_columns.is_none() {
+            return Err(WriterError::RetargetUserStatement);
+        }
+        self.flush()?;
+        let column_names: Vec<_> = self
+            .target_columns
+            .iter()
+            .flatten()
+            .map(String::as_str)
+            .collect();
+        let num_fields = column_names.len();
+        let prepare = |num_rows| {
+            // Writers using a template never insert multiple rows with a single statement.
+            let sql = match &self.statement_template {
+                Some(template) => statement_from_template(
+                    template,
+                    table_name,
+                    &column_names,
+                    self.identifier_quoting,
+                ),
+                None => insert_statement_text_multi_row(
+                    table_name,
+                    &column_names,
+                    num_rows,
+                    self.identifier_quoting,
+                ),
+            };
+            let mut statement = connection.prepare(&sql).map_err(|source| {
+                WriterError::PreparingInsertStatement {
+                    source,
+                    sql: sql.clone(),
+                }
+            })?;
+            // The parameters of any further row refer to the same columns.
+            for parameter_number in 1..=num_fields {
+                if let Err(source) = statement.describe_param(parameter_number as u16) {
+                    return Err(WriterError::DescribeParameters { source, sql });
+                }
+            }
+            Ok(statement)
+        };
+        let bind = |statement: Prepared<StatementImpl<'o>>, capacity, num_rows| {
+            let descriptions = (0..num_rows)
+                .flat_map(|_| {
+                    self.strategies
+                        .iter()
+                        .map(|strategy| strategy.buffer_desc())
+                })
+                .collect::<Vec<_>>();
+            statement
+                .into_column_inserter(capacity, descriptions)
+                .map_err(WriterError::BindParameterBuffers)
+        };
+        // Prepare and bind everything before replacing anything, so the writer stays usable with
+        // the current table, should the new one turn out to be incompatible.
+        if let Some(multi_row_values) = &self.multi_row_values {
+            let rows_per_statement = multi_row_values.rows_per_statement;
+            let single_row = bind(prepare(1)?, 1, 1)?;
+            let multi_row = bind(prepare(rows_per_statement)?, 1, rows_per_statement)?;
+            self.inserter = multi_row;
+            self.multi_row_values.as_mut().unwrap().single_row = single_row;
+        } else {
+            let capacity = self.inserter.capacity();
+            self.inserter = bind(prepare(1)?, capacity, 1)?;
+        }
+        Ok(())
+    }
+}
+
+/// Creates instances of [`OdbcWriter`]. Allows for fine tuning how arrow arrays are bound as
+/// parameters. The constructors of [`OdbcWriter`] use the default settings.
+#[derive(Default, Clone)]
+pub struct OdbcWriterBuilder {
+    timestamp_as_epoch: Option<TimeUnit>,
+    timestamp_precision: Option<u32>,
+    boolean_as_text: Option<BoolEncoding>,
+    decimal_as_float: bool,
+    decimal_scales: Vec<(String, i8, DecimalInsertPolicy)>,
+    parameter_limit: Option<usize>,
+    identifier_quoting: Option<IdentifierQuoting>,
+}
+
+impl OdbcWriterBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert arrow `Timestamp` columns as integers counting the units since Unix epoch
+    /// (1970-01-01 00:00:00) rather than as ODBC timestamp structs. Use this if the target
+    /// columns store timestamps as e.g. `BIGINT`. Values are rescaled from the unit of the arrow
+    /// array to the specified `unit`. If `unit` is coarser than the unit of the array, values are
+    /// rounded down (towards negative infinity). If it is finer, values which can no longer be
+    /// represented in a 64 Bit integer cause an error. Timestamps with a timezone are written as the
+    /// units passed since epoch in UTC, independent of their timezone.
+    pub fn with_timestamp_as_epoch(&mut self, unit: TimeUnit) -> &mut Self {
+        self.timestamp_as_epoch = Some(unit);
+        self
+    }
+
+    /// Number of fractional second digits of the timestamps inserted for arrow `Timestamp` columns
+    /// without time zone. Finer fractions are truncated. Must be between `0` and `9`, otherwise
+    /// building the writer fails with [`WriterError::InvalidTimestampPrecision`]. Default is `7`,
+    /// the precision of e.g. `DATETIME2` in Microsoft SQL Server. Nanosecond timestamps with a
+    /// precision of `8` or `9` are bound as text, since the timestamp structs bound otherwise are
+    /// limited to a precision of `7`.
+    pub fn with_timestamp_precision(&mut self, precision: u32) -> &mut Self {
+        self.timestamp_precision = Some(precision);
+        self
+    }
+
+    /// Insert arrow `Boolean` columns as text using the representation of `encoding`, rather than
+    /// as bits. Use this if the target columns store booleans e.g. as `'Y'` and `'N'` in a
+    /// `CHAR(1)` column. `NULL` stays `NULL`. This mirrors
+    /// [`crate::OdbcReaderBuilder::with_boolean_columns`] on the read side.
+    pub fn with_boolean_as_text(&mut self, encoding: BoolEncoding) -> &mut Self {
+        self.boolean_as_text = Some(encoding);
+        self
+    }
+
+    /// Insert arrow `Decimal128` and `Decimal256` columns as 64 Bit floating point numbers, rather
+    /// than as text. Use this if the target columns are `FLOAT` or `DOUBLE`, which may reject or
+    /// misinterpret decimal text. Values are converted to the closest `f64`, so precision is lost
+    /// for decimals with more than 15 significant digits. Default is `false`.
+    pub fn with_decimal_as_float(&mut self, enabled: bool) -> &mut Self {
+        self.decimal_as_float = enabled;
+        self
+    }
+
+    /// Scale of the target column for the arrow `Decimal128` field with the given name. If the
+    /// field has a larger scale, its values are rounded according to `policy`, before they are sent
+    /// to the data source. Otherwise it is up to the data source whether it rejects or rounds these
+    /// values. A field with a scale smaller or equal to `scale` is not affected. Calling this again
+    /// for the same column replaces the scale. Default is to send values with the scale of the
+    /// arrow field.
+    pub fn with_decimal_scale(
+        &mut self,
+        column_name: &str,
+        scale: i8,
+        policy: DecimalInsertPolicy,
+    ) -> &mut Self {
+        self.decimal_scales
+            .retain(|(name, _, _)| name != column_name);
+        self.decimal_scales
+            .push((column_name.to_owned(), scale, policy));
+        self
+    }
+
+    /// Maximum number of parameters the database allows for a single statement (e.g. 2100 for
+    /// Microsoft SQL Server). If a statement would exceed the limit, the writer reduces the number
+    /// of rows per statement of [`Self::build_with_multi_row_values`] to fit. If even a single row
+    /// exceeds the limit [`WriterError::ParameterLimitExceeded`] is returned. Array parameters bind
+    /// one parameter per column, independent of the row capacity. If not set, the limit of
+    /// Microsoft SQL Server is applied to multi row statements, in case the connection reports it
+    /// as the database management system.
+    pub fn with_parameter_limit(&mut self, limit: usize) -> &mut Self {
+        self.parameter_limit = Some(limit);
+        self
+    }
+
+    /// Quote every column name in the generated insert statements using `quoting`. Use this e.g. to
+    /// insert into columns named like reserved words, or with databases which do not accept double
+    /// quotes around identifiers. By default column names are only wrapped in double quotes if they
+    /// contain characters which are not valid in unquoted identifiers. See
+    /// [`insert_statement_from_schema`].
+    pub fn with_identifier_quoting(&mut self, quoting: IdentifierQuoting) -> &mut Self {
+        self.identifier_quoting = Some(quoting);
+        self
+    }
+
+    /// Insert statement for all fields of the schema, quoting column names as configured.
+    fn insert_statement(&self, schema: &Schema, table_name: &str) -> String {
+        insert_statement_text(table_name, &field_names(schema), self.identifier_quoting)
+    }
+
+    /// Like [`Self::build`], but remembers the table columns targeted by the generated statement,
+    /// so the writer can be retargeted to another table.
+    fn build_for_columns<S>(
+        &self,
+        row_capacity: usize,
+        schema: &Schema,
+        statement: Prepared<S>,
+        target_columns: &[&str],
+    ) -> Result<OdbcWriter<S>, WriterError>
+    where
+        S: AsStatementRef,
+    {
+        let mut writer = self.build(row_capacity, schema, statement)?;
+        writer.target_columns = Some(target_columns.iter().map(|&name| name.to_owned()).collect());
+        Ok(writer)
+    }
+
+    /// Construct a new ODBC writer using an alredy existing prepared statement. See
+    /// [`OdbcWriter::new`].
+    pub fn build<S>(
+        &self,
+        row_capacity: usize,
+        schema: &Schema,
+        statement: Prepared<S>,
+    ) -> Result<OdbcWriter<S>, WriterError>
+    where
+        S: AsStatementRef,
+    {
+        if let Some(precision) = self.timestamp_precision.filter(|&precision| precision > 9) {
+            return Err(WriterError::InvalidTimestampPrecision(precision));
+        }
+        if let Some(limit) = self.parameter_limit {
+            check_parameter_limit(limit, schema.fields().len())?;
+        }
+        let strategies: Vec<_> = schema
+            .fields()
+            .iter()
+            .map(|field| field_to_write_strategy(field.as_ref(), self))
+            .collect::<Result<_, _>>()?;
+        let descriptions = strategies.iter().map(|cws| cws.buffer_desc());
+        let inserter = statement
+            .into_column_inserter(row_capacity, descriptions)
+            .map_err(WriterError::BindParameterBuffers)?;
+
+        let column_names = schema
+            .fields()
+            .iter()
+            .map(|field| field.name().clone())
+            .collect();
+        let transforms = schema.fields().iter().map(|_| None).collect();
+
+        Ok(OdbcWriter {
+            inserter,
+            strategies,
+            column_names,
+            target_columns: None,
+            transforms,
+            multi_row_values: None,
+            identifier_quoting: self.identifier_quoting,
+            statement_template: None,
+        })
+    }
+
+    /// A writer which takes ownership of the connection and inserts the given schema into a table
+    /// with matching column names. See [`OdbcWriter::from_connection`].
+    pub fn build_from_connection<'env>(
+        &self,
+        connection: Connection<'env>,
+        schema: &Schema,
+        table_name: &str,
+        row_capacity: usize,
+    ) -> Result<OdbcWriter<StatementConnection<'env>>, WriterError> {
+        let sql = self.insert_statement(schema, table_name);
+        let statement = connection
+            .into_prepared(&sql)
+            .map_err(|source| WriterError::PreparingInsertStatement { source, sql })?;
+        self.build_for_columns(row_capacity, schema, statement, &field_names(schema))
+    }
+
+    /// A writer which borrows the connection and inserts the given schema into a table with
+    /// matching column names. See [`OdbcWriter::with_connection`].
+    pub fn build_with_connection<'o>(
+        &self,
+        connection: &'o Connection<'o>,
+        schema: &Schema,
+        table_name: &str,
+        row_capacity: usize,
+    ) -> Result<OdbcWriter<StatementImpl<'o>>, WriterError> {
+        let sql = self.insert_statement(schema, table_name);
+        let statement = connection
+            .prepare(&sql)
+            .map_err(|source| WriterError::PreparingInsertStatement { source, sql })?;
+        self.build_for_columns(row_capacity, schema, statement, &field_names(schema))
+    }
+
+    /// A writer which borrows the connection and inserts the fields of the schema into the
+    /// explicitly named columns of a table. See [`OdbcWriter::with_connection_for_columns`].
+    pub fn build_with_connection_for_columns<'o>(
+        &self,
+        connection: &'o Connection<'o>,
+        schema: &Schema,
+        table_name: &str,
+        column_names: &[&str],
+        row_capacity: usize,
+    ) -> Result<OdbcWriter<StatementImpl<'o>>, WriterError> {
+        let num_fields = schema.fields().len();
+        if num_fields != column_names.len() {
+            return Err(WriterError::ColumnCountMismatch {
+                num_fields,
+                num_columns: column_names.len(),
+            });
+        }
+        let sql = insert_statement_text(table_name, column_names, self.identifier_quoting);
+        let statement = connection
+            .prepare(&sql)
+            .map_err(|source| WriterError::PreparingInsertStatement { source, sql })?;
+        self.build_for_columns(row_capacity, schema, statement, column_names)
+    }
+
+    /// A writer sending the rows using a statement generated from a template. See
+    /// [`OdbcWriter::with_statement_template`].
+    pub fn build_with_statement_template<'o>(
+        &self,
+        connection: &'o Connection<'o>,
+        schema: &Schema,
+        table_name: &str,
+        template: &str,
+        row_capacity: usize,
+    ) -> Result<OdbcWriter<StatementImpl<'o>>, WriterError> {
+        let column_names = field_names(schema);
+        let occurrences = template.matches("{placeholders}").count();
+        if occurrences != 1 {
+            return Err(WriterError::InvalidStatementTemplate { occurrences });
+        }
+        let sql =
+            statement_from_template(template, table_name, &column_names, self.identifier_quoting);
         let statement = connection
             .prepare(&sql)
             .map_err(|source| WriterError::PreparingInsertStatement { source, sql })?;
-        Self::new(row_capacity, schema, statement)
+        let mut writer = self.build_for_columns(row_capacity, schema, statement, &column_names)?;
+        writer.statement_template = Some(template.to_owned());
+        Ok(writer)
+    }
+
+    /// A writer inserting rows using a multi row `VALUES` clause. See
+    /// [`OdbcWriter::with_multi_row_values`].
+    ///
+    /// # Panics
+    ///
+    /// If `rows_per_statement` is zero.
+    pub fn build_with_multi_row_values<'o>(
+        &self,
+        connection: &'o Connection<'o>,
+        schema: &Schema,
+        table_name: &str,
+        rows_per_statement: usize,
+    ) -> Result<OdbcWriter<StatementImpl<'o>>, WriterError> {
+        assert!(
+            rows_per_statement > 0,
+            "Statement must insert at least one row"
+        );
+        let column_names = field_names(schema);
+        let limit = self
+            .parameter_limit
+            .or_else(|| detect_parameter_limit(connection));
+        let rows_per_statement = if let Some(limit) = limit {
+            check_parameter_limit(limit, column_names.len())?;
+            fit_rows_per_statement(rows_per_statement, column_names.len(), limit)
+        } else {
+            rows_per_statement
+        };
+        let prepare = |num_rows| {
+            let sql = insert_statement_text_multi_row(
+                table_name,
+                &column_names,
+                num_rows,
+                self.identifier_quoting,
+            );
+            connection
+                .prepare(&sql)
+                .map_err(|source| WriterError::PreparingInsertStatement { source, sql })
+        };
+        let mut writer = self.build_for_columns(1, schema, prepare(1)?, &column_names)?;
+        let descriptions = (0..rows_per_statement)
+            .flat_map(|_| {
+                writer
+                    .strategies
+                    .iter()
+                    .map(|strategy| strategy.buffer_desc())
+            })
+            .collect::<Vec<_>>();
+        let multi_row_inserter = prepare(rows_per_statement)?
+            .into_column_inserter(1, descriptions)
+            .map_err(WriterError::BindParameterBuffers)?;
+        let single_row = std::mem::replace(&mut writer.inserter, multi_row_inserter);
+        writer.multi_row_values = Some(MultiRowValues {
+            rows_per_statement,
+            single_row,
+            pending: Vec::new(),
+        });
+        Ok(writer)
+    }
+}
+
+/// Names of the fields of the schema, in order.
+fn field_names(schema: &Schema) -> Vec<&str> {
+    schema
+        .fields()
+        .iter()
+        .map(|field| field.name().as_str())
+        .collect()
+}
+
+/// Maximum number of parameters per statement of the database management system, if known.
+fn detect_parameter_limit(connection: &Connection<'_>) -> Option<usize> {
+    // Failing to retrieve the name is no reason to fail the insert, we just do not know the limit.
+    let dbms_name = connection.database_management_system_name().ok()?;
+    (dbms_name == "Microsoft SQL Server").then_some(2100)
+}
+
+/// Error if a single row with `num_columns` parameters would exceed the limit.
+fn check_parameter_limit(limit: usize, num_columns: usize) -> Result<(), WriterError> {
+    if num_columns > limit {
+        return Err(WriterError::ParameterLimitExceeded {
+            limit,
+            requested: num_columns,
+        });
+    }
+    Ok(())
+}
+
+/// Reduces the number of rows in a multi row statement, so the number of parameters does not
+/// exceed the limit.
+fn fit_rows_per_statement(rows_per_statement: usize, num_columns: usize, limit: usize) -> usize {
+    let max_rows = limit.checked_div(num_columns).unwrap_or(rows_per_statement);
+    if rows_per_statement > max_rows {
+        warn!(
+            "Inserting {rows_per_statement} rows per statement would require {} parameters, yet \
+            the limit is {limit}. Inserting {max_rows} rows per statement instead.",
+            rows_per_statement * num_columns
+        );
+        max_rows
+    } else {
+        rows_per_statement
     }
 }
 
@@ -335,8 +1312,44 @@ pub trait WriteStrategy {
     ) -> Result<(), WriterError>;
 }
 
-fn field_to_write_strategy(field: &Field) -> Result<Box<dyn WriteStrategy>, WriterError> {
+fn field_to_write_strategy(
+    field: &Field,
+    options: &OdbcWriterBuilder,
+) -> Result<Box<dyn WriteStrategy>, WriterError> {
     let is_nullable = field.is_nullable();
+    // Arrow stores timestamps with a timezone as instants in UTC, so their values already count
+    // the units since Unix epoch. Only the rendering of the value depends on the timezone.
+    if let (DataType::Timestamp(source_unit, _), Some(target_unit)) =
+        (field.data_type(), options.timestamp_as_epoch)
+    {
+        return Ok(Box::new(TimestampToEpoch::new(
+            *source_unit,
+            target_unit,
+            is_nullable,
+        )));
+    }
+    if let (DataType::Boolean, Some(encoding)) = (field.data_type(), options.boolean_as_text) {
+        return Ok(Box::new(BooleanToText::new(encoding)));
+    }
+    if options.decimal_as_float {
+        match field.data_type() {
+            // Powers of ten up to 10^22 are exact, so dividing by them rounds only once. Their
+            // inverse is not exact, so multiplying with it would round twice.
+            DataType::Decimal128(_, scale) => {
+                let divisor = 10f64.powi(*scale as i32);
+                return Ok(Decimal128Type::map_with(is_nullable, move |value| {
+                    value as f64 / divisor
+                }));
+            }
+            DataType::Decimal256(_, scale) => {
+                let divisor = 10f64.powi(*scale as i32);
+                return Ok(Decimal256Type::map_with(is_nullable, move |value| {
+                    i256_to_f64(value) / divisor
+                }));
+            }
+            _ => (),
+        }
+    }
     let strategy = match field.data_type() {
         DataType::Utf8 => Box::new(Utf8ToNativeText {}),
         DataType::Boolean => boolean_to_bit(is_nullable),
@@ -346,23 +1359,15 @@ fn field_to_write_strategy(field: &Field) -> Result<Box<dyn WriteStrategy>, Writ
         DataType::Int32 => Int32Type::identical(is_nullable),
         DataType::Int64 => Int64Type::identical(is_nullable),
         DataType::UInt8 => UInt8Type::identical(is_nullable),
+        // Bind the next wider signed integer, so every value is in range.
+        DataType::UInt16 => UInt16Type::map_with(is_nullable, i32::from),
+        DataType::UInt32 => UInt32Type::map_with(is_nullable, i64::from),
+        DataType::UInt64 => Box::new(UInt64AsText),
         DataType::Float16 => Float16Type::map_with(is_nullable, |half| half.to_f32()),
         DataType::Float32 => Float32Type::identical(is_nullable),
         DataType::Float64 => Float64Type::identical(is_nullable),
-        DataType::Timestamp(TimeUnit::Second, None) => {
-            TimestampSecondType::map_with(is_nullable, epoch_to_timestamp_s)
-        }
-        DataType::Timestamp(TimeUnit::Millisecond, None) => {
-            TimestampMillisecondType::map_with(is_nullable, epoch_to_timestamp_ms)
-        }
-        DataType::Timestamp(TimeUnit::Microsecond, None) => {
-            TimestampMicrosecondType::map_with(is_nullable, epoch_to_timestamp_us)
-        }
-        DataType::Timestamp(TimeUnit::Nanosecond, None) => {
-            TimestampNanosecondType::map_with(is_nullable, |ns| {
-                // Drop the last to digits of precision, since we bind it with precision 7 and not 9.
-                epoch_to_timestamp_ns((ns / 100) * 100)
-            })
+        DataType::Timestamp(unit, None) => {
+            timestamp_with_precision(*unit, options.timestamp_precision.unwrap_or(7), is_nullable)
         }
         DataType::Date32 => Date32Type::map_with(is_nullable, epoch_to_date),
         DataType::Date64 => Date64Type::map_with(is_nullable, |days_since_epoch| {
@@ -381,16 +1386,39 @@ fn field_to_write_strategy(field: &Field) -> Result<Box<dyn WriteStrategy>, Writ
             Box::new(NullableTimeAsText::<Time64NanosecondType>::new())
         }
         DataType::Binary => Box::new(VariadicBinary::new(1)),
+        DataType::LargeBinary => Box::new(LargeVariadicBinary),
         DataType::FixedSizeBinary(length) => {
             Box::new(VariadicBinary::new((*length).try_into().unwrap()))
         }
         DataType::Decimal128(precision, scale) => {
-            Box::new(NullableDecimal128AsText::new(*precision, *scale))
+            let strategy = NullableDecimal128AsText::new(*precision, *scale);
+            let decimal_scale = options
+                .decimal_scales
+                .iter()
+                .find(|(name, _, _)| name == field.name());
+            match decimal_scale {
+                Some(&(_, target_scale, policy)) => {
+                    Box::new(strategy.with_rounding(target_scale, policy))
+                }
+                None => Box::new(strategy),
+            }
         }
         DataType::Decimal256(precision, scale) => {
             Box::new(NullableDecimal256AsText::new(*precision, *scale))
         }
+        DataType::Null => Box::new(NullAsText),
+        DataType::Dictionary(_, value_type) => {
+            let value_field = Field::new(field.name(), value_type.as_ref().clone(), is_nullable);
+            let values = field_to_write_strategy(&value_field, options)?;
+            Box::new(DictionaryToValues::new(value_type.as_ref().clone(), values))
+        }
         unsupported => return Err(WriterError::UnsupportedArrowDataType(unsupported.clone())),
     };
     Ok(strategy)
 }
+
+/// Closest 64 Bit floating point number to a 256 Bit integer.
+fn i256_to_f64(value: i256) -> f64 {
+    let (low, high) = value.to_parts();
+    high as f64 * 2f64.powi(128) + low as f64
+}
@@ -1,5 +1,5 @@
-use arrow::array::{Array, BinaryArray};
-use odbc_api::buffers::{AnySliceMut, BufferDesc};
+use arrow::array::{Array, BinaryArray, LargeBinaryArray};
+use odbc_api::buffers::{AnySliceMut, BinColumnSliceMut, BufferDesc};
 
 use super::{WriteStrategy, WriterError};
 
@@ -27,16 +27,50 @@ impl WriteStrategy for VariadicBinary {
         from: &dyn Array,
     ) -> Result<(), WriterError> {
         let from = from.as_any().downcast_ref::<BinaryArray>().unwrap();
-        let mut to = to.as_bin_view().unwrap();
-        for (row_index, element) in from.iter().enumerate() {
-            if let Some(bytes) = element {
-                to.ensure_max_element_length(bytes.len(), row_index)
-                    .map_err(WriterError::RebindBuffer)?;
-                to.set_cell(param_offset + row_index, Some(bytes))
-            } else {
-                to.set_cell(param_offset + row_index, None);
-            }
+        let to = to.as_bin_view().unwrap();
+        insert_into_bin_slice(from.iter(), to, param_offset)
+    }
+}
+
+/// Same as [`VariadicBinary`], but for arrays with 64 Bit offsets.
+pub struct LargeVariadicBinary;
+
+impl WriteStrategy for LargeVariadicBinary {
+    fn buffer_desc(&self) -> BufferDesc {
+        BufferDesc::Binary { length: 1 }
+    }
+
+    fn write_rows(
+        &self,
+        param_offset: usize,
+        to: AnySliceMut<'_>,
+        from: &dyn Array,
+    ) -> Result<(), WriterError> {
+        let from = from.as_any().downcast_ref::<LargeBinaryArray>().unwrap();
+        let to = to.as_bin_view().unwrap();
+        insert_into_bin_slice(from.iter(), to, param_offset)
+    }
+}
+
+/// The buffer grows to hold the largest value, so values are never truncated on the client side.
+/// Values too large for the target column are reported by the database once the statement is
+/// executed.
+fn insert_into_bin_slice<'a>(
+    from: impl Iterator<Item = Option<&'a [u8]>>,
+    mut to: BinColumnSliceMut,
+    param_offset: usize,
+) -> Result<(), WriterError> {
+    for (row_index, element) in from.enumerate() {
+        // Values of previous batches (`param_offset`) must be preserved in case of reallocation,
+        // too.
+        let num_rows_written_so_far = param_offset + row_index;
+        if let Some(bytes) = element {
+            to.ensure_max_element_length(bytes.len(), num_rows_written_so_far)
+                .map_err(WriterError::RebindBuffer)?;
+            to.set_cell(num_rows_written_so_far, Some(bytes))
+        } else {
+            to.set_cell(num_rows_written_so_far, None);
         }
-        Ok(())
     }
+    Ok(())
 }
@@ -4,7 +4,7 @@ use odbc_api::{
     Bit,
 };
 
-use crate::WriterError;
+use crate::{BoolEncoding, WriterError};
 
 use super::WriteStrategy;
 
@@ -59,3 +59,37 @@ impl WriteStrategy for NonNullable {
         Ok(())
     }
 }
+
+/// Writes booleans as text, using the representation of the encoding. E.g. `'Y'` and `'N'`.
+pub struct BooleanToText {
+    encoding: BoolEncoding,
+}
+
+impl BooleanToText {
+    pub fn new(encoding: BoolEncoding) -> Self {
+        Self { encoding }
+    }
+}
+
+impl WriteStrategy for BooleanToText {
+    fn buffer_desc(&self) -> BufferDesc {
+        BufferDesc::Text {
+            max_str_len: self.encoding.max_str_len(),
+        }
+    }
+
+    fn write_rows(
+        &self,
+        param_offset: usize,
+        column_buf: AnySliceMut<'_>,
+        array: &dyn Array,
+    ) -> Result<(), WriterError> {
+        let from = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+        let mut to = column_buf.as_text_view().unwrap();
+        for (index, cell) in from.iter().enumerate() {
+            let text = cell.map(|value| self.encoding.repr(value).as_bytes());
+            to.set_cell(index + param_offset, text)
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+use arrow::{array::Array, compute::cast, datatypes::DataType};
+use odbc_api::buffers::{AnySliceMut, BufferDesc};
+
+use super::{WriteStrategy, WriterError};
+
+/// Inserts dictionary encoded arrays, by resolving the keys to their values before writing them
+/// with the strategy of the value type.
+pub struct DictionaryToValues {
+    value_type: DataType,
+    /// Strategy used for the decoded values.
+    values: Box<dyn WriteStrategy>,
+}
+
+impl DictionaryToValues {
+    pub fn new(value_type: DataType, values: Box<dyn WriteStrategy>) -> Self {
+        Self { value_type, values }
+    }
+}
+
+impl WriteStrategy for DictionaryToValues {
+    fn buffer_desc(&self) -> BufferDesc {
+        self.values.buffer_desc()
+    }
+
+    fn write_rows(
+        &self,
+        param_offset: usize,
+        to: AnySliceMut<'_>,
+        from: &dyn Array,
+    ) -> Result<(), WriterError> {
+        let decoded = cast(from, &self.value_type).map_err(WriterError::DecodeDictionary)?;
+        self.values.write_rows(param_offset, to, decoded.as_ref())
+    }
+}
@@ -0,0 +1,85 @@
+use arrow::{array::Array, datatypes::TimeUnit};
+use odbc_api::buffers::{AnySliceMut, BufferDesc};
+
+use super::{WriteStrategy, WriterError};
+
+/// Writes arrow timestamps as 64 Bit integers, counting the units since Unix epoch.
+pub struct TimestampToEpoch {
+    /// Unit of the arrow timestamp array
+    source: TimeUnit,
+    /// Unit of the integer written to the database
+    target: TimeUnit,
+    nullable: bool,
+}
+
+impl TimestampToEpoch {
+    pub fn new(source: TimeUnit, target: TimeUnit, nullable: bool) -> Self {
+        Self {
+            source,
+            target,
+            nullable,
+        }
+    }
+
+    fn rescale(&self, value: i64) -> Result<i64, WriterError> {
+        let source = decimal_exponent(self.source);
+        let target = decimal_exponent(self.target);
+        if target >= source {
+            value
+                .checked_mul(10i64.pow(target - source))
+                .ok_or(WriterError::EpochOverflow {
+                    value,
+                    unit: self.target,
+                })
+        } else {
+            Ok(value.div_euclid(10i64.pow(source - target)))
+        }
+    }
+}
+
+/// Number of decimal digits of second fractions representable by the unit.
+pub fn decimal_exponent(unit: TimeUnit) -> u32 {
+    match unit {
+        TimeUnit::Second => 0,
+        TimeUnit::Millisecond => 3,
+        TimeUnit::Microsecond => 6,
+        TimeUnit::Nanosecond => 9,
+    }
+}
+
+impl WriteStrategy for TimestampToEpoch {
+    fn buffer_desc(&self) -> BufferDesc {
+        BufferDesc::I64 {
+            nullable: self.nullable,
+        }
+    }
+
+    fn write_rows(
+        &self,
+        param_offset: usize,
+        column_buf: AnySliceMut<'_>,
+        array: &dyn Array,
+    ) -> Result<(), WriterError> {
+        // All arrow timestamp arrays are backed by a buffer of 64 Bit integers, independent of
+        // their unit.
+        let data = array.to_data();
+        let values = &data.buffer::<i64>(0)[..array.len()];
+        if self.nullable {
+            let mut to = column_buf.as_nullable_slice::<i64>().unwrap();
+            for (index, &value) in values.iter().enumerate() {
+                let cell = if array.is_null(index) {
+                    None
+                } else {
+                    Some(self.rescale(value)?)
+                };
+                to.set_cell(index + param_offset, cell)
+            }
+        } else {
+            let to = column_buf.as_slice::<i64>().unwrap();
+            for (index, &value) in values.iter().enumerate() {
+                to[index + param_offset] = self.rescale(value)?;
+            }
+        }
+        Ok(())
+    }
+}
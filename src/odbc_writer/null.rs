@@ -0,0 +1,29 @@
+use arrow::array::Array;
+use odbc_api::buffers::{AnySliceMut, BufferDesc};
+
+use super::{WriteStrategy, WriterError};
+
+/// Inserts `NULL` for each element of an arrow `Null` array. Since the arrow type carries no
+/// information about the target column, the values are bound as text. The data source converts
+/// `NULL` into the type of the column. Please note that e.g. Microsoft SQL Server does not
+/// implicitly convert text into binary columns.
+pub struct NullAsText;
+
+impl WriteStrategy for NullAsText {
+    fn buffer_desc(&self) -> BufferDesc {
+        BufferDesc::Text { max_str_len: 1 }
+    }
+
+    fn write_rows(
+        &self,
+        param_offset: usize,
+        to: AnySliceMut<'_>,
+        from: &dyn Array,
+    ) -> Result<(), WriterError> {
+        let mut to = to.as_text_view().unwrap();
+        for row_index in 0..from.len() {
+            to.set_cell(param_offset + row_index, None);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+use std::io::Write;
+
+use arrow::{
+    array::{Array, TimestampNanosecondArray},
+    datatypes::{
+        TimeUnit, TimestampMicrosecondType, TimestampMillisecondType, TimestampNanosecondType,
+        TimestampSecondType,
+    },
+};
+use chrono::DateTime;
+use odbc_api::buffers::{AnySliceMut, BufferDesc};
+
+use crate::date_time::{
+    epoch_to_timestamp_ms, epoch_to_timestamp_ns, epoch_to_timestamp_s, epoch_to_timestamp_us,
+};
+
+use super::{
+    epoch::decimal_exponent, map_arrow_to_odbc::MapArrowToOdbc, WriteStrategy, WriterError,
+};
+
+/// Fractional precision of the timestamp structs bound by ODBC buffers.
+const STRUCT_PRECISION: u32 = 7;
+
+/// Binds arrow timestamps without time zone, truncated to `precision` fractional digits. Up to
+/// precision 7 timestamps are bound as ODBC timestamp structs. Nanoseconds with higher precision
+/// are bound as text, since the precision of the struct buffers is fixed to 7.
+pub fn timestamp_with_precision(
+    unit: TimeUnit,
+    precision: u32,
+    is_nullable: bool,
+) -> Box<dyn WriteStrategy> {
+    if unit == TimeUnit::Nanosecond && precision > STRUCT_PRECISION {
+        return Box::new(TimestampNsAsText::new(precision));
+    }
+    // Units coarser than the precision are not truncated.
+    let factor = 10i64.pow(decimal_exponent(unit).saturating_sub(precision));
+    match unit {
+        TimeUnit::Second => TimestampSecondType::map_with(is_nullable, epoch_to_timestamp_s),
+        TimeUnit::Millisecond => TimestampMillisecondType::map_with(is_nullable, move |ms| {
+            epoch_to_timestamp_ms((ms / factor) * factor)
+        }),
+        TimeUnit::Microsecond => TimestampMicrosecondType::map_with(is_nullable, move |us| {
+            epoch_to_timestamp_us((us / factor) * factor)
+        }),
+        TimeUnit::Nanosecond => TimestampNanosecondType::map_with(is_nullable, move |ns| {
+            epoch_to_timestamp_ns((ns / factor) * factor)
+        }),
+    }
+}
+
+/// Binds nanosecond timestamps as text, e.g. `2024-01-02 03:04:05.123456789`, with `precision`
+/// fractional digits.
+struct TimestampNsAsText {
+    precision: u32,
+}
+
+impl TimestampNsAsText {
+    fn new(precision: u32) -> Self {
+        Self { precision }
+    }
+
+    /// Length of the text representation. `yyyy-mm-dd hh:mm:ss.` followed by the fraction.
+    fn str_len(&self) -> usize {
+        20 + self.precision as usize
+    }
+}
+
+impl WriteStrategy for TimestampNsAsText {
+    fn buffer_desc(&self) -> BufferDesc {
+        BufferDesc::Text {
+            max_str_len: self.str_len(),
+        }
+    }
+
+    fn write_rows(
+        &self,
+        param_offset: usize,
+        to: AnySliceMut<'_>,
+        from: &dyn Array,
+    ) -> Result<(), WriterError> {
+        let from = from
+            .as_any()
+            .downcast_ref::<TimestampNanosecondArray>()
+            .unwrap();
+        let mut to = to.as_text_view().unwrap();
+        let divisor = 10u32.pow(9 - self.precision);
+        let width = self.precision as usize;
+        for (row_index, element) in from.iter().enumerate() {
+            let index = param_offset + row_index;
+            if let Some(ns) = element {
+                let date_time = DateTime::from_timestamp_nanos(ns);
+                let fraction = date_time.timestamp_subsec_nanos() / divisor;
+                write!(
+                    to.set_mut(index, self.str_len()),
+                    "{}.{fraction:0width$}",
+                    date_time.format("%Y-%m-%d %H:%M:%S")
+                )
+                .unwrap();
+            } else {
+                to.set_cell(index, None);
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+use arrow::array::{Array, UInt64Array};
+use odbc_api::buffers::{AnySliceMut, BufferDesc};
+
+use super::{WriteStrategy, WriterError};
+
+/// Length of the text representation of `u64::MAX`.
+const MAX_STR_LEN_U64: usize = 20;
+
+/// Writes unsigned 64 Bit integers as decimal text. ODBC offers no unsigned 64 Bit integer
+/// parameter type every driver supports, and values above `i64::MAX` can not be bound as signed
+/// integers. Binding text lets the database convert the value and report values out of range of
+/// the target column.
+pub struct UInt64AsText;
+
+impl WriteStrategy for UInt64AsText {
+    fn buffer_desc(&self) -> BufferDesc {
+        BufferDesc::Text {
+            max_str_len: MAX_STR_LEN_U64,
+        }
+    }
+
+    fn write_rows(
+        &self,
+        param_offset: usize,
+        column_buf: AnySliceMut<'_>,
+        array: &dyn Array,
+    ) -> Result<(), WriterError> {
+        let from = array.as_any().downcast_ref::<UInt64Array>().unwrap();
+        let mut to = column_buf.as_text_view().unwrap();
+        for (index, cell) in from.iter().enumerate() {
+            let text = cell.map(|value| value.to_string());
+            to.set_cell(index + param_offset, text.as_ref().map(String::as_bytes))
+        }
+        Ok(())
+    }
+}
@@ -1,40 +1,88 @@
-use std::{convert::TryInto, sync::Arc};
+use std::{
+    collections::HashMap, convert::TryInto, fmt, num::NonZeroUsize, sync::Arc, time::Duration,
+};
 
 use arrow::{
     array::{ArrayRef, BooleanBuilder},
     datatypes::{
-        DataType as ArrowDataType, Date32Type, Field, Float32Type, Float64Type, Int16Type,
-        Int32Type, Int64Type, Int8Type, TimeUnit, TimestampMicrosecondType,
-        TimestampMillisecondType, TimestampNanosecondType, TimestampSecondType, UInt8Type,
+        ArrowPrimitiveType, DataType as ArrowDataType, Date32Type, Date64Type,
+        DurationMicrosecondType, DurationMillisecondType, DurationNanosecondType,
+        DurationSecondType, Field, Float32Type, Float64Type, Int16Type, Int32Type, Int64Type,
+        Int8Type, TimeUnit, TimestampMicrosecondType, TimestampMillisecondType,
+        TimestampNanosecondType, TimestampSecondType, UInt16Type, UInt32Type, UInt64Type,
+        UInt8Type,
     },
 };
 
 use log::debug;
 use odbc_api::{
     buffers::{AnySlice, BufferDesc, Item},
+    sys::SqlDataType,
     Bit, DataType as OdbcDataType, ResultSetMetadata,
 };
 use thiserror::Error;
 
+mod async_odbc_reader;
 mod binary;
+mod buffer_pool;
+mod channel;
 mod concurrent_odbc_reader;
+mod deadline;
 mod decimal;
+mod dictionary;
+mod exact_batch_rows;
+mod interval;
+mod list;
+mod long_data;
 mod map_odbc_to_arrow;
+mod merge;
+mod null_fill;
 mod odbc_reader;
+mod progress;
+mod row_limit;
+mod statistics;
 mod text;
+mod time;
+mod timestamp_offset;
 mod to_record_batch;
+mod unsigned_big_int;
+mod value_errors;
+
+pub(crate) use self::{
+    interval::is_interval_day_to_second, timestamp_offset::is_timestamp_with_offset,
+};
 
-use crate::date_time::{
-    days_since_epoch, ms_since_epoch, ns_since_epoch, seconds_since_epoch, us_since_epoch,
+use self::{
+    interval::IntervalDayToSecond,
+    list::text_to_fixed_size_list,
+    null_fill::fill_null_strategy,
+    time::Time64Nanosecond,
+    timestamp_offset::{offset_struct_type, TimestampWithOffset, TimestampWithOffsetAsStruct},
+    unsigned_big_int::UInt64FromText,
+};
+use crate::{
+    date_time::{
+        date_ms_since_epoch, days_since_epoch, ms_since_epoch, ns_since_epoch, seconds_since_epoch,
+        us_since_epoch,
+    },
+    BoolEncoding,
 };
 
 pub use self::{
-    binary::{Binary, FixedSizedBinary},
+    async_odbc_reader::AsyncOdbcReader,
+    binary::{Binary, FixedSizedBinary, Guid},
+    buffer_pool::BufferPool,
     concurrent_odbc_reader::ConcurrentOdbcReader,
-    decimal::Decimal,
+    decimal::{Decimal, Decimal256},
+    dictionary::DictionaryText,
+    list::TextToList,
     map_odbc_to_arrow::{MapOdbcToArrow, MappingError},
-    odbc_reader::{OdbcReader, OdbcReaderBuilder},
-    text::choose_text_strategy,
+    merge::{merge_sorted_readers, MergeSorted},
+    null_fill::NullFill,
+    odbc_reader::{read, OdbcReader, OdbcReaderBuilder},
+    statistics::ColumnStats,
+    text::{choose_large_text_strategy, choose_text_strategy, InvalidUtf8Policy, TextEncoding},
+    value_errors::{ErrorAction, MappingErrorHandler, OnValueError},
 };
 
 /// All decisions needed to copy data from an ODBC buffer to an Arrow Array
@@ -80,6 +128,60 @@ impl ReadStrategy for NullableBoolean {
     }
 }
 
+/// Reads booleans from a data source which represents them as text or integers. E.g. `CHAR(1)`
+/// columns holding `'Y'` and `'N'`. Values are fetched as text and interpreted using the encoding.
+pub struct BooleanFromText {
+    /// Maximum string length in u8, excluding terminating zero
+    max_str_len: usize,
+    encoding: BoolEncoding,
+    /// What to do with values which are not a valid representation in `encoding`.
+    on_value_error: OnValueError,
+}
+
+impl BooleanFromText {
+    pub fn new(max_str_len: usize, encoding: BoolEncoding, on_value_error: OnValueError) -> Self {
+        Self {
+            max_str_len,
+            encoding,
+            on_value_error,
+        }
+    }
+}
+
+impl ReadStrategy for BooleanFromText {
+    fn buffer_desc(&self) -> BufferDesc {
+        BufferDesc::Text {
+            max_str_len: self.max_str_len,
+        }
+    }
+
+    fn fill_arrow_array(&self, column_view: AnySlice) -> Result<ArrayRef, MappingError> {
+        let view = column_view.as_text_view().unwrap();
+        let mut builder = BooleanBuilder::with_capacity(view.len());
+        for (row, value) in view.iter().enumerate() {
+            let opt = if let Some(text) = value {
+                match self.encoding.parse(text) {
+                    Some(boolean) => Some(boolean),
+                    None => {
+                        self.on_value_error.handle(
+                            row,
+                            MappingError::InvalidBoolean {
+                                value: String::from_utf8_lossy(text).into_owned(),
+                                encoding: self.encoding,
+                            },
+                        )?;
+                        None
+                    }
+                }
+            } else {
+                None
+            };
+            builder.append_option(opt);
+        }
+        Ok(Arc::new(builder.finish()))
+    }
+}
+
 /// Allows setting limits for buffers bound to the ODBC data source. Check this out if you find that
 /// you get memory allocation, or zero sized column errors. Used than constructing a reader using
 /// [`crate::OdbcReaderBuilder`].
@@ -113,30 +215,246 @@ pub struct BufferAllocationOptions {
     pub fallibale_allocations: bool,
 }
 
+/// Renames the fields of inferred schemas. See
+/// [`crate::OdbcReaderBuilder::with_column_name_transform`].
+#[derive(Clone)]
+pub struct ColumnNameTransform(pub Arc<dyn Fn(&str) -> String + Send + Sync>);
+
+impl fmt::Debug for ColumnNameTransform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ColumnNameTransform")
+    }
+}
+
+/// Options set by the user using [`crate::OdbcReaderBuilder`] which influence the choice of the
+/// read strategies for the individual columns.
+#[derive(Default, Debug, Clone)]
+pub struct ReadOptions {
+    pub buffer_allocation_options: BufferAllocationOptions,
+    pub map_value_errors_to_null: bool,
+    /// Decides for each value which can not be mapped, whether it is replaced with `NULL`.
+    pub mapping_error_handler: Option<MappingErrorHandler>,
+    /// Trim text in fixed sized character columns, like `CHAR`.
+    pub trim_fixed_sized_character_strings: bool,
+    /// Trim text in all character columns, including variadic ones like `VARCHAR`.
+    pub trim_all_strings: bool,
+    /// Emit `NULL` for empty strings.
+    pub empty_string_as_null: bool,
+    /// Fetch columns with wide character types as UTF-16, even on non windows platforms.
+    pub wide_for_wide_columns: bool,
+    /// What to do with text, which is not correctly encoded.
+    pub invalid_utf8_policy: InvalidUtf8Policy,
+    /// Infer `Utf8` rather than `Decimal128` for decimal columns.
+    pub decimals_as_text: bool,
+    /// Infer microseconds rather than nanoseconds for timestamps with more than six fractional
+    /// digits.
+    pub safe_timestamp_range: bool,
+    /// Infer this unit for all timestamps, independent of their precision.
+    pub timestamp_unit: Option<TimeUnit>,
+    /// Infer every field as nullable, independent of the nullability reported by the driver.
+    pub all_columns_nullable: bool,
+    /// Applied to the name of each inferred column.
+    pub column_name_transform: Option<ColumnNameTransform>,
+    /// Make sure names of inferred columns are unique by appending suffixes to duplicates.
+    pub dedup_column_names: bool,
+    /// Fetch unsigned arrow integers wider than 8 Bit by binding the next wider signed integer.
+    pub allow_unsigned_widening: bool,
+    /// Exclude columns from the inferred schema, which the driver fails to describe.
+    pub skip_undescribable_columns: bool,
+    /// Sentinels replacing `NULL` in numeric columns.
+    pub null_fill: Option<NullFill>,
+    /// Infer timestamps with offset as a struct of local time and offset, rather than normalizing
+    /// them to UTC.
+    pub preserve_timestamp_offset: bool,
+    /// Entries attached to the metadata of the schema of the reader.
+    pub schema_metadata: HashMap<String, String>,
+    /// Query timeout set on the statement before the schema is inferred.
+    pub query_timeout: Option<Duration>,
+    /// Query timeout set on the statement only while the metadata of the result set is queried.
+    pub metadata_query_timeout: Option<Duration>,
+    /// Columns which should be interpreted as boolean, together with the encoding used to
+    /// represent the boolean values.
+    pub boolean_columns: Vec<(String, BoolEncoding)>,
+    /// Columns for which the user explicitly chose the encoding used to transfer text.
+    pub text_encodings: Vec<(String, TextEncoding)>,
+    /// Columns which should be fetched as text, independent of their relational type.
+    pub text_columns: Vec<String>,
+    /// Arrow types chosen by the user for individual columns, replacing the inferred ones.
+    pub column_overrides: Vec<(String, ArrowDataType)>,
+    /// Text columns which should be fetched as raw bytes.
+    pub binary_columns: Vec<String>,
+    /// Text columns which should be fetched dictionary encoded.
+    pub dictionary_columns: Vec<String>,
+    /// Text columns which are not bound to the transit buffer, but fetched piece by piece.
+    pub long_data_columns: Vec<String>,
+    /// Name of the database management system, as reported by the driver. Used to enable
+    /// mappings specific to a data source.
+    pub dbms_name: Option<String>,
+}
+
+impl ReadOptions {
+    /// `true` if the user told us we are connected to Microsoft SQL Server.
+    pub fn is_mssql(&self) -> bool {
+        self.dbms_name.as_deref() == Some("Microsoft SQL Server")
+    }
+
+    /// Encoding used for booleans in the column with the specified name, in case the user
+    /// declared it as a boolean column.
+    pub fn bool_encoding(&self, column_name: &str) -> Option<BoolEncoding> {
+        self.boolean_columns
+            .iter()
+            .find(|(name, _)| name == column_name)
+            .map(|&(_, encoding)| encoding)
+    }
+
+    /// Encoding used to transfer the text of the column with the specified name, in case the user
+    /// chose one explicitly.
+    pub fn text_encoding(&self, column_name: &str) -> Option<TextEncoding> {
+        self.text_encodings
+            .iter()
+            .find(|(name, _)| name == column_name)
+            .map(|&(_, encoding)| encoding)
+    }
+
+    /// Arrow type the user chose for the column with the specified name, if any.
+    pub fn column_override(&self, column_name: &str) -> Option<&ArrowDataType> {
+        self.column_overrides
+            .iter()
+            .find(|(name, _)| name == column_name)
+            .map(|(_, data_type)| data_type)
+    }
+
+    /// `true` if the user declared the column with the specified name to be fetched as text.
+    pub fn is_text_column(&self, column_name: &str) -> bool {
+        self.text_columns.iter().any(|name| name == column_name)
+    }
+
+    /// `true` if the user declared the column with the specified name to be streamed piece by
+    /// piece, rather than fetched into the transit buffer.
+    pub fn is_long_data_column(&self, column_name: &str) -> bool {
+        self.long_data_columns
+            .iter()
+            .any(|name| name == column_name)
+    }
+
+    /// `true` if the user declared the column with the specified name to be fetched as raw bytes.
+    pub fn is_binary_column(&self, column_name: &str) -> bool {
+        self.binary_columns.iter().any(|name| name == column_name)
+    }
+
+    /// `true` if the user declared the column with the specified name to be fetched dictionary
+    /// encoded.
+    pub fn is_dictionary_column(&self, column_name: &str) -> bool {
+        self.dictionary_columns
+            .iter()
+            .any(|name| name == column_name)
+    }
+
+    /// `true` if values which can not be mapped may be emitted as `NULL`, so inferred fields must
+    /// be nullable.
+    pub fn value_errors_may_be_null(&self) -> bool {
+        self.map_value_errors_to_null || self.mapping_error_handler.is_some()
+    }
+
+    /// What to do with values of the column which can not be mapped. `column` is the zero based
+    /// index of the column in the result set. A handler takes precedence over mapping all value
+    /// errors to `NULL`.
+    pub fn on_value_error(&self, column: u16) -> OnValueError {
+        match (&self.mapping_error_handler, self.map_value_errors_to_null) {
+            (Some(handler), _) => OnValueError::Handler {
+                handler: handler.clone(),
+                column: column.into(),
+            },
+            (None, true) => OnValueError::Null,
+            (None, false) => OnValueError::Abort,
+        }
+    }
+}
+
 pub fn choose_column_strategy(
     field: &Field,
     query_metadata: &mut impl ResultSetMetadata,
     col_index: u16,
-    buffer_allocation_options: BufferAllocationOptions,
-    map_value_errors_to_null: bool,
-    trim_fixed_sized_character_strings: bool,
+    options: &ReadOptions,
+) -> Result<Box<dyn ReadStrategy + Send>, ColumnFailure> {
+    match options.null_fill {
+        // Fetch the column as nullable and replace the nulls afterwards, so the array is free of
+        // nulls, even if the column in the data source is not.
+        Some(null_fill) if null_fill.applies_to(field.data_type()) => {
+            let nullable_field = field.clone().with_nullable(true);
+            let nullable =
+                choose_strategy_for_field(&nullable_field, query_metadata, col_index, options)?;
+            fill_null_strategy(field.data_type(), null_fill, nullable)
+        }
+        _ => choose_strategy_for_field(field, query_metadata, col_index, options),
+    }
+}
+
+fn choose_strategy_for_field(
+    field: &Field,
+    query_metadata: &mut impl ResultSetMetadata,
+    col_index: u16,
+    options: &ReadOptions,
 ) -> Result<Box<dyn ReadStrategy + Send>, ColumnFailure> {
+    let buffer_allocation_options = options.buffer_allocation_options;
+    let on_value_error = options.on_value_error(col_index - 1);
     let strat: Box<dyn ReadStrategy + Send> = match field.data_type() {
         ArrowDataType::Boolean => {
-            if field.is_nullable() {
+            if let Some(encoding) = options.bool_encoding(field.name()) {
+                let sql_type = query_metadata
+                    .col_data_type(col_index)
+                    .map_err(ColumnFailure::FailedToDescribeColumn)?;
+                // Fixed sized character columns may be padded with whitespaces, so we make sure
+                // the buffer is able to hold the entire value.
+                let max_str_len = sql_type
+                    .utf8_len()
+                    .map(NonZeroUsize::get)
+                    .unwrap_or_default()
+                    .max(encoding.max_str_len());
+                Box::new(BooleanFromText::new(max_str_len, encoding, on_value_error))
+            } else if field.is_nullable() {
                 Box::new(NullableBoolean)
             } else {
                 Box::new(NonNullableBoolean)
             }
         }
         ArrowDataType::Int8 => Int8Type::identical(field.is_nullable()),
-        ArrowDataType::Int16 => Int16Type::identical(field.is_nullable()),
-        ArrowDataType::Int32 => Int32Type::identical(field.is_nullable()),
+        ArrowDataType::Int16 => {
+            let sql_type = col_data_type_or_unknown(query_metadata, col_index);
+            integer_strategy::<Int16Type>(sql_type, field.is_nullable(), on_value_error)
+        }
+        ArrowDataType::Int32 => {
+            let sql_type = col_data_type_or_unknown(query_metadata, col_index);
+            if matches!(sql_type, OdbcDataType::Date) {
+                // Dates are represented as days since epoch, like `Date32`, yet in an array of
+                // type Int32 for tools which do not support `Date32`.
+                Int32Type::map_falliable(field.is_nullable(), on_value_error, days_since_epoch)
+            } else {
+                integer_strategy::<Int32Type>(sql_type, field.is_nullable(), on_value_error)
+            }
+        }
         ArrowDataType::Int64 => Int64Type::identical(field.is_nullable()),
         ArrowDataType::UInt8 => UInt8Type::identical(field.is_nullable()),
+        ArrowDataType::UInt16 if options.allow_unsigned_widening => {
+            widened_unsigned_strategy::<UInt16Type, i32>(field.is_nullable(), on_value_error)
+        }
+        ArrowDataType::UInt32 if options.allow_unsigned_widening => {
+            widened_unsigned_strategy::<UInt32Type, i64>(field.is_nullable(), on_value_error)
+        }
+        ArrowDataType::UInt64 if is_unsigned_big_int(query_metadata, col_index) => {
+            Box::new(UInt64FromText::new(on_value_error))
+        }
+        ArrowDataType::UInt64 if options.allow_unsigned_widening => {
+            widened_unsigned_strategy::<UInt64Type, i64>(field.is_nullable(), on_value_error)
+        }
         ArrowDataType::Float32 => Float32Type::identical(field.is_nullable()),
         ArrowDataType::Float64 => Float64Type::identical(field.is_nullable()),
-        ArrowDataType::Date32 => Date32Type::map_infalliable(field.is_nullable(), days_since_epoch),
+        ArrowDataType::Date32 => {
+            Date32Type::map_falliable(field.is_nullable(), on_value_error, days_since_epoch)
+        }
+        ArrowDataType::Date64 => {
+            Date64Type::map_falliable(field.is_nullable(), on_value_error, date_ms_since_epoch)
+        }
         ArrowDataType::Utf8 => {
             let sql_type = query_metadata
                 .col_data_type(col_index)
@@ -146,16 +464,51 @@ pub fn choose_column_strategy(
             debug!("Relational type of column {}: {sql_type:?}", col_index - 1);
             let lazy_display_size = || query_metadata.col_display_size(col_index);
             // Use the SQL type first to determine buffer length.
-            choose_text_strategy(
-                sql_type,
-                lazy_display_size,
-                buffer_allocation_options.max_text_size,
-                trim_fixed_sized_character_strings,
-            )?
+            choose_text_strategy(sql_type, lazy_display_size, options, field.name())?
+        }
+        ArrowDataType::LargeUtf8 => {
+            let sql_type = query_metadata
+                .col_data_type(col_index)
+                .map_err(ColumnFailure::FailedToDescribeColumn)?;
+            let lazy_display_size = || query_metadata.col_display_size(col_index);
+            choose_large_text_strategy(sql_type, lazy_display_size, options, field.name())?
+        }
+        // Lists of strings are transmitted as comma separated text, e.g. MySQL `SET` columns.
+        ArrowDataType::List(item) if item.data_type() == &ArrowDataType::Utf8 => {
+            let sql_type = query_metadata
+                .col_data_type(col_index)
+                .map_err(ColumnFailure::FailedToDescribeColumn)?;
+            let lazy_display_size = || query_metadata.col_display_size(col_index);
+            let text = choose_text_strategy(sql_type, lazy_display_size, options, field.name())?;
+            Box::new(TextToList::new(text, item.clone()))
+        }
+        // Arrays are transmitted as text, e.g. PostgreSQL `integer[]` columns as `{1,2,3}`.
+        ArrowDataType::FixedSizeList(item, length) => {
+            let sql_type = query_metadata
+                .col_data_type(col_index)
+                .map_err(ColumnFailure::FailedToDescribeColumn)?;
+            let lazy_display_size = || query_metadata.col_display_size(col_index);
+            let text = choose_text_strategy(sql_type, lazy_display_size, options, field.name())?;
+            text_to_fixed_size_list(text, item.clone(), *length, on_value_error)
+                .ok_or_else(|| ColumnFailure::UnsupportedArrowType(field.data_type().clone()))?
+        }
+        // Low cardinality text, e.g. status or country codes.
+        ArrowDataType::Dictionary(key, value)
+            if key.as_ref() == &ArrowDataType::Int32 && value.as_ref() == &ArrowDataType::Utf8 =>
+        {
+            let sql_type = query_metadata
+                .col_data_type(col_index)
+                .map_err(ColumnFailure::FailedToDescribeColumn)?;
+            let lazy_display_size = || query_metadata.col_display_size(col_index);
+            let text = choose_text_strategy(sql_type, lazy_display_size, options, field.name())?;
+            Box::new(DictionaryText::new(text))
         }
         ArrowDataType::Decimal128(precision, scale @ 0..) => {
             Box::new(Decimal::new(*precision, *scale))
         }
+        ArrowDataType::Decimal256(precision, scale @ 0..) => {
+            Box::new(Decimal256::new(*precision, *scale))
+        }
         ArrowDataType::Binary => {
             let sql_type = query_metadata
                 .col_data_type(col_index)
@@ -175,24 +528,57 @@ pub fn choose_column_strategy(
             };
             Box::new(Binary::new(length))
         }
-        ArrowDataType::Timestamp(TimeUnit::Second, _) => {
-            TimestampSecondType::map_infalliable(field.is_nullable(), seconds_since_epoch)
+        ArrowDataType::Timestamp(unit, time_zone) => {
+            let sql_type = col_data_type_or_unknown(query_metadata, col_index);
+            if is_timestamp_with_offset(sql_type) {
+                Box::new(TimestampWithOffset::new(
+                    *unit,
+                    time_zone.clone(),
+                    on_value_error,
+                ))
+            } else {
+                timestamp_strategy(*unit, field.is_nullable(), on_value_error)
+            }
+        }
+        ArrowDataType::FixedSizeBinary(length) => {
+            let sql_type = col_data_type_or_unknown(query_metadata, col_index);
+            if is_guid(sql_type) && *length == 16 {
+                Box::new(Guid)
+            } else {
+                Box::new(FixedSizedBinary::new((*length).try_into().unwrap()))
+            }
+        }
+        // Durations are backed by a 64 Bit integer counting the elapsed units. We let the ODBC
+        // driver convert the integer column in the data source for us and take the value as is.
+        ArrowDataType::Duration(TimeUnit::Second) => {
+            DurationSecondType::identical(field.is_nullable())
+        }
+        ArrowDataType::Duration(TimeUnit::Millisecond) => {
+            DurationMillisecondType::identical(field.is_nullable())
         }
-        ArrowDataType::Timestamp(TimeUnit::Millisecond, _) => {
-            TimestampMillisecondType::map_infalliable(field.is_nullable(), ms_since_epoch)
+        ArrowDataType::Duration(TimeUnit::Microsecond) => {
+            DurationMicrosecondType::identical(field.is_nullable())
         }
-        ArrowDataType::Timestamp(TimeUnit::Microsecond, _) => {
-            TimestampMicrosecondType::map_infalliable(field.is_nullable(), us_since_epoch)
+        ArrowDataType::Duration(TimeUnit::Nanosecond) => {
+            let sql_type = col_data_type_or_unknown(query_metadata, col_index);
+            if is_interval_day_to_second(sql_type) {
+                Box::new(IntervalDayToSecond::new(on_value_error))
+            } else {
+                DurationNanosecondType::identical(field.is_nullable())
+            }
         }
-        ArrowDataType::Timestamp(TimeUnit::Nanosecond, _) => {
-            TimestampNanosecondType::map_falliable(
-                field.is_nullable(),
-                map_value_errors_to_null,
-                ns_since_epoch,
-            )
+        // Times of day are transmitted as text, since the ODBC time struct lacks fractional seconds
+        ArrowDataType::Time64(TimeUnit::Nanosecond) => {
+            Box::new(Time64Nanosecond::new(on_value_error))
         }
-        ArrowDataType::FixedSizeBinary(length) => {
-            Box::new(FixedSizedBinary::new((*length).try_into().unwrap()))
+        struct_type if struct_type == &offset_struct_type() => {
+            let sql_type = query_metadata
+                .col_data_type(col_index)
+                .map_err(ColumnFailure::FailedToDescribeColumn)?;
+            if !is_timestamp_with_offset(sql_type) {
+                return Err(ColumnFailure::UnsupportedArrowType(struct_type.clone()));
+            }
+            Box::new(TimestampWithOffsetAsStruct::new(on_value_error))
         }
         unsupported_arrow_type => {
             return Err(ColumnFailure::UnsupportedArrowType(
@@ -203,6 +589,109 @@ pub fn choose_column_strategy(
     Ok(strat)
 }
 
+/// Strategy for a timestamp column of the arrow schema, whose relational type has no time zone
+/// offset. Values are bound as ODBC timestamp structs and converted into units since Unix epoch.
+fn timestamp_strategy(
+    unit: TimeUnit,
+    nullable: bool,
+    on_value_error: OnValueError,
+) -> Box<dyn ReadStrategy + Send> {
+    match unit {
+        TimeUnit::Second => {
+            TimestampSecondType::map_falliable(nullable, on_value_error, seconds_since_epoch)
+        }
+        TimeUnit::Millisecond => {
+            TimestampMillisecondType::map_falliable(nullable, on_value_error, ms_since_epoch)
+        }
+        TimeUnit::Microsecond => {
+            TimestampMicrosecondType::map_falliable(nullable, on_value_error, us_since_epoch)
+        }
+        TimeUnit::Nanosecond => {
+            TimestampNanosecondType::map_falliable(nullable, on_value_error, ns_since_epoch)
+        }
+    }
+}
+
+/// Strategy for an integer column of the arrow schema. Usually we rely on the driver to convert
+/// the values into the width of the arrow type. Yet some drivers refuse to convert `BIT` columns
+/// to integers, or fail to convert `BIGINT` columns to narrower integers. In these cases we bind
+/// the width reported by the driver and convert the values ourselves.
+fn integer_strategy<T>(
+    sql_type: OdbcDataType,
+    nullable: bool,
+    on_value_error: OnValueError,
+) -> Box<dyn ReadStrategy + Send>
+where
+    T: ArrowPrimitiveType + Send,
+    T::Native: Item + From<u8> + TryFrom<i64>,
+{
+    match sql_type {
+        OdbcDataType::Bit => T::map_infalliable(nullable, |&Bit(value)| T::Native::from(value)),
+        OdbcDataType::BigInt => T::map_falliable(nullable, on_value_error, |&value: &i64| {
+            T::Native::try_from(value).map_err(|_| MappingError::IntegerOutOfRange {
+                value,
+                arrow_type: T::DATA_TYPE,
+            })
+        }),
+        _ => T::identical(nullable),
+    }
+}
+
+/// Relational type of the column, for arrow types which can be fetched without knowing it, yet
+/// use it to pick a more specific strategy. If the column can not be described, the type is
+/// `Unknown`, so the column is fetched as it would be without the distinction, rather than failing.
+fn col_data_type_or_unknown(
+    query_metadata: &mut impl ResultSetMetadata,
+    col_index: u16,
+) -> OdbcDataType {
+    query_metadata
+        .col_data_type(col_index)
+        .unwrap_or_else(|error| {
+            debug!("Unable to describe column {}: {error}", col_index - 1);
+            OdbcDataType::Unknown
+        })
+}
+
+/// `true` if the relational type is a GUID, e.g. `UNIQUEIDENTIFIER` in Microsoft SQL Server.
+fn is_guid(sql_type: OdbcDataType) -> bool {
+    matches!(
+        sql_type,
+        OdbcDataType::Other { data_type, .. } if data_type == SqlDataType::EXT_GUID
+    )
+}
+
+/// `true` if the column is an unsigned `BIGINT`, e.g. MySQL `BIGINT UNSIGNED`. `false` if the
+/// column can not be described, so it is fetched as it would be without the distinction.
+fn is_unsigned_big_int(query_metadata: &mut impl ResultSetMetadata, col_index: u16) -> bool {
+    col_data_type_or_unknown(query_metadata, col_index) == OdbcDataType::BigInt
+        && query_metadata
+            .column_is_unsigned(col_index)
+            .unwrap_or_else(|error| {
+                debug!("Unable to describe column {}: {error}", col_index - 1);
+                false
+            })
+}
+
+/// ODBC has no unsigned integer types, apart from `TINYINT` for some drivers. We bind the next
+/// wider signed integer `S` and convert the values into the unsigned arrow type. `UInt64` is bound
+/// as `i64`, since there is no wider one. Negative values can not be represented.
+fn widened_unsigned_strategy<T, S>(
+    nullable: bool,
+    on_value_error: OnValueError,
+) -> Box<dyn ReadStrategy + Send>
+where
+    T: ArrowPrimitiveType + Send,
+    T::Native: TryFrom<S>,
+    S: Item + Copy + Into<i64> + Send + 'static,
+{
+    T::map_falliable(nullable, on_value_error, |&value: &S| {
+        T::Native::try_from(value).map_err(|_| MappingError::IntegerOutOfRange {
+            value: value.into(),
+            arrow_type: T::DATA_TYPE,
+        })
+    })
+}
+
 /// Read error related to a specific column
 #[derive(Error, Debug)]
 pub enum ColumnFailure {
@@ -247,6 +736,12 @@ pub enum ColumnFailure {
         num_elements: usize,
         element_size: usize,
     },
+    /// The sentinel replacing `NULL` is not representable in the arrow type of the column.
+    #[error("Sentinel {sentinel} for NULL values is not representable as {arrow_type}.")]
+    NullFillOutOfRange {
+        sentinel: i64,
+        arrow_type: ArrowDataType,
+    },
 }
 
 impl ColumnFailure {
@@ -0,0 +1,138 @@
+use arrow::{datatypes::SchemaRef, error::ArrowError, record_batch::RecordBatch};
+use odbc_api::{
+    buffers::ColumnarAnyBuffer,
+    handles::{AsStatementRef, StatementRef},
+    BlockCursorPolling, CursorPolling, ResultSetMetadata, Sleep,
+};
+
+use super::{
+    deadline::Deadline, odbc_reader::odbc_to_arrow_error, progress::Progress, row_limit::RowLimit,
+    to_record_batch::ToRecordBatch,
+};
+
+/// Asynchronous sibling of [`crate::OdbcReader`]. Fetches arrow record batches from a cursor in
+/// asynchronous polling mode, so your executor is not blocked while waiting for the data source.
+/// Create it using [`crate::OdbcReaderBuilder::build_async`].
+///
+/// Since `next` takes the sleep function used between polls, this type does not implement
+/// `Stream` itself, but you can adapt it to one, using e.g. `futures::stream::unfold`.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::future::Future;
+/// use arrow_odbc::{odbc_api::Connection, OdbcReaderBuilder};
+///
+/// // `sleep` is called between polls, e.g. `|| tokio::time::sleep(Duration::from_millis(50))`.
+/// async fn fetch_all<F: Future>(
+///     connection: &Connection<'_>,
+///     sleep: impl Fn() -> F,
+/// ) -> Result<(), anyhow::Error> {
+///     let cursor = connection
+///         .execute_polling("SELECT * FROM MyTable", (), &sleep)
+///         .await?
+///         .expect("SELECT statement must produce a cursor");
+///     let mut reader = OdbcReaderBuilder::new().build_async(cursor)?;
+///     while let Some(batch) = reader.next(&sleep).await {
+///         let batch = batch?;
+///         // ... process batch ...
+///     }
+///     Ok(())
+/// }
+/// ```
+pub struct AsyncOdbcReader<S: AsStatementRef> {
+    /// Converts the content of ODBC buffers into Arrow record batches
+    converter: ToRecordBatch,
+    /// Fetches values from the ODBC datasource using columnar batches, polling the data source
+    /// until each batch is ready.
+    batch_stream: BlockCursorPolling<CursorPolling<S>, ColumnarAnyBuffer>,
+    /// `Some` if the reader stops fetching after a point in time.
+    deadline: Option<Deadline>,
+    /// `Some` if the user wants to be notified about the number of rows fetched so far.
+    progress: Option<Progress>,
+    /// `Some` if the reader stops after emitting a total number of rows.
+    row_limit: Option<RowLimit>,
+}
+
+impl<S> AsyncOdbcReader<S>
+where
+    S: AsStatementRef,
+{
+    /// The schema implied by `batch_stream` and `converter` must match. Users should use
+    /// [`crate::OdbcReaderBuilder::build_async`] instead.
+    pub(crate) fn new(
+        converter: ToRecordBatch,
+        batch_stream: BlockCursorPolling<CursorPolling<S>, ColumnarAnyBuffer>,
+        deadline: Option<Deadline>,
+        progress: Option<Progress>,
+        row_limit: Option<RowLimit>,
+    ) -> Self {
+        Self {
+            converter,
+            batch_stream,
+            deadline,
+            progress,
+            row_limit,
+        }
+    }
+
+    /// Arrow schema of the record batches emitted by this reader.
+    pub fn schema(&self) -> SchemaRef {
+        self.converter.schema().clone()
+    }
+
+    /// Fetches the next batch from the data source and converts it into a record batch. `sleep`
+    /// is awaited between polls, until the data source has finished fetching. `None` once the
+    /// result set is exhausted.
+    pub async fn next(&mut self, sleep: impl Sleep) -> Option<Result<RecordBatch, ArrowError>> {
+        if let Some(item) = self.deadline.as_mut().and_then(Deadline::check) {
+            return item;
+        }
+        if self.row_limit.as_ref().is_some_and(RowLimit::is_reached) {
+            return None;
+        }
+        match self
+            .batch_stream
+            .fetch_with_truncation_check(true, sleep)
+            .await
+        {
+            // We successfully fetched a batch from the database. Try to copy it into a record batch
+            // and forward errors if any.
+            Ok(Some(batch)) => {
+                if let Some(progress) = &mut self.progress {
+                    progress.report(batch.num_rows());
+                }
+                let result_record_batch = self
+                    .converter
+                    .buffer_to_record_batch(batch)
+                    .map_err(|mapping_error| ArrowError::ExternalError(Box::new(mapping_error)));
+                let result_record_batch = match &mut self.row_limit {
+                    Some(row_limit) => result_record_batch.map(|batch| row_limit.apply(batch)),
+                    None => result_record_batch,
+                };
+                Some(result_record_batch)
+            }
+            // We ran out of batches in the result set. End the iteration.
+            Ok(None) => None,
+            // We had an error fetching the next batch from the database, let's report it as an
+            // external error.
+            Err(odbc_error) => Some(Err(odbc_to_arrow_error(odbc_error))),
+        }
+    }
+}
+
+/// Grants access to the metadata of a cursor in asynchronous polling mode. The metadata is queried
+/// synchronously, since drivers know it without another roundtrip, once the query has been
+/// executed.
+pub struct PollingMetadata<'c, S: AsStatementRef>(pub &'c mut CursorPolling<S>);
+
+impl<S> AsStatementRef for PollingMetadata<'_, S>
+where
+    S: AsStatementRef,
+{
+    fn as_stmt_ref(&mut self) -> StatementRef<'_> {
+        self.0.as_stmt_ref()
+    }
+}
+
+impl<S> ResultSetMetadata for PollingMetadata<'_, S> where S: AsStatementRef {}
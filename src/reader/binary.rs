@@ -68,3 +68,44 @@ impl ReadStrategy for FixedSizedBinary {
         Ok(Arc::new(builder.finish()))
     }
 }
+
+/// Fetches GUIDs (e.g. `UNIQUEIDENTIFIER` in Microsoft SQL Server) as 16 bytes in canonical (RFC
+/// 4122) order. Bound as binary, drivers return the bytes of the `SQLGUID` structure in memory. Its
+/// first three fields are integers in the byte order of the platform, so we reorder them to big
+/// endian.
+pub struct Guid;
+
+/// Length of a GUID in bytes
+const GUID_LEN: usize = 16;
+
+impl ReadStrategy for Guid {
+    fn buffer_desc(&self) -> BufferDesc {
+        BufferDesc::Binary { length: GUID_LEN }
+    }
+
+    fn fill_arrow_array(&self, column_view: AnySlice) -> Result<ArrayRef, MappingError> {
+        let view = column_view.as_bin_view().unwrap();
+        let mut builder = FixedSizeBinaryBuilder::new(GUID_LEN.try_into().unwrap());
+        for value in view.iter() {
+            if let Some(bytes) = value {
+                builder.append_value(canonical_guid(bytes)).unwrap();
+            } else {
+                builder.append_null();
+            }
+        }
+        Ok(Arc::new(builder.finish()))
+    }
+}
+
+/// Reorders the bytes of a `SQLGUID` structure into canonical order.
+fn canonical_guid(sql_guid: &[u8]) -> [u8; GUID_LEN] {
+    let data_1 = u32::from_ne_bytes(sql_guid[0..4].try_into().unwrap());
+    let data_2 = u16::from_ne_bytes(sql_guid[4..6].try_into().unwrap());
+    let data_3 = u16::from_ne_bytes(sql_guid[6..8].try_into().unwrap());
+    let mut canonical = [0; GUID_LEN];
+    canonical[0..4].copy_from_slice(&data_1.to_be_bytes());
+    canonical[4..6].copy_from_slice(&data_2.to_be_bytes());
+    canonical[6..8].copy_from_slice(&data_3.to_be_bytes());
+    canonical[8..].copy_from_slice(&sql_guid[8..GUID_LEN]);
+    canonical
+}
@@ -0,0 +1,81 @@
+use std::sync::{Arc, Mutex};
+
+use odbc_api::{
+    buffers::{BufferDesc, ColumnarAnyBuffer},
+    RowSetBuffer,
+};
+
+/// Caches transit buffers, so they can be reused by readers of subsequent queries, rather than
+/// being allocated for each query anew. Buffers are only reused if the buffer descriptions of all
+/// columns and the number of rows per batch match. Queries with a shape not yet seen allocate a
+/// fresh buffer, which is added to the pool once the reader is dropped or destroyed using
+/// `into_cursor`.
+///
+/// Cloning a [`BufferPool`] yields another handle to the same pool. The pool is thread safe, so it
+/// can be shared between many readers, including the ones fetching concurrently.
+///
+/// # Example
+///
+/// ```no_run
+/// use arrow_odbc::{odbc_api::Connection, BufferPool, OdbcReaderBuilder};
+///
+/// fn query_many(connection: &Connection, queries: &[&str]) -> Result<(), anyhow::Error> {
+///     let pool = BufferPool::new();
+///     for query in queries {
+///         let cursor = connection
+///             .execute(query, ())?
+///             .expect("SELECT statement must produce a cursor");
+///         let reader = OdbcReaderBuilder::new().with_buffer_pool(&pool).build(cursor)?;
+///         for batch in reader {
+///             // ... process batch ...
+///         }
+///         // Buffer is returned to the pool then `reader` is dropped.
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct BufferPool {
+    idle: Arc<Mutex<Vec<IdleBuffer>>>,
+}
+
+/// A buffer which is currently not bound to any cursor.
+struct IdleBuffer {
+    descs: Vec<BufferDesc>,
+    buffer: ColumnarAnyBuffer,
+}
+
+impl BufferPool {
+    /// An empty pool
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of buffers currently held by the pool and not in use by any reader.
+    pub fn num_idle_buffers(&self) -> usize {
+        self.idle.lock().unwrap().len()
+    }
+
+    /// Removes all idle buffers from the pool and frees their memory.
+    pub fn clear(&self) {
+        self.idle.lock().unwrap().clear()
+    }
+
+    /// Takes an idle buffer with matching shape out of the pool. `None` if there is no such buffer.
+    pub(crate) fn check_out(
+        &self,
+        row_capacity: usize,
+        descs: &[BufferDesc],
+    ) -> Option<ColumnarAnyBuffer> {
+        let mut idle = self.idle.lock().unwrap();
+        let index = idle.iter().position(|candidate| {
+            candidate.buffer.row_array_size() == row_capacity && candidate.descs == descs
+        })?;
+        Some(idle.swap_remove(index).buffer)
+    }
+
+    /// Puts a buffer, which is no longer used by a reader, into the pool.
+    pub(crate) fn check_in(&self, descs: Vec<BufferDesc>, buffer: ColumnarAnyBuffer) {
+        self.idle.lock().unwrap().push(IdleBuffer { descs, buffer })
+    }
+}
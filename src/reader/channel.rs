@@ -0,0 +1,37 @@
+use std::{
+    sync::mpsc::{sync_channel, Receiver},
+    thread::{self, JoinHandle},
+};
+
+use arrow::{error::ArrowError, record_batch::RecordBatch};
+
+/// Name of the thread driving a reader, which sends its batches into a channel.
+const THREAD_NAME: &str = "arrow-odbc-channel";
+
+/// Moves `reader` into a dedicated system thread, which drives it to completion and sends each
+/// item into a bounded channel with `capacity`. The thread ends once the reader is exhausted, or the
+/// receiver has been dropped. In either case the reader is returned from the thread, so it can be
+/// obtained by joining the handle.
+pub fn spawn_into_channel<R>(
+    mut reader: R,
+    capacity: usize,
+) -> (Receiver<Result<RecordBatch, ArrowError>>, JoinHandle<R>)
+where
+    R: Iterator<Item = Result<RecordBatch, ArrowError>> + Send + 'static,
+{
+    let (sender, receiver) = sync_channel(capacity);
+    // Named, so the thread can be told apart in profilers, debuggers and panic messages.
+    let handle = thread::Builder::new()
+        .name(THREAD_NAME.to_owned())
+        .spawn(move || {
+            for batch in reader.by_ref() {
+                if sender.send(batch).is_err() {
+                    // The receiver has hung up. Nobody is interested in the remaining batches.
+                    break;
+                }
+            }
+            reader
+        })
+        .expect("Failed to spawn thread");
+    (receiver, handle)
+}
@@ -1,3 +1,7 @@
+use std::{sync::mpsc::Receiver, thread::JoinHandle};
+
+use log::warn;
+
 use arrow::{
     datatypes::SchemaRef,
     error::ArrowError,
@@ -5,9 +9,13 @@ use arrow::{
 };
 use odbc_api::{buffers::ColumnarAnyBuffer, BlockCursor, ConcurrentBlockCursor, Cursor};
 
-use crate::Error;
+use crate::{BufferPool, Error};
 
-use super::{odbc_reader::odbc_to_arrow_error, to_record_batch::ToRecordBatch};
+use super::{
+    channel::spawn_into_channel, deadline::Deadline, exact_batch_rows::ExactBatchRows,
+    odbc_reader::odbc_to_arrow_error, progress::Progress, row_limit::RowLimit,
+    to_record_batch::ToRecordBatch,
+};
 
 /// Arrow ODBC reader. Implements the [`arrow::record_batch::RecordBatchReader`] trait so it can be
 /// used to fill Arrow arrays from an ODBC data source. Similar to [`crate::OdbcReader`], yet
@@ -18,6 +26,15 @@ use super::{odbc_reader::odbc_to_arrow_error, to_record_batch::ToRecordBatch};
 /// may be in use by the main thread in order to copy values into arrow arrays, while the other is
 /// used to write values from the database.
 ///
+/// # Errors
+///
+/// An error is emitted exactly once. Every call to `next` after it returns `None`, so iterating
+/// a reader which has failed is safe, but yields no further batches. Errors are reported as
+/// [`ArrowError::ExternalError`]. The boxed source can be downcast to [`odbc_api::Error`] for
+/// errors fetching from the data source, to [`crate::MappingError`] for values which
+/// could not be converted into arrow arrays, or to [`crate::Error`] for anything else (e.g. an
+/// exceeded deadline).
+///
 /// # Example
 ///
 /// ```no_run
@@ -65,39 +82,69 @@ use super::{odbc_reader::odbc_to_arrow_error, to_record_batch::ToRecordBatch};
 ///     Ok(())
 /// }
 /// ```
-pub struct ConcurrentOdbcReader<C: Cursor> {
+pub struct ConcurrentOdbcReader<C: Cursor + Send + 'static> {
     /// We fill the buffers using ODBC concurrently. The buffer currently being filled is bound to
     /// the Cursor. This is the buffer which is unbound and read by the application to fill the
     /// arrow arrays. After being read we will reuse the buffer and bind it to the cursor in order
-    /// to safe allocations.
-    buffer: ColumnarAnyBuffer,
+    /// to safe allocations. Only `None` while the reader is dropped.
+    buffer: Option<ColumnarAnyBuffer>,
     /// Converts the content of ODBC buffers into Arrow record batches
     converter: ToRecordBatch,
     /// Fetches values from the ODBC datasource using columnar batches. Values are streamed batch
-    /// by batch in order to avoid reallocation of the buffers used for tranistion.
-    batch_stream: ConcurrentBlockCursor<C, ColumnarAnyBuffer>,
+    /// by batch in order to avoid reallocation of the buffers used for tranistion. `None` once
+    /// fetching failed, in which case the fetch thread has ended and taken the cursor with it, or
+    /// once the fetch thread has been joined in order to return the cursor.
+    batch_stream: Option<ConcurrentBlockCursor<C, ColumnarAnyBuffer>>,
+    /// Pool the buffer owned by the application is returned to once the reader is destroyed.
+    buffer_pool: Option<BufferPool>,
+    /// `Some` if fetched batches are resliced to have an exact number of rows.
+    exact_batch_rows: Option<ExactBatchRows>,
+    /// `Some` if the reader stops fetching after a point in time.
+    deadline: Option<Deadline>,
+    /// `Some` if the user wants to be notified about the number of rows fetched so far.
+    progress: Option<Progress>,
+    /// `Some` if the reader stops after emitting a total number of rows.
+    row_limit: Option<RowLimit>,
+    /// `true` once an error has been emitted. The iteration ends afterwards.
+    failed: bool,
 }
 
 impl<C: Cursor + Send + 'static> ConcurrentOdbcReader<C> {
     /// The schema implied by `block_cursor` and `converter` must match. Invariant is hard to check
     /// in type system, keep this constructor private to this crate. Users should use
     /// [`crate::OdbcReader::into_concurrent`] instead.
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn from_block_cursor(
         block_cursor: BlockCursor<C, ColumnarAnyBuffer>,
         converter: ToRecordBatch,
         fallibale_allocations: bool,
+        buffer_pool: Option<BufferPool>,
+        exact_batch_rows: Option<ExactBatchRows>,
+        deadline: Option<Deadline>,
+        progress: Option<Progress>,
+        row_limit: Option<RowLimit>,
     ) -> Result<Self, Error> {
         let max_batch_size = block_cursor.row_array_size();
         let batch_stream = ConcurrentBlockCursor::from_block_cursor(block_cursor);
         // Note that we delay buffer allocation until after the fetch thread has started and we
         // start fetching the first row group concurrently as early, not waiting for the buffer
         // allocation to go through.
-        let buffer = converter.allocate_buffer(max_batch_size, fallibale_allocations)?;
+        let buffer = converter.allocate_buffer(
+            max_batch_size,
+            fallibale_allocations,
+            buffer_pool.as_ref(),
+        )?;
 
         Ok(Self {
-            buffer,
+            buffer: Some(buffer),
             converter,
-            batch_stream,
+            batch_stream: Some(batch_stream),
+            buffer_pool,
+            exact_batch_rows,
+            deadline,
+            progress,
+            row_limit,
+            failed: false,
         })
     }
 
@@ -109,40 +156,139 @@ impl<C: Cursor + Send + 'static> ConcurrentOdbcReader<C> {
     /// Due to the concurrent fetching of row groups you can not know how many row groups have been
     /// extracted once the cursor is returned. Unless that is that the entire cursor has been
     /// consumed i.e. [`Self::next`] returned `None`.
-    pub fn into_cursor(self) -> Result<C, odbc_api::Error> {
-        self.batch_stream.into_cursor()
+    ///
+    /// Rows already fetched, but not yet emitted due to
+    /// [`crate::OdbcReaderBuilder::with_exact_batch_rows`] are discarded. If fetching a batch failed
+    /// before, the cursor is already gone and an error is returned.
+    pub fn into_cursor(mut self) -> Result<C, odbc_api::Error> {
+        // The transit buffer owned by the application is returned to the pool by `drop`.
+        match self.batch_stream.take() {
+            Some(batch_stream) => batch_stream.into_cursor(),
+            None => Err(odbc_api::Error::NoDiagnostics {
+                function: "SQLFetch",
+            }),
+        }
+    }
+
+    /// Moves this reader into a dedicated system thread, which drives it to completion and sends the
+    /// batches into a bounded channel with room for `capacity` batches. This decouples the rate at
+    /// which batches are produced from the rate at which they are consumed, e.g. by a pool of
+    /// worker threads. Once the channel is full, fetching is paused until the consumer catches up.
+    ///
+    /// The thread is named `arrow-odbc-channel`. Errors are sent through the channel like any other
+    /// item. The thread stops after the result set is exhausted or once the receiver is dropped.
+    /// Joining the returned handle yields the reader again, so you can e.g. call
+    /// [`Self::into_cursor`] on it to process further result sets.
+    /// Combined with the concurrent fetch of this reader you get a two stage pipeline: One thread
+    /// fetching from the data source, and one thread converting the ODBC buffers into arrow
+    /// arrays.
+    pub fn into_channel(
+        self,
+        capacity: usize,
+    ) -> (Receiver<Result<RecordBatch, ArrowError>>, JoinHandle<Self>) {
+        spawn_into_channel(self, capacity)
     }
 }
 
-impl<C> Iterator for ConcurrentOdbcReader<C>
+impl<C> ConcurrentOdbcReader<C>
 where
-    C: Cursor,
+    C: Cursor + Send + 'static,
 {
-    type Item = Result<RecordBatch, ArrowError>;
+    /// Next item of the iterator, without regard to previous errors.
+    fn next_item(&mut self) -> Option<Result<RecordBatch, ArrowError>> {
+        if let Some(item) = self.deadline.as_mut().and_then(Deadline::check) {
+            return item;
+        }
+        if self.row_limit.as_ref().is_some_and(RowLimit::is_reached) {
+            return None;
+        }
+        let item = if let Some(mut exact_batch_rows) = self.exact_batch_rows.take() {
+            let schema = self.converter.schema().clone();
+            let item = exact_batch_rows.next(&schema, |_| self.fetch_batch());
+            self.exact_batch_rows = Some(exact_batch_rows);
+            item
+        } else {
+            self.fetch_batch()
+        };
+        match &mut self.row_limit {
+            Some(row_limit) => item.map(|result| result.map(|batch| row_limit.apply(batch))),
+            None => item,
+        }
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        match self.batch_stream.fetch_into(&mut self.buffer) {
+    /// Fetches the next batch from the data source and converts it into a record batch.
+    fn fetch_batch(&mut self) -> Option<Result<RecordBatch, ArrowError>> {
+        let (Some(batch_stream), Some(buffer)) = (&mut self.batch_stream, &mut self.buffer) else {
+            return None;
+        };
+        match batch_stream.fetch_into(buffer) {
             // We successfully fetched a batch from the database. Try to copy it into a record batch
             // and forward errors if any.
             Ok(true) => {
+                if let Some(progress) = &mut self.progress {
+                    progress.report(buffer.num_rows());
+                }
                 let result_record_batch = self
                     .converter
-                    .buffer_to_record_batch(&self.buffer)
+                    .buffer_to_record_batch(buffer)
                     .map_err(|mapping_error| ArrowError::ExternalError(Box::new(mapping_error)));
                 Some(result_record_batch)
             }
             // We ran out of batches in the result set. End the iterator.
             Ok(false) => None,
             // We had an error fetching the next batch from the database, let's report it as an
-            // external error.
-            Err(odbc_error) => Some(Err(odbc_to_arrow_error(odbc_error))),
+            // external error. The fetch thread has already ended, so there is nothing left to join.
+            Err(odbc_error) => {
+                self.batch_stream = None;
+                Some(Err(odbc_to_arrow_error(odbc_error)))
+            }
+        }
+    }
+}
+
+impl<C> Drop for ConcurrentOdbcReader<C>
+where
+    C: Cursor + Send + 'static,
+{
+    fn drop(&mut self) {
+        // Dropping the channels of the concurrent block cursor tells the fetch thread to stop.
+        // Joining it ensures it is not fetching from the cursor anymore, once the reader is gone,
+        // even if it has been dropped before the result set is consumed.
+        if let Some(batch_stream) = self.batch_stream.take() {
+            if let Err(odbc_error) = batch_stream.into_cursor() {
+                warn!(
+                    "Fetching in the dedicated thread failed after the last batch.\n{odbc_error}"
+                );
+            }
+        }
+        // Only the buffer owned by the application can be returned to the pool. The other one is
+        // owned by the fetch thread and freed together with it.
+        if let Some(buffer) = self.buffer.take() {
+            self.converter
+                .recycle_buffer(buffer, self.buffer_pool.as_ref());
+        }
+    }
+}
+
+impl<C> Iterator for ConcurrentOdbcReader<C>
+where
+    C: Cursor + Send + 'static,
+{
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
         }
+        let item = self.next_item();
+        self.failed = matches!(item, Some(Err(_)));
+        item
     }
 }
 
 impl<C> RecordBatchReader for ConcurrentOdbcReader<C>
 where
-    C: Cursor,
+    C: Cursor + Send + 'static,
 {
     fn schema(&self) -> SchemaRef {
         self.converter.schema().clone()
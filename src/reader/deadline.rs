@@ -0,0 +1,37 @@
+use std::time::Instant;
+
+use arrow::{error::ArrowError, record_batch::RecordBatch};
+
+use crate::Error;
+
+/// Point in time after which a reader stops fetching batches. See
+/// [`crate::OdbcReaderBuilder::with_deadline`].
+pub struct Deadline {
+    instant: Instant,
+    /// `true` once the deadline has been reported as exceeded.
+    exceeded: bool,
+}
+
+impl Deadline {
+    pub fn new(instant: Instant) -> Self {
+        Self {
+            instant,
+            exceeded: false,
+        }
+    }
+
+    /// `None` if the reader may fetch the next batch. Otherwise the item the reader should emit
+    /// instead. This is an error the first time the deadline is exceeded and the end of the
+    /// iteration afterwards.
+    pub fn check(&mut self) -> Option<Option<Result<RecordBatch, ArrowError>>> {
+        if self.exceeded {
+            return Some(None);
+        }
+        if Instant::now() >= self.instant {
+            self.exceeded = true;
+            let error = ArrowError::ExternalError(Box::new(Error::DeadlineExceeded));
+            return Some(Some(Err(error)));
+        }
+        None
+    }
+}
@@ -1,6 +1,9 @@
 use std::sync::Arc;
 
-use arrow::array::{ArrayRef, Decimal128Builder};
+use arrow::{
+    array::{ArrayRef, Decimal128Builder, Decimal256Builder},
+    datatypes::i256,
+};
 use odbc_api::{
     buffers::{AnySlice, BufferDesc},
     decimal_text_to_i128,
@@ -50,3 +53,77 @@ impl ReadStrategy for Decimal {
         ))
     }
 }
+
+/// Decimals with a precision above 38 digits, which do not fit into an `i128`.
+pub struct Decimal256 {
+    precision: u8,
+    /// We know scale to be non-negative, yet we can save us some conversions storing it as i8.
+    scale: i8,
+}
+
+impl Decimal256 {
+    pub fn new(precision: u8, scale: i8) -> Self {
+        Self { precision, scale }
+    }
+}
+
+impl ReadStrategy for Decimal256 {
+    fn buffer_desc(&self) -> BufferDesc {
+        BufferDesc::Text {
+            // Must be able to hold num precision digits a sign and a decimal point
+            max_str_len: self.precision as usize + 2,
+        }
+    }
+
+    fn fill_arrow_array(&self, column_view: AnySlice) -> Result<ArrayRef, MappingError> {
+        let view = column_view.as_text_view().unwrap();
+        let mut builder = Decimal256Builder::new();
+        let scale = self.scale as usize;
+
+        for opt in view.iter() {
+            if let Some(text) = opt {
+                let num = decimal_text_to_i256(text, scale);
+                builder.append_value(num);
+            } else {
+                builder.append_null();
+            }
+        }
+
+        Ok(Arc::new(
+            builder
+                .finish()
+                .with_precision_and_scale(self.precision, self.scale)
+                .unwrap(),
+        ))
+    }
+}
+
+/// Parses the text representation of a decimal into an integer, scaled by `10^scale`. Like
+/// [`decimal_text_to_i128`] any character which is not a digit or a leading minus is treated as
+/// the radix character, since some databases (e.g. Oracle with `NLS_NUMERIC_CHARACTERS` set to
+/// `,.`) transmit a comma instead of a point. Trailing zeroes may be omitted by the database.
+fn decimal_text_to_i256(text: &[u8], scale: usize) -> i256 {
+    let ten = i256::from_i128(10);
+    let is_negative = text.first() == Some(&b'-');
+    let mut n = i256::ZERO;
+    // `None` until we pass the radix character
+    let mut num_digits_after_radix = None;
+    for &byte in text {
+        if byte.is_ascii_digit() {
+            n = n * ten + i256::from_i128((byte - b'0').into());
+            if let Some(num_digits) = &mut num_digits_after_radix {
+                *num_digits += 1;
+            }
+        } else if byte != b'-' {
+            num_digits_after_radix = Some(0);
+        }
+    }
+    for _ in num_digits_after_radix.unwrap_or(0)..scale {
+        n *= ten;
+    }
+    if is_negative {
+        -n
+    } else {
+        n
+    }
+}
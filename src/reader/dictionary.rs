@@ -0,0 +1,79 @@
+use std::{collections::HashMap, sync::Arc, sync::Mutex};
+
+use arrow::{
+    array::{Array, ArrayRef, DictionaryArray, Int32Array, StringArray},
+    datatypes::Int32Type,
+};
+use odbc_api::buffers::{AnySlice, BufferDesc};
+
+use super::{MappingError, ReadStrategy};
+
+/// Fetches text and emits it dictionary encoded, as `Dictionary(Int32, Utf8)`. Intended for low
+/// cardinality columns, like status codes or country codes. The dictionary is shared by all batches
+/// of the reader, so a value is represented by the same key in every batch. Each emitted array
+/// holds all values encountered so far as its dictionary. Batches without new values share the
+/// dictionary of the previous one, without copying it.
+pub struct DictionaryText {
+    /// Strategy used to fetch the text, before it is encoded.
+    text: Box<dyn ReadStrategy + Send>,
+    dictionary: Mutex<Dictionary>,
+}
+
+impl DictionaryText {
+    pub fn new(text: Box<dyn ReadStrategy + Send>) -> Self {
+        Self {
+            text,
+            dictionary: Mutex::new(Dictionary::default()),
+        }
+    }
+}
+
+/// Distinct values encountered so far, in order of their first occurrence.
+#[derive(Default)]
+struct Dictionary {
+    keys: HashMap<String, i32>,
+    values: Vec<String>,
+    /// `values` as arrow array, shared by all batches emitted since the last value has been added.
+    /// `None` if values have been added since it has been built.
+    array: Option<ArrayRef>,
+}
+
+impl Dictionary {
+    fn key(&mut self, value: &str) -> Result<i32, MappingError> {
+        if let Some(&key) = self.keys.get(value) {
+            return Ok(key);
+        }
+        let key = i32::try_from(self.values.len()).map_err(|_| MappingError::DictionaryOverflow)?;
+        self.keys.insert(value.to_owned(), key);
+        self.values.push(value.to_owned());
+        self.array = None;
+        Ok(key)
+    }
+
+    /// All values encountered so far. Only copied into a new array, if values have been added
+    /// since the last call.
+    fn values(&mut self) -> ArrayRef {
+        self.array
+            .get_or_insert_with(|| Arc::new(StringArray::from_iter_values(&self.values)))
+            .clone()
+    }
+}
+
+impl ReadStrategy for DictionaryText {
+    fn buffer_desc(&self) -> BufferDesc {
+        self.text.buffer_desc()
+    }
+
+    fn fill_arrow_array(&self, column_view: AnySlice) -> Result<ArrayRef, MappingError> {
+        let text = self.text.fill_arrow_array(column_view)?;
+        let text = text.as_any().downcast_ref::<StringArray>().unwrap();
+        let mut dictionary = self.dictionary.lock().unwrap();
+        let keys: Int32Array = text
+            .iter()
+            .map(|value| value.map(|value| dictionary.key(value)).transpose())
+            .collect::<Result<_, _>>()?;
+        let array = DictionaryArray::<Int32Type>::try_new(keys, dictionary.values())
+            .expect("Keys of dictionary must be in range of its values");
+        Ok(Arc::new(array))
+    }
+}
@@ -0,0 +1,70 @@
+use std::cmp::min;
+
+use arrow::{
+    compute::concat_batches, datatypes::SchemaRef, error::ArrowError, record_batch::RecordBatch,
+};
+
+/// Reslices the record batches fetched from the data source, so each emitted batch has exactly
+/// the same number of rows. Only the last batch of a result set may have fewer rows. ODBC row set
+/// boundaries are not visible to the consumer.
+pub struct ExactBatchRows {
+    /// Number of rows in each emitted batch
+    num_rows: usize,
+    /// Fetched rows, which have not been emitted yet.
+    pending: Vec<RecordBatch>,
+}
+
+impl ExactBatchRows {
+    pub fn new(num_rows: usize) -> Self {
+        Self {
+            num_rows,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Number of rows fetched, which have not been emitted yet.
+    pub fn num_pending(&self) -> usize {
+        self.pending.iter().map(RecordBatch::num_rows).sum()
+    }
+
+    /// Calls `fetch` until enough rows for a batch are accumulated, or the result set is
+    /// exhausted. `fetch` is invoked with the number of rows accumulated so far, i.e. the index of
+    /// the first fetched row within the emitted batch. Errors of `fetch` are forwarded
+    /// immediately. Rows fetched so far are kept and emitted with the next call.
+    pub fn next(
+        &mut self,
+        schema: &SchemaRef,
+        mut fetch: impl FnMut(usize) -> Option<Result<RecordBatch, ArrowError>>,
+    ) -> Option<Result<RecordBatch, ArrowError>> {
+        let mut num_pending = self.num_pending();
+        while num_pending < self.num_rows {
+            match fetch(num_pending) {
+                Some(Ok(batch)) => {
+                    num_pending += batch.num_rows();
+                    self.pending.push(batch);
+                }
+                Some(Err(error)) => return Some(Err(error)),
+                None => break,
+            }
+        }
+        if num_pending == 0 {
+            return None;
+        }
+        // Avoid copying the values, in case the rows are already in one batch.
+        let batch = if self.pending.len() == 1 {
+            self.pending.pop().unwrap()
+        } else {
+            let batch = match concat_batches(schema, &self.pending) {
+                Ok(batch) => batch,
+                Err(error) => return Some(Err(error)),
+            };
+            self.pending.clear();
+            batch
+        };
+        let len = min(self.num_rows, num_pending);
+        if num_pending > len {
+            self.pending.push(batch.slice(len, num_pending - len));
+        }
+        Some(Ok(batch.slice(0, len)))
+    }
+}
@@ -0,0 +1,161 @@
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, DurationNanosecondBuilder};
+use odbc_api::{
+    buffers::{AnySlice, BufferDesc},
+    sys::SqlDataType,
+    DataType as OdbcDataType,
+};
+
+use super::{MappingError, OnValueError, ReadStrategy};
+
+/// `SQL_INTERVAL_DAY_TO_SECOND`. Not part of `odbc_sys`.
+const INTERVAL_DAY_TO_SECOND: SqlDataType = SqlDataType(110);
+
+/// Sign, up to nine digits of leading precision for the days, `hh:mm:ss` and a fraction of up to
+/// nine digits.
+const MAX_STR_LEN: usize = 1 + 9 + 1 + 8 + 1 + 9;
+
+/// `true` if the relational type is `INTERVAL DAY TO SECOND`.
+pub fn is_interval_day_to_second(sql_type: OdbcDataType) -> bool {
+    matches!(sql_type, OdbcDataType::Other { data_type, .. } if data_type == INTERVAL_DAY_TO_SECOND)
+}
+
+/// Fetches `INTERVAL DAY TO SECOND` columns as nanoseconds, like `Duration(Nanosecond)`. Values are
+/// transmitted as text in the format the ODBC standard specifies for converting intervals to
+/// characters, e.g. `-1 02:03:04.5`.
+pub struct IntervalDayToSecond {
+    /// What to do with values which can not be parsed or are out of range.
+    on_value_error: OnValueError,
+}
+
+impl IntervalDayToSecond {
+    pub fn new(on_value_error: OnValueError) -> Self {
+        Self { on_value_error }
+    }
+}
+
+impl ReadStrategy for IntervalDayToSecond {
+    fn buffer_desc(&self) -> BufferDesc {
+        BufferDesc::Text {
+            max_str_len: MAX_STR_LEN,
+        }
+    }
+
+    fn fill_arrow_array(&self, column_view: AnySlice) -> Result<ArrayRef, MappingError> {
+        let view = column_view.as_text_view().unwrap();
+        let mut builder = DurationNanosecondBuilder::with_capacity(view.len());
+        for (row, value) in view.iter().enumerate() {
+            let opt = if let Some(text) = value {
+                match parse_day_to_second_ns(text) {
+                    Some(ns) => Some(ns),
+                    None => {
+                        self.on_value_error.handle(
+                            row,
+                            MappingError::InvalidInterval {
+                                value: String::from_utf8_lossy(text).into_owned(),
+                            },
+                        )?;
+                        None
+                    }
+                }
+            } else {
+                None
+            };
+            builder.append_option(opt);
+        }
+        Ok(Arc::new(builder.finish()))
+    }
+}
+
+/// Parses `[-]d hh:mm:ss[.fffffffff]` into nanoseconds. `None` if the text does not match the
+/// format, or the interval does not fit into a 64 Bit integer.
+fn parse_day_to_second_ns(text: &[u8]) -> Option<i64> {
+    let text = std::str::from_utf8(text).ok()?.trim();
+    let (is_negative, text) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text),
+    };
+    let (days, time) = text.split_once(' ')?;
+    let (time, fraction) = time.split_once('.').unwrap_or((time, ""));
+    let mut parts = time.split(':');
+    let (hours, minutes, seconds) = (parts.next()?, parts.next()?, parts.next()?);
+    if parts.next().is_some() || fraction.len() > 9 {
+        return None;
+    }
+    let number = |digits: &str| -> Option<i64> {
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        digits.parse().ok()
+    };
+    let seconds = number(days)?
+        .checked_mul(24)?
+        .checked_add(number(hours)?)?
+        .checked_mul(60)?
+        .checked_add(number(minutes)?)?
+        .checked_mul(60)?
+        .checked_add(number(seconds)?)?;
+    let fraction_ns = if fraction.is_empty() {
+        0
+    } else {
+        number(fraction)? * 10i64.pow(9 - fraction.len() as u32)
+    };
+    let ns = seconds
+        .checked_mul(1_000_000_000)?
+        .checked_add(fraction_ns)?;
+    Some(if is_negative { -ns } else { ns })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_day_to_second_ns;
+
+    #[test]
+    fn parse_days_hours_minutes_seconds_and_fraction() {
+        assert_eq!(
+            Some(93_784_500_000_000),
+            parse_day_to_second_ns(b"1 02:03:04.5")
+        );
+        assert_eq!(
+            Some(3_723_000_000_000),
+            parse_day_to_second_ns(b"0 01:02:03")
+        );
+        assert_eq!(Some(1), parse_day_to_second_ns(b"0 00:00:00.000000001"));
+    }
+
+    #[test]
+    fn parse_negative_interval() {
+        assert_eq!(
+            Some(-93_784_500_000_000),
+            parse_day_to_second_ns(b"-1 02:03:04.5")
+        );
+    }
+
+    #[test]
+    fn parse_rejects_fraction_with_more_than_nine_digits() {
+        assert_eq!(None, parse_day_to_second_ns(b"0 00:00:00.0000000001"));
+    }
+
+    #[test]
+    fn parse_rejects_missing_components() {
+        assert_eq!(None, parse_day_to_second_ns(b"02:03:04"));
+        assert_eq!(None, parse_day_to_second_ns(b"1 02:03"));
+        assert_eq!(None, parse_day_to_second_ns(b"1 02::04"));
+        assert_eq!(None, parse_day_to_second_ns(b""));
+    }
+
+    #[test]
+    fn parse_rejects_intervals_overflowing_64_bit_nanoseconds() {
+        assert_eq!(
+            Some(i64::MAX),
+            parse_day_to_second_ns(b"106751 23:47:16.854775807")
+        );
+        assert_eq!(None, parse_day_to_second_ns(b"106751 23:47:16.854775808"));
+        assert_eq!(None, parse_day_to_second_ns(b"106752 00:00:00"));
+        assert_eq!(
+            None,
+            parse_day_to_second_ns(b"99999999999999999999 00:00:00")
+        );
+    }
+}
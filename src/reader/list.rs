@@ -0,0 +1,214 @@
+use std::{marker::PhantomData, str::FromStr, sync::Arc};
+
+use arrow::{
+    array::{
+        Array, ArrayRef, FixedSizeListBuilder, ListBuilder, PrimitiveBuilder, StringArray,
+        StringBuilder,
+    },
+    datatypes::{
+        ArrowPrimitiveType, DataType as ArrowDataType, FieldRef, Float32Type, Float64Type,
+        Int16Type, Int32Type, Int64Type,
+    },
+};
+use odbc_api::buffers::{AnySlice, BufferDesc};
+
+use super::{MappingError, OnValueError, ReadStrategy};
+
+/// Fetches text and splits each value at commas into a list of strings. E.g. MySQL transmits
+/// values of `SET` columns this way (`'a,c'`). An empty string results in an empty list.
+pub struct TextToList {
+    /// Strategy used to fetch the text, before it is split.
+    text: Box<dyn ReadStrategy + Send>,
+    /// Field describing the items of the list, as declared in the arrow schema.
+    item: FieldRef,
+}
+
+impl TextToList {
+    pub fn new(text: Box<dyn ReadStrategy + Send>, item: FieldRef) -> Self {
+        Self { text, item }
+    }
+}
+
+impl ReadStrategy for TextToList {
+    fn buffer_desc(&self) -> BufferDesc {
+        self.text.buffer_desc()
+    }
+
+    fn fill_arrow_array(&self, column_view: AnySlice) -> Result<ArrayRef, MappingError> {
+        let text = self.text.fill_arrow_array(column_view)?;
+        let text = text.as_any().downcast_ref::<StringArray>().unwrap();
+        let mut builder = ListBuilder::with_capacity(StringBuilder::new(), text.len())
+            .with_field(self.item.clone());
+        for value in text.iter() {
+            if let Some(value) = value {
+                let labels = value.split(',').filter(|label| !label.is_empty());
+                for label in labels {
+                    builder.values().append_value(label);
+                }
+                builder.append(true);
+            } else {
+                builder.append_null();
+            }
+        }
+        Ok(Arc::new(builder.finish()))
+    }
+}
+
+/// Strategy for a `FixedSizeList` column of the arrow schema, whose values are transmitted as text
+/// by `text`. `None` if the items are not of a numeric type, or if `length` is negative.
+pub fn text_to_fixed_size_list(
+    text: Box<dyn ReadStrategy + Send>,
+    item: FieldRef,
+    length: i32,
+    on_value_error: OnValueError,
+) -> Option<Box<dyn ReadStrategy + Send>> {
+    if length < 0 {
+        return None;
+    }
+    let strategy: Box<dyn ReadStrategy + Send> = match item.data_type() {
+        ArrowDataType::Int16 => Box::new(TextToFixedSizeList::<Int16Type>::new(
+            text,
+            item,
+            length,
+            on_value_error,
+        )),
+        ArrowDataType::Int32 => Box::new(TextToFixedSizeList::<Int32Type>::new(
+            text,
+            item,
+            length,
+            on_value_error,
+        )),
+        ArrowDataType::Int64 => Box::new(TextToFixedSizeList::<Int64Type>::new(
+            text,
+            item,
+            length,
+            on_value_error,
+        )),
+        ArrowDataType::Float32 => Box::new(TextToFixedSizeList::<Float32Type>::new(
+            text,
+            item,
+            length,
+            on_value_error,
+        )),
+        ArrowDataType::Float64 => Box::new(TextToFixedSizeList::<Float64Type>::new(
+            text,
+            item,
+            length,
+            on_value_error,
+        )),
+        _ => return None,
+    };
+    Some(strategy)
+}
+
+/// Fetches text holding arrays in the representation of PostgreSQL, e.g. `{1,2,3}`, and parses
+/// the elements into a list of fixed length. Elements spelled `NULL` are null.
+pub struct TextToFixedSizeList<T> {
+    /// Strategy used to fetch the text, before it is parsed.
+    text: Box<dyn ReadStrategy + Send>,
+    /// Field describing the items of the list, as declared in the arrow schema.
+    item: FieldRef,
+    /// Number of elements each array must have.
+    length: i32,
+    /// What to do with arrays which can not be parsed, or have the wrong number of elements.
+    on_value_error: OnValueError,
+    _item_type: PhantomData<T>,
+}
+
+impl<T> TextToFixedSizeList<T> {
+    /// `length` must not be negative. This is checked by [`text_to_fixed_size_list`].
+    pub fn new(
+        text: Box<dyn ReadStrategy + Send>,
+        item: FieldRef,
+        length: i32,
+        on_value_error: OnValueError,
+    ) -> Self {
+        Self {
+            text,
+            item,
+            length,
+            on_value_error,
+            _item_type: PhantomData,
+        }
+    }
+
+    /// Number of elements of each list, as `usize`.
+    fn item_count(&self) -> usize {
+        self.length.try_into().unwrap()
+    }
+}
+
+impl<T> TextToFixedSizeList<T>
+where
+    T: ArrowPrimitiveType,
+    T::Native: FromStr,
+{
+    /// Elements of the array. `None` if the text is not enclosed in braces, an element is not a
+    /// number, the number of elements does not match, or an element is `NULL` even though the
+    /// items are not nullable.
+    fn parse(&self, text: &str) -> Option<Vec<Option<T::Native>>> {
+        let inner = text.trim().strip_prefix('{')?.strip_suffix('}')?;
+        let elements = if inner.trim().is_empty() {
+            Vec::new()
+        } else {
+            inner
+                .split(',')
+                .map(|element| match element.trim() {
+                    "NULL" if self.item.is_nullable() => Some(None),
+                    element => element.parse().ok().map(Some),
+                })
+                .collect::<Option<Vec<_>>>()?
+        };
+        (elements.len() == self.item_count()).then_some(elements)
+    }
+}
+
+impl<T> ReadStrategy for TextToFixedSizeList<T>
+where
+    T: ArrowPrimitiveType,
+    T::Native: FromStr,
+{
+    fn buffer_desc(&self) -> BufferDesc {
+        self.text.buffer_desc()
+    }
+
+    fn fill_arrow_array(&self, column_view: AnySlice) -> Result<ArrayRef, MappingError> {
+        let text = self.text.fill_arrow_array(column_view)?;
+        let text = text.as_any().downcast_ref::<StringArray>().unwrap();
+        let values = PrimitiveBuilder::<T>::with_capacity(text.len() * self.item_count());
+        let mut builder = FixedSizeListBuilder::with_capacity(values, self.length, text.len())
+            .with_field(self.item.clone());
+        for (row, value) in text.iter().enumerate() {
+            let elements = if let Some(value) = value {
+                match self.parse(value) {
+                    Some(elements) => Some(elements),
+                    None => {
+                        self.on_value_error.handle(
+                            row,
+                            MappingError::InvalidArray {
+                                value: value.to_owned(),
+                                length: self.length,
+                            },
+                        )?;
+                        None
+                    }
+                }
+            } else {
+                None
+            };
+            if let Some(elements) = elements {
+                for element in elements {
+                    builder.values().append_option(element);
+                }
+                builder.append(true);
+            } else {
+                // Children must hold a value for each element, even if the list itself is `NULL`.
+                for _ in 0..self.item_count() {
+                    builder.values().append_value(T::Native::default());
+                }
+                builder.append(false);
+            }
+        }
+        Ok(Arc::new(builder.finish()))
+    }
+}
@@ -0,0 +1,93 @@
+use std::{borrow::Cow, sync::Arc};
+
+use arrow::array::{ArrayRef, StringArray};
+use odbc_api::{
+    handles::{AsStatementRef, Statement},
+    parameter::VarCharArray,
+};
+
+use super::{InvalidUtf8Policy, MappingError};
+
+/// Size of the buffer in bytes, each piece of a long value is fetched into.
+const CHUNK_SIZE: usize = 8192;
+
+/// Text column, which is not bound to the transit buffer. Its values are fetched piece by piece
+/// using `SQLGetData`, after each row has been fetched. This way the size of the values is only
+/// limited by memory, rather than the size of the transit buffer. See
+/// [`crate::OdbcReaderBuilder::with_long_data_streaming`].
+pub struct LongText {
+    /// Name of the column. Used to report invalid UTF-8.
+    column_name: String,
+    /// One based index of the column in the result set.
+    col_index: u16,
+    invalid_utf8_policy: InvalidUtf8Policy,
+    trim: bool,
+    /// Emit `NULL` for empty strings.
+    empty_as_null: bool,
+}
+
+impl LongText {
+    pub fn new(
+        column_name: String,
+        col_index: u16,
+        invalid_utf8_policy: InvalidUtf8Policy,
+        trim: bool,
+        empty_as_null: bool,
+    ) -> Self {
+        Self {
+            column_name,
+            col_index,
+            invalid_utf8_policy,
+            trim,
+            empty_as_null,
+        }
+    }
+
+    /// Fetches the value of the current row of the cursor into `buf`. `false` if the value is
+    /// `NULL`.
+    pub fn fetch(
+        &self,
+        cursor: &mut impl AsStatementRef,
+        buf: &mut Vec<u8>,
+    ) -> Result<bool, odbc_api::Error> {
+        buf.clear();
+        let mut stmt = cursor.as_stmt_ref();
+        let mut chunk = VarCharArray::<CHUNK_SIZE>::NULL;
+        loop {
+            stmt.get_data(self.col_index, &mut chunk)
+                .into_result(&stmt)?;
+            let Some(bytes) = chunk.as_bytes() else {
+                return Ok(false);
+            };
+            buf.extend_from_slice(bytes);
+            if chunk.is_complete() {
+                return Ok(true);
+            }
+        }
+    }
+
+    /// Array with a single element, holding a value obtained with [`Self::fetch`]. `row` is the
+    /// index of the row within the emitted batch, used to report invalid UTF-8.
+    pub fn to_array(&self, value: Option<&[u8]>, row: usize) -> Result<ArrayRef, MappingError> {
+        let Some(bytes) = value else {
+            return Ok(Arc::new(StringArray::new_null(1)));
+        };
+        let text = match (std::str::from_utf8(bytes), self.invalid_utf8_policy) {
+            (Ok(text), _) => Cow::Borrowed(text),
+            (Err(_), InvalidUtf8Policy::Replace) => String::from_utf8_lossy(bytes),
+            (Err(_), InvalidUtf8Policy::Null) => return Ok(Arc::new(StringArray::new_null(1))),
+            (Err(utf8_error), InvalidUtf8Policy::Error) => {
+                return Err(MappingError::InvalidUtf8 {
+                    column: self.column_name.clone(),
+                    row,
+                    byte_offset: utf8_error.valid_up_to(),
+                });
+            }
+        };
+        let text = if self.trim { text.trim() } else { &text };
+        if self.empty_as_null && text.is_empty() {
+            return Ok(Arc::new(StringArray::new_null(1)));
+        }
+        Ok(Arc::new(StringArray::from(vec![text])))
+    }
+}
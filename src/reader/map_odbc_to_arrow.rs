@@ -2,13 +2,15 @@ use std::{marker::PhantomData, sync::Arc};
 
 use arrow::{
     array::{ArrayRef, PrimitiveBuilder},
-    datatypes::ArrowPrimitiveType,
+    datatypes::{ArrowPrimitiveType, DataType},
 };
 use chrono::NaiveDateTime;
 use odbc_api::buffers::{AnySlice, BufferDesc, Item};
 use thiserror::Error;
 
-use super::ReadStrategy;
+use crate::BoolEncoding;
+
+use super::{OnValueError, ReadStrategy};
 
 /// Extend an arrow primitive type to serve as a builder for Read strategies.
 pub trait MapOdbcToArrow {
@@ -18,7 +20,7 @@ pub trait MapOdbcToArrow {
     /// element of an arrow array. This method assumes the conversion is falliable.
     fn map_falliable<U>(
         nullable: bool,
-        map_errors_to_null: bool,
+        on_value_error: OnValueError,
         odbc_to_arrow: impl Fn(&U) -> Result<Self::ArrowElement, MappingError> + 'static + Send,
     ) -> Box<dyn ReadStrategy + Send>
     where
@@ -48,14 +50,17 @@ where
 
     fn map_falliable<U>(
         nullable: bool,
-        map_errors_to_null: bool,
+        on_value_error: OnValueError,
         odbc_to_arrow: impl Fn(&U) -> Result<Self::ArrowElement, MappingError> + 'static + Send,
     ) -> Box<dyn ReadStrategy + Send>
     where
         U: Item + 'static + Send,
     {
-        if map_errors_to_null {
-            return Box::new(ErrorToNullStrategy::<Self, U, _>::new(odbc_to_arrow));
+        if on_value_error.may_emit_null() {
+            return Box::new(ErrorToNullStrategy::<Self, U, _>::new(
+                on_value_error,
+                odbc_to_arrow,
+            ));
         }
 
         if nullable {
@@ -264,18 +269,21 @@ where
     }
 }
 
-/// Map invalid values to `NULL` rather than emitting a [`MappingError`]`.
+/// Map invalid values to `NULL` rather than emitting a [`MappingError`]`, unless a handler of the
+/// user decides otherwise.
 struct ErrorToNullStrategy<P, O, F> {
     _primitive_type: PhantomData<P>,
     _odbc_item: PhantomData<O>,
+    on_value_error: OnValueError,
     odbc_to_arrow: F,
 }
 
 impl<P, O, F> ErrorToNullStrategy<P, O, F> {
-    fn new(odbc_to_arrow: F) -> Self {
+    fn new(on_value_error: OnValueError, odbc_to_arrow: F) -> Self {
         Self {
             _primitive_type: PhantomData,
             _odbc_item: PhantomData,
+            on_value_error,
             odbc_to_arrow,
         }
     }
@@ -294,8 +302,15 @@ where
     fn fill_arrow_array(&self, column_view: AnySlice) -> Result<ArrayRef, MappingError> {
         let opts = column_view.as_nullable_slice::<O>().unwrap();
         let mut builder = PrimitiveBuilder::<P>::with_capacity(opts.len());
-        for odbc_opt in opts {
-            builder.append_option(odbc_opt.and_then(|val| (self.odbc_to_arrow)(val).ok()));
+        for (row, odbc_opt) in opts.enumerate() {
+            let opt = match odbc_opt.map(&self.odbc_to_arrow).transpose() {
+                Ok(opt) => opt,
+                Err(error) => {
+                    self.on_value_error.handle(row, error)?;
+                    None
+                }
+            };
+            builder.append_option(opt);
         }
         Ok(Arc::new(builder.finish()))
     }
@@ -313,4 +328,89 @@ pub enum MappingError {
         Suggestions to fix this error either reduce the precision or fetch the values as text."
     )]
     OutOfRangeTimestampNs { value: NaiveDateTime },
+    #[error(
+        "Date {year:04}-{month:02}-{day:02} returned by the database does not exist in the \
+        calendar. Some databases (e.g. MySQL) permit zero dates like 0000-00-00. Suggestions to \
+        fix this error are to map value errors to NULL, or to fetch the values as text."
+    )]
+    InvalidDate { year: i16, month: u16, day: u16 },
+    #[error(
+        "Fraction {fraction} of a timestamp returned by the database is not a valid number of \
+        nanoseconds. ODBC drivers are expected to return fractions of a second in nanoseconds, so \
+        the value must be smaller than 1000000000. This usually indicates a driver using a \
+        different unit."
+    )]
+    InvalidTimestampFraction { fraction: u32 },
+    #[error(
+        "Interval '{value}' returned by the database is not of the form '[-]d hh:mm:ss[.f]', or \
+        can not be represented as nanoseconds in a 64 Bit integer. Suggestions to fix this error \
+        are to map value errors to NULL, or to fetch the values as text."
+    )]
+    InvalidInterval { value: String },
+    #[error(
+        "Time '{value}' returned by the database is not of the form 'hh:mm:ss[.f]'. Suggestions to \
+        fix this error are to map value errors to NULL, or to fetch the values as text."
+    )]
+    InvalidTime { value: String },
+    #[error(
+        "Timestamp '{value}' returned by the database is not of the form \
+        'yyyy-mm-dd hh:mm:ss[.f] +hh:mm', or can not be represented in the unit of the arrow \
+        type. Suggestions to fix this error are to map value errors to NULL, or to fetch the \
+        values as text."
+    )]
+    InvalidTimestampWithOffset { value: String },
+    #[error(
+        "Value '{value}' is not a valid representation of a boolean using encoding {encoding:?}."
+    )]
+    InvalidBoolean {
+        value: String,
+        encoding: BoolEncoding,
+    },
+    #[error(
+        "Column '{column}' had invalid UTF-8 at byte {byte_offset} of the value in row {row}. The \
+        ODBC driver had been expected to return UTF-8 encoded text. This usually indicates a \
+        misconfigured encoding of the connection or data source."
+    )]
+    InvalidUtf8 {
+        /// Name of the column containing the invalid value
+        column: String,
+        /// Index of the row within the batch
+        row: usize,
+        /// Offset of the first byte which is not part of a valid UTF-8 sequence
+        byte_offset: usize,
+    },
+    #[error(
+        "Column '{column}' had an unpaired surrogate {unpaired_surrogate:#06x} in the value in row \
+        {row}. The ODBC driver had been expected to return UTF-16 encoded text."
+    )]
+    InvalidUtf16 {
+        /// Name of the column containing the invalid value
+        column: String,
+        /// Index of the row within the batch
+        row: usize,
+        /// The code unit which is not part of a valid UTF-16 sequence
+        unpaired_surrogate: u16,
+    },
+    #[error(
+        "Integer value {value} is not representable in an arrow array of type {arrow_type}. \
+        Suggestions to fix this error are to declare a wider integer type in the schema, or to \
+        map value errors to NULL."
+    )]
+    IntegerOutOfRange { value: i64, arrow_type: DataType },
+    #[error(
+        "Value '{value}' returned by the database is not an unsigned 64 Bit integer. Suggestions \
+        to fix this error are to map value errors to NULL, or to fetch the values as text."
+    )]
+    InvalidUnsignedInteger { value: String },
+    #[error(
+        "Array '{value}' returned by the database is not of the form '{{e1,e2,...}}' with \
+        {length} numeric elements. Suggestions to fix this error are to map value errors to NULL, \
+        or to fetch the values as text."
+    )]
+    InvalidArray { value: String, length: i32 },
+    #[error(
+        "A dictionary encoded column has more distinct values than its 32 Bit keys can represent. \
+        A suggestion to fix this error is to fetch the values as text."
+    )]
+    DictionaryOverflow,
 }
@@ -0,0 +1,251 @@
+use std::cmp::max;
+
+use arrow::{
+    array::Array,
+    compute::interleave,
+    datatypes::{DataType, SchemaRef},
+    error::ArrowError,
+    record_batch::{RecordBatch, RecordBatchReader},
+    row::{RowConverter, Rows, SortField},
+};
+
+use crate::Error;
+
+/// Merges the record batches of several readers, each sorted by the same column, into a single
+/// stream sorted by that column. Use this e.g. to read sharded or partitioned tables, with one
+/// `ORDER BY` query for each shard, as one globally sorted stream.
+///
+/// Each reader must be sorted ascending by `sort_column`, with `NULL` first (this is the order of
+/// `ORDER BY ... ASC` for e.g. Microsoft SQL Server). The merge does not check the order of the
+/// input. The sort column must be of a primitive (e.g. integer, floating point, timestamp) or
+/// string type. All readers must share the same schema. Both are validated up front.
+///
+/// Emitted batches hold as many rows as the largest first batch of any reader, except for the last
+/// one. Rows with equal sort keys are taken from the reader which comes first in `readers`. An
+/// error of any reader is forwarded and ends the merge.
+///
+/// Each row is compared against the current row of every reader, so this is intended for a
+/// moderate number of readers.
+///
+/// # Example
+///
+/// ```no_run
+/// use arrow_odbc::{merge_sorted_readers, odbc_api::Cursor, OdbcReaderBuilder};
+///
+/// fn merge_shards(shards: Vec<impl Cursor>) -> Result<(), anyhow::Error> {
+///     let readers = shards
+///         .into_iter()
+///         .map(|cursor| OdbcReaderBuilder::new().build(cursor))
+///         .collect::<Result<Vec<_>, _>>()?;
+///     for batch in merge_sorted_readers(readers, "id")? {
+///         // ... process batch ...
+///     }
+///     Ok(())
+/// }
+/// ```
+pub fn merge_sorted_readers<R>(readers: Vec<R>, sort_column: &str) -> Result<MergeSorted<R>, Error>
+where
+    R: RecordBatchReader,
+{
+    let schema = readers
+        .first()
+        .map(RecordBatchReader::schema)
+        .ok_or(Error::NoReadersToMerge)?;
+    if let Some(reader_index) = readers
+        .iter()
+        .position(|reader| reader.schema().fields() != schema.fields())
+    {
+        return Err(Error::MergeSchemaMismatch { reader_index });
+    }
+    let (sort_index, field) =
+        schema
+            .column_with_name(sort_column)
+            .ok_or_else(|| Error::MergeSortColumnNotFound {
+                column: sort_column.to_owned(),
+            })?;
+    let data_type = field.data_type();
+    let is_supported =
+        data_type.is_primitive() || matches!(data_type, DataType::Utf8 | DataType::LargeUtf8);
+    if !is_supported {
+        return Err(Error::UnsupportedMergeSortType {
+            column: sort_column.to_owned(),
+            data_type: data_type.clone(),
+        });
+    }
+    let converter = RowConverter::new(vec![SortField::new(data_type.clone())])
+        .expect("Row format must support primitive and string types");
+    let heads = readers.iter().map(|_| None).collect();
+    Ok(MergeSorted {
+        readers,
+        schema,
+        sort_index,
+        converter,
+        heads,
+        batch_size: None,
+        failed: false,
+    })
+}
+
+/// Record batches of several sorted readers merged into a single sorted stream. Created by
+/// [`merge_sorted_readers`].
+pub struct MergeSorted<R> {
+    readers: Vec<R>,
+    schema: SchemaRef,
+    /// Index of the sort column in the schema.
+    sort_index: usize,
+    /// Converts the sort column into a format which can be compared row by row.
+    converter: RowConverter,
+    /// Current batch of each reader. `None` once the reader is exhausted.
+    heads: Vec<Option<Head>>,
+    /// Number of rows in each emitted batch. `None` until the first batches are fetched.
+    batch_size: Option<usize>,
+    /// `true` once an error has been emitted. The iteration ends afterwards.
+    failed: bool,
+}
+
+/// Batch of a reader, whose rows have not been fully merged yet.
+struct Head {
+    batch: RecordBatch,
+    /// Sort column of `batch` in comparable row format.
+    keys: Rows,
+    /// Index of the next row in `batch` to be merged.
+    position: usize,
+    /// Index of `batch` within the batches the next emitted batch is interleaved from.
+    source: usize,
+}
+
+impl<R> MergeSorted<R>
+where
+    R: RecordBatchReader,
+{
+    /// Fetches the next non-empty batch of a reader. `None` if the reader is exhausted.
+    fn fetch_head(&mut self, reader_index: usize) -> Result<Option<Head>, ArrowError> {
+        for batch in self.readers[reader_index].by_ref() {
+            let batch = batch?;
+            if batch.num_rows() == 0 {
+                continue;
+            }
+            let keys = self
+                .converter
+                .convert_columns(&[batch.column(self.sort_index).clone()])?;
+            return Ok(Some(Head {
+                batch,
+                keys,
+                position: 0,
+                source: 0,
+            }));
+        }
+        Ok(None)
+    }
+
+    /// Picks the rows at `indices` (index of the batch within `sources`, index of the row) from
+    /// `sources`, one column at a time.
+    fn interleave(
+        &self,
+        sources: &[RecordBatch],
+        indices: &[(usize, usize)],
+    ) -> Result<RecordBatch, ArrowError> {
+        let columns = (0..self.schema.fields().len())
+            .map(|column_index| {
+                let arrays: Vec<&dyn Array> = sources
+                    .iter()
+                    .map(|batch| batch.column(column_index).as_ref())
+                    .collect();
+                interleave(&arrays, indices)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        RecordBatch::try_new(self.schema.clone(), columns)
+    }
+
+    /// Fetches the first batch of each reader.
+    fn start(&mut self) -> Result<usize, ArrowError> {
+        let mut batch_size = 1;
+        for reader_index in 0..self.readers.len() {
+            let head = self.fetch_head(reader_index)?;
+            if let Some(head) = &head {
+                batch_size = max(batch_size, head.batch.num_rows());
+            }
+            self.heads[reader_index] = head;
+        }
+        Ok(batch_size)
+    }
+
+    /// Next item of the iterator, without regard to previous errors.
+    fn next_item(&mut self) -> Option<Result<RecordBatch, ArrowError>> {
+        let batch_size = match self.batch_size {
+            Some(batch_size) => batch_size,
+            None => match self.start() {
+                Ok(batch_size) => {
+                    self.batch_size = Some(batch_size);
+                    batch_size
+                }
+                Err(error) => return Some(Err(error)),
+            },
+        };
+
+        // Batches the emitted batch is interleaved from. Starts with the current batch of each
+        // reader and grows each time a reader is advanced to its next batch.
+        let mut sources = Vec::new();
+        for head in self.heads.iter_mut().flatten() {
+            head.source = sources.len();
+            sources.push(head.batch.clone());
+        }
+        let mut indices = Vec::with_capacity(batch_size);
+        while indices.len() < batch_size {
+            let smallest = self
+                .heads
+                .iter()
+                .enumerate()
+                .filter_map(|(reader_index, head)| head.as_ref().map(|head| (reader_index, head)))
+                .min_by(|(_, a), (_, b)| a.keys.row(a.position).cmp(&b.keys.row(b.position)))
+                .map(|(reader_index, _)| reader_index);
+            let Some(reader_index) = smallest else {
+                break;
+            };
+            let head = self.heads[reader_index].as_mut().unwrap();
+            indices.push((head.source, head.position));
+            head.position += 1;
+            if head.position == head.batch.num_rows() {
+                let mut next = match self.fetch_head(reader_index) {
+                    Ok(next) => next,
+                    Err(error) => return Some(Err(error)),
+                };
+                if let Some(next) = &mut next {
+                    next.source = sources.len();
+                    sources.push(next.batch.clone());
+                }
+                self.heads[reader_index] = next;
+            }
+        }
+
+        if indices.is_empty() {
+            return None;
+        }
+        Some(self.interleave(&sources, &indices))
+    }
+}
+
+impl<R> Iterator for MergeSorted<R>
+where
+    R: RecordBatchReader,
+{
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let item = self.next_item();
+        self.failed = matches!(item, Some(Err(_)));
+        item
+    }
+}
+
+impl<R> RecordBatchReader for MergeSorted<R>
+where
+    R: RecordBatchReader,
+{
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+}
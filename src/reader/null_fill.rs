@@ -0,0 +1,119 @@
+use std::sync::Arc;
+
+use arrow::{
+    array::{Array, ArrayRef, PrimitiveArray},
+    datatypes::{
+        ArrowPrimitiveType, DataType as ArrowDataType, Float32Type, Float64Type, Int16Type,
+        Int32Type, Int64Type, Int8Type, UInt8Type,
+    },
+};
+use odbc_api::buffers::{AnySlice, BufferDesc};
+
+use super::{ColumnFailure, MappingError, ReadStrategy};
+
+/// Sentinel values replacing `NULL` in numeric columns, for consumers which can not handle arrow
+/// nulls. See [`crate::OdbcReaderBuilder::with_numeric_null_fill`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NullFill {
+    /// Replaces `NULL` in integer columns. `None` keeps `NULL` in integer columns. Default is
+    /// `None`.
+    pub integer: Option<i64>,
+    /// Replaces `NULL` in floating point columns. Default is `NaN`.
+    pub float: f64,
+}
+
+impl Default for NullFill {
+    fn default() -> Self {
+        Self {
+            integer: None,
+            float: f64::NAN,
+        }
+    }
+}
+
+impl NullFill {
+    /// `true` if `NULL` in columns of this type is replaced with a sentinel.
+    pub fn applies_to(&self, data_type: &ArrowDataType) -> bool {
+        match data_type {
+            ArrowDataType::Int8
+            | ArrowDataType::Int16
+            | ArrowDataType::Int32
+            | ArrowDataType::Int64
+            | ArrowDataType::UInt8 => self.integer.is_some(),
+            ArrowDataType::Float32 | ArrowDataType::Float64 => true,
+            _ => false,
+        }
+    }
+}
+
+/// Wraps the strategy fetching a numeric column as nullable, so `NULL` in the arrays it creates is
+/// replaced with the sentinel for the type. `data_type` must be a type the fill applies to.
+pub fn fill_null_strategy(
+    data_type: &ArrowDataType,
+    null_fill: NullFill,
+    nullable: Box<dyn ReadStrategy + Send>,
+) -> Result<Box<dyn ReadStrategy + Send>, ColumnFailure> {
+    let integer = null_fill.integer.unwrap_or_default();
+    let strategy: Box<dyn ReadStrategy + Send> = match data_type {
+        ArrowDataType::Int8 => integer_fill::<Int8Type>(integer, nullable)?,
+        ArrowDataType::Int16 => integer_fill::<Int16Type>(integer, nullable)?,
+        ArrowDataType::Int32 => integer_fill::<Int32Type>(integer, nullable)?,
+        ArrowDataType::Int64 => integer_fill::<Int64Type>(integer, nullable)?,
+        ArrowDataType::UInt8 => integer_fill::<UInt8Type>(integer, nullable)?,
+        ArrowDataType::Float32 => Box::new(FillNull::<Float32Type>::new(
+            nullable,
+            null_fill.float as f32,
+        )),
+        ArrowDataType::Float64 => Box::new(FillNull::<Float64Type>::new(nullable, null_fill.float)),
+        _ => unreachable!("Null fill must only be applied to numeric types"),
+    };
+    Ok(strategy)
+}
+
+fn integer_fill<T>(
+    sentinel: i64,
+    nullable: Box<dyn ReadStrategy + Send>,
+) -> Result<Box<dyn ReadStrategy + Send>, ColumnFailure>
+where
+    T: ArrowPrimitiveType + Send,
+    T::Native: TryFrom<i64>,
+{
+    let native = T::Native::try_from(sentinel).map_err(|_| ColumnFailure::NullFillOutOfRange {
+        sentinel,
+        arrow_type: T::DATA_TYPE,
+    })?;
+    Ok(Box::new(FillNull::<T>::new(nullable, native)))
+}
+
+struct FillNull<T: ArrowPrimitiveType> {
+    /// Strategy fetching the column as nullable.
+    nullable: Box<dyn ReadStrategy + Send>,
+    sentinel: T::Native,
+}
+
+impl<T: ArrowPrimitiveType> FillNull<T> {
+    fn new(nullable: Box<dyn ReadStrategy + Send>, sentinel: T::Native) -> Self {
+        Self { nullable, sentinel }
+    }
+}
+
+impl<T> ReadStrategy for FillNull<T>
+where
+    T: ArrowPrimitiveType + Send,
+{
+    fn buffer_desc(&self) -> BufferDesc {
+        self.nullable.buffer_desc()
+    }
+
+    fn fill_arrow_array(&self, column_view: AnySlice) -> Result<ArrayRef, MappingError> {
+        let array = self.nullable.fill_arrow_array(column_view)?;
+        if array.null_count() == 0 {
+            return Ok(array);
+        }
+        let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+        let filled = PrimitiveArray::<T>::from_iter_values(
+            array.iter().map(|value| value.unwrap_or(self.sentinel)),
+        );
+        Ok(Arc::new(filled))
+    }
+}
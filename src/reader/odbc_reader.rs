@@ -1,15 +1,41 @@
-use std::cmp::min;
+use std::{
+    cmp::min,
+    collections::HashMap,
+    sync::{mpsc::Receiver, Arc, Mutex},
+    thread::JoinHandle,
+    time::{Duration, Instant},
+};
 
 use arrow::{
-    datatypes::SchemaRef,
+    datatypes::{DataType, Schema, SchemaRef, TimeUnit},
     error::ArrowError,
     record_batch::{RecordBatch, RecordBatchReader},
 };
-use odbc_api::{buffers::ColumnarAnyBuffer, BlockCursor, Cursor};
+use log::{info, warn};
+use odbc_api::{
+    buffers::ColumnarAnyBuffer,
+    handles::{AsStatementRef, StatementImpl},
+    BlockCursor, Connection, Cursor, CursorImpl, CursorPolling, ParameterCollectionRef,
+    ResultSetMetadata,
+};
 
-use crate::{BufferAllocationOptions, ConcurrentOdbcReader, Error};
+use crate::{
+    schema::validate_schema, AsyncOdbcReader, BoolEncoding, BufferAllocationOptions, BufferPool,
+    ConcurrentOdbcReader, Error, ErrorAction, InvalidUtf8Policy, MappingError, NullFill,
+    TextEncoding,
+};
 
-use super::to_record_batch::ToRecordBatch;
+use super::{
+    async_odbc_reader::PollingMetadata,
+    channel::spawn_into_channel,
+    deadline::Deadline,
+    exact_batch_rows::ExactBatchRows,
+    progress::{Progress, ProgressCallback},
+    row_limit::RowLimit,
+    statistics::ColumnStats,
+    to_record_batch::ToRecordBatch,
+    ColumnNameTransform, MappingErrorHandler, ReadOptions,
+};
 
 /// Arrow ODBC reader. Implements the [`arrow::record_batch::RecordBatchReader`] trait so it can be
 /// used to fill Arrow arrays from an ODBC data source.
@@ -65,10 +91,55 @@ pub struct OdbcReader<C: Cursor> {
     converter: ToRecordBatch,
     /// Fetches values from the ODBC datasource using columnar batches. Values are streamed batch
     /// by batch in order to avoid reallocation of the buffers used for tranistion.
-    batch_stream: BlockCursor<C, ColumnarAnyBuffer>,
+    cursor: CursorState<C>,
     /// We remember if the user decided to use fallibale allocations or not in case we need to
     /// allocate another buffer due to a state transition towards [`ConcurrentOdbcReader`].
     fallibale_allocations: bool,
+    /// Pool the transit buffer is returned to once the reader is destroyed.
+    buffer_pool: Option<BufferPool>,
+    /// `Some` if fetched batches are resliced to have an exact number of rows.
+    exact_batch_rows: Option<ExactBatchRows>,
+    /// Maximum number of rows in each emitted batch. Equals the size of the transit buffer, unless
+    /// fewer rows are fetched at once (see [`OdbcReaderBuilder::with_odbc_fetch_rows`]).
+    max_rows_per_batch: usize,
+    /// Size of the transit buffer in bytes.
+    buffer_size_in_bytes: usize,
+    /// `Some` if the reader stops fetching after a point in time.
+    deadline: Option<Deadline>,
+    /// `Some` if the user wants to be notified about the number of rows fetched so far.
+    progress: Option<Progress>,
+    /// `Some` if the reader stops after emitting a total number of rows.
+    row_limit: Option<RowLimit>,
+    /// Statistics of the columns of the batch emitted last. Only collected if requested using
+    /// [`OdbcReaderBuilder::with_statistics`].
+    last_batch_statistics: Vec<ColumnStats>,
+    /// Index of the statement within the executed batch, which produced the result set of this
+    /// reader.
+    statement_index: usize,
+    /// Builder used to create this reader. Remembered, so we can create readers for subsequent
+    /// result sets with the same settings.
+    builder: OdbcReaderBuilder,
+}
+
+/// Cursor of an [`OdbcReader`], together with the transit buffer bound to it.
+enum CursorState<C: Cursor> {
+    /// The transit buffer is bound to the cursor.
+    Bound(BlockCursor<C, ColumnarAnyBuffer>),
+    /// The result set has no columns. There is nothing to fetch, so no buffer is bound.
+    Unbound(C),
+    /// The cursor has been moved out of the reader, which is being destroyed.
+    Released,
+    /// Unbinding the transit buffer, or binding it again after fetching streamed columns failed.
+    /// Cursor and buffer are lost, so the reader does not emit any further batches. Holds the
+    /// failed ODBC function, whose diagnostics have already been emitted by the reader.
+    Lost { function: &'static str },
+}
+
+impl<C: Cursor> CursorState<C> {
+    /// Moves the cursor out, leaving [`Self::Released`] behind.
+    fn take(&mut self) -> Self {
+        std::mem::replace(self, CursorState::Released)
+    }
 }
 
 impl<C: Cursor> OdbcReader<C> {
@@ -79,6 +150,9 @@ impl<C: Cursor> OdbcReader<C> {
     /// written to by a dedicated system thread, while the other is read by the application. Use
     /// this if you want to trade memory for speed.
     ///
+    /// Fails with [`Error::LongDataStreamingUnsupported`] if columns are streamed (see
+    /// [`OdbcReaderBuilder::with_long_data_streaming`]).
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -129,45 +203,252 @@ impl<C: Cursor> OdbcReader<C> {
     ///     Ok(())
     /// }
     /// ```
-    pub fn into_concurrent(self) -> Result<ConcurrentOdbcReader<C>, Error>
+    pub fn into_concurrent(mut self) -> Result<ConcurrentOdbcReader<C>, Error>
     where
         C: Send + 'static,
     {
+        if self.converter.has_long_data() {
+            return Err(Error::LongDataStreamingUnsupported);
+        }
+        let batch_stream = match self.cursor.take() {
+            CursorState::Bound(batch_stream) => batch_stream,
+            // The concurrent reader always fetches into a buffer, so we bind an empty one.
+            CursorState::Unbound(cursor) => {
+                let buffer = self
+                    .converter
+                    .allocate_buffer(1, self.fallibale_allocations, None)?;
+                cursor
+                    .bind_buffer(buffer)
+                    .map_err(Error::UnableToBindBuffer)?
+            }
+            // Only readers streaming long data lose their cursor.
+            CursorState::Released | CursorState::Lost { .. } => {
+                unreachable!("Cursor is only released by consuming the reader")
+            }
+        };
+        let converter = std::mem::replace(&mut self.converter, ToRecordBatch::empty());
         ConcurrentOdbcReader::from_block_cursor(
-            self.batch_stream,
-            self.converter,
+            batch_stream,
+            converter,
             self.fallibale_allocations,
+            self.buffer_pool.take(),
+            self.exact_batch_rows.take(),
+            self.deadline.take(),
+            self.progress.take(),
+            self.row_limit.take(),
         )
     }
 
+    /// Moves this reader into a dedicated system thread, which drives it to completion and sends the
+    /// batches into a bounded channel with room for `capacity` batches. This decouples the rate at
+    /// which batches are produced from the rate at which they are consumed, e.g. by a pool of
+    /// worker threads. Once the channel is full, fetching is paused until the consumer catches up.
+    ///
+    /// The thread is named `arrow-odbc-channel`. Errors are sent through the channel like any other
+    /// item. The thread stops after the result set is exhausted or once the receiver is dropped.
+    /// Joining the returned handle yields the reader again, so you can e.g. call
+    /// [`Self::into_cursor`] on it to process further result sets.
+    ///
+    /// Since the reader is moved to another thread, the cursor must be `Send` and `'static`. Use
+    /// e.g. `Connection::into_cursor` to obtain a cursor owning its connection.
+    pub fn into_channel(
+        self,
+        capacity: usize,
+    ) -> (Receiver<Result<RecordBatch, ArrowError>>, JoinHandle<Self>)
+    where
+        C: Send + 'static,
+    {
+        spawn_into_channel(self, capacity)
+    }
+
     /// Destroy the ODBC arrow reader and yield the underlyinng cursor object.
     ///
     /// One application of this is to process more than one result set in case you executed a stored
     /// procedure.
-    pub fn into_cursor(self) -> Result<C, odbc_api::Error> {
-        let (cursor, _buffer) = self.batch_stream.unbind()?;
+    ///
+    /// Rows already fetched, but not yet emitted due to [`OdbcReaderBuilder::with_exact_batch_rows`]
+    /// are discarded.
+    ///
+    /// Dropping the reader also unbinds the transit buffer from the cursor, yet can only log errors
+    /// doing so. Use this method to handle them. Fails, if the reader already lost its cursor,
+    /// because the transit buffer could not be bound again after fetching streamed columns.
+    pub fn into_cursor(mut self) -> Result<C, odbc_api::Error> {
+        let (cursor, buffer) = self.unbind()?;
+        if let Some(buffer) = buffer {
+            self.converter
+                .recycle_buffer(buffer, self.buffer_pool.as_ref());
+        }
         Ok(cursor)
     }
 
+    /// Takes the cursor out of this reader, together with the transit buffer, if one is bound.
+    fn unbind(&mut self) -> Result<(C, Option<ColumnarAnyBuffer>), odbc_api::Error> {
+        match self.cursor.take() {
+            CursorState::Bound(batch_stream) => {
+                let (cursor, buffer) = batch_stream.unbind()?;
+                Ok((cursor, Some(buffer)))
+            }
+            CursorState::Unbound(cursor) => Ok((cursor, None)),
+            CursorState::Lost { function } => Err(odbc_api::Error::NoDiagnostics { function }),
+            CursorState::Released => {
+                unreachable!("Cursor is only released by consuming the reader")
+            }
+        }
+    }
+
+    /// Advances the cursor to the next result set and creates a reader for it, using the same
+    /// settings as this one. The schema is inferred anew from the next result set, even if this
+    /// reader has been created with an explicit one. Statements which do not produce a result set
+    /// (i.e. the result set has no columns) are skipped. `None` if there are no more result sets.
+    ///
+    /// The transit buffer of this reader is reused by the next one, if the columns of both result
+    /// sets require the same buffers.
+    ///
+    /// One application of this is to process more than one result set in case you executed a
+    /// stored procedure.
+    ///
+    /// If a later statement of the batch fails, [`Error::MoreResults`] tells the index of the
+    /// statement. Batches emitted by readers of previous result sets stay valid.
+    pub fn next_result_set(mut self) -> Result<Option<OdbcReader<C>>, Error> {
+        let mut statement_index = self.statement_index;
+        let (mut cursor, buffer) = self.unbind().map_err(|source| Error::MoreResults {
+            statement_index: statement_index + 1,
+            source,
+        })?;
+        // Offer the old buffer to the next reader using the pool of the user or a temporary one.
+        let pool = self.buffer_pool.clone().unwrap_or_default();
+        if let Some(buffer) = buffer {
+            self.converter.recycle_buffer(buffer, Some(&pool));
+        }
+        loop {
+            statement_index += 1;
+            let more_results = cursor.more_results().map_err(|source| Error::MoreResults {
+                statement_index,
+                source,
+            })?;
+            let Some(mut next) = more_results else {
+                return Ok(None);
+            };
+            let num_cols = next
+                .num_result_cols()
+                .map_err(Error::UnableToRetrieveNumCols)?;
+            if num_cols == 0 {
+                cursor = next;
+                continue;
+            }
+            let mut builder = self.builder.clone();
+            builder.schema = None;
+            let mut reader = builder.build_with_pool(next, Some(&pool))?;
+            reader.statement_index = statement_index;
+            return Ok(Some(reader));
+        }
+    }
+
+    /// Index of the statement within the executed batch, which produced the result set of this
+    /// reader. `0` for the reader created from the cursor, incremented for each result set
+    /// advanced to by [`Self::next_result_set`]. Skipped statements without result set count,
+    /// too.
+    pub fn statement_index(&self) -> usize {
+        self.statement_index
+    }
+
     /// Size of the internal preallocated buffer bound to the cursor and filled by your ODBC driver
     /// in rows. Each record batch will at most have this many rows. Only the last one may have
-    /// less.
+    /// less. This does not hold true, if the number of rows for each batch has been set using
+    /// [`OdbcReaderBuilder::with_exact_batch_rows`]. If fewer rows are fetched at once (see
+    /// [`OdbcReaderBuilder::with_odbc_fetch_rows`]), this is still the number of rows in each
+    /// batch, rather than the size of the buffer.
     pub fn max_rows_per_batch(&self) -> usize {
-        self.batch_stream.row_array_size()
+        self.max_rows_per_batch
     }
-}
 
-impl<C> Iterator for OdbcReader<C>
-where
-    C: Cursor,
-{
-    type Item = Result<RecordBatch, ArrowError>;
+    /// Size of the transit buffer bound to the cursor in bytes. This is the number of rows fetched
+    /// at once, times the bytes required to hold one row of all columns. Use this to log or tune
+    /// the memory usage of the reader, e.g. via [`OdbcReaderBuilder::with_max_bytes_per_batch`].
+    pub fn buffer_size_in_bytes(&self) -> usize {
+        self.buffer_size_in_bytes
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        match self.batch_stream.fetch_with_truncation_check(true) {
+    /// Null counts, and for primitive columns the smallest and largest value, of each column of
+    /// the batch emitted last. Collected while the reader emits the batches, if enabled with
+    /// [`OdbcReaderBuilder::with_statistics`]. Use this e.g. to write the statistics of parquet
+    /// row groups, without another pass over the data. Empty if statistics are not collected or no
+    /// batch has been emitted yet.
+    pub fn last_batch_statistics(&self) -> &[ColumnStats] {
+        &self.last_batch_statistics
+    }
+
+    /// Same as [`Iterator::next`], but with the result and the option swapped, so it composes
+    /// nicely with `?`. Intended for tools fetching rows on demand, using a reader created with
+    /// [`OdbcReaderBuilder::interactive`]. Each batch then holds exactly one row. Otherwise the
+    /// batch holds up to [`Self::max_rows_per_batch`] rows, just like for `next`. `None` once the
+    /// result set is exhausted.
+    pub fn fetch_one(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+        self.next().transpose()
+    }
+
+    /// Fetches all remaining rows of the result set and returns their number. The rows are only
+    /// fetched into the transit buffer, and never converted into arrow arrays. Use this e.g. for
+    /// validation jobs, which only need the row count. Since no values are converted, mapping
+    /// errors are not reported and streamed columns are not fetched. Rows already fetched, but not
+    /// yet emitted due to [`OdbcReaderBuilder::with_exact_batch_rows`] are counted.
+    /// [`OdbcReaderBuilder::with_max_total_rows`] and [`OdbcReaderBuilder::with_deadline`] are
+    /// respected.
+    pub fn count_remaining(mut self) -> Result<usize, ArrowError> {
+        let mut total = self
+            .exact_batch_rows
+            .as_ref()
+            .map_or(0, ExactBatchRows::num_pending);
+        if let Some(row_limit) = &mut self.row_limit {
+            total = row_limit.count(total);
+        }
+        loop {
+            match self.deadline.as_mut().and_then(Deadline::check) {
+                Some(Some(Err(error))) => return Err(error),
+                Some(_) => break,
+                None => (),
+            }
+            if self.row_limit.as_ref().is_some_and(RowLimit::is_reached) {
+                break;
+            }
+            // Result sets without columns have nothing to fetch.
+            let CursorState::Bound(batch_stream) = &mut self.cursor else {
+                break;
+            };
+            let Some(num_rows) = batch_stream
+                .fetch()
+                .map_err(odbc_to_arrow_error)?
+                .map(|batch| batch.num_rows())
+            else {
+                break;
+            };
+            if let Some(progress) = self.progress.as_mut() {
+                progress.report(num_rows);
+            }
+            total += match &mut self.row_limit {
+                Some(row_limit) => row_limit.count(num_rows),
+                None => num_rows,
+            };
+        }
+        Ok(total)
+    }
+
+    /// Fetches the next batch from the data source and converts it into a record batch. `row` is
+    /// the index of the first fetched row within the emitted batch.
+    fn fetch_batch(&mut self, row: usize) -> Option<Result<RecordBatch, ArrowError>> {
+        if self.converter.has_long_data() {
+            return self.fetch_row_with_long_data(row);
+        }
+        let CursorState::Bound(batch_stream) = &mut self.cursor else {
+            return None;
+        };
+        match batch_stream.fetch_with_truncation_check(true) {
             // We successfully fetched a batch from the database. Try to copy it into a record batch
             // and forward errors if any.
             Ok(Some(batch)) => {
+                if let Some(progress) = &mut self.progress {
+                    progress.report(batch.num_rows());
+                }
                 let result_record_batch = self
                     .converter
                     .buffer_to_record_batch(batch)
@@ -181,6 +462,112 @@ where
             Err(odbc_error) => Some(Err(odbc_to_arrow_error(odbc_error))),
         }
     }
+
+    /// Fetches the next row into the transit buffer, and the values of the streamed columns using
+    /// `SQLGetData`. Since `SQLGetData` can not be called while the buffer is bound, it is unbound
+    /// and bound again for each row. `None` once the result set is exhausted, or if the buffer
+    /// could not be bound again after an error. `row` is the index of the fetched row within the
+    /// emitted batch.
+    fn fetch_row_with_long_data(&mut self, row: usize) -> Option<Result<RecordBatch, ArrowError>> {
+        let CursorState::Bound(batch_stream) = &mut self.cursor else {
+            return None;
+        };
+        let bound = match batch_stream.fetch_with_truncation_check(true) {
+            Ok(Some(batch)) => {
+                if let Some(progress) = &mut self.progress {
+                    progress.report(batch.num_rows());
+                }
+                self.converter.bound_arrays(batch)
+            }
+            Ok(None) => return None,
+            Err(odbc_error) => return Some(Err(odbc_to_arrow_error(odbc_error))),
+        };
+        let (mut cursor, buffer) = match self.unbind() {
+            Ok((cursor, Some(buffer))) => (cursor, buffer),
+            Ok((_cursor, None)) => unreachable!("Transit buffer is bound while fetching"),
+            Err(odbc_error) => {
+                self.cursor = CursorState::Lost {
+                    function: "SQLFreeStmt",
+                };
+                return Some(Err(odbc_to_arrow_error(odbc_error)));
+            }
+        };
+        let long_data = self.converter.fetch_long_data(&mut cursor, row);
+        match cursor.bind_buffer(buffer) {
+            Ok(batch_stream) => self.cursor = CursorState::Bound(batch_stream),
+            Err(odbc_error) => {
+                self.cursor = CursorState::Lost {
+                    function: "SQLBindCol",
+                };
+                return Some(Err(odbc_to_arrow_error(odbc_error)));
+            }
+        }
+        let result_record_batch = bound
+            .map_err(|mapping_error| ArrowError::ExternalError(Box::new(mapping_error)))
+            .and_then(|bound| {
+                let long_data = long_data?;
+                Ok(self.converter.assemble_record_batch(bound, long_data))
+            });
+        Some(result_record_batch)
+    }
+}
+
+impl<C> Drop for OdbcReader<C>
+where
+    C: Cursor,
+{
+    fn drop(&mut self) {
+        // Return the transit buffer to the pool, so it can be reused by another reader.
+        let Some(pool) = &self.buffer_pool else {
+            return;
+        };
+        if let CursorState::Bound(batch_stream) = self.cursor.take() {
+            match batch_stream.unbind() {
+                Ok((_cursor, buffer)) => self.converter.recycle_buffer(buffer, Some(pool)),
+                Err(odbc_error) => warn!(
+                    "Unbinding the transit buffer failed. It is not returned to the pool.\n\
+                    {odbc_error}"
+                ),
+            }
+        }
+    }
+}
+
+impl<C> Iterator for OdbcReader<C>
+where
+    C: Cursor,
+{
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(item) = self.deadline.as_mut().and_then(Deadline::check) {
+            return item;
+        }
+        if self.row_limit.as_ref().is_some_and(RowLimit::is_reached) {
+            return None;
+        }
+        // A result set without columns has nothing to fetch. Spare the round trip to the data
+        // source.
+        if matches!(self.cursor, CursorState::Unbound(_)) {
+            return None;
+        }
+        let item = if let Some(mut exact_batch_rows) = self.exact_batch_rows.take() {
+            let schema = self.converter.schema().clone();
+            let item = exact_batch_rows.next(&schema, |row| self.fetch_batch(row));
+            self.exact_batch_rows = Some(exact_batch_rows);
+            item
+        } else {
+            self.fetch_batch(0)
+        };
+        let item = match &mut self.row_limit {
+            Some(row_limit) => item.map(|result| result.map(|batch| row_limit.apply(batch))),
+            None => item,
+        };
+        if let (true, Some(Ok(batch))) = (self.builder.statistics, &item) {
+            self.last_batch_statistics = ColumnStats::for_batch(batch);
+        }
+        item
+    }
 }
 
 impl<C> RecordBatchReader for OdbcReader<C>
@@ -192,6 +579,72 @@ where
     }
 }
 
+/// Executes the SQL statement and creates a reader for the result set, in one call. The name of the
+/// database management system is queried from the connection and passed to the reader, unless it
+/// has been set explicitly using [`OdbcReaderBuilder::with_dbms_name`]. All other options are taken
+/// from `builder`.
+///
+/// # Example
+///
+/// ```no_run
+/// use arrow_odbc::{odbc_api::{Environment, ConnectionOptions}, read, OdbcReaderBuilder};
+///
+/// const CONNECTION_STRING: &str = "\
+///     Driver={ODBC Driver 17 for SQL Server};\
+///     Server=localhost;\
+///     UID=SA;\
+///     PWD=My@Test@Password1;\
+/// ";
+///
+/// fn main() -> Result<(), anyhow::Error> {
+///     let odbc_environment = Environment::new()?;
+///
+///     // Connect with database.
+///     let connection = odbc_environment.connect_with_connection_string(
+///         CONNECTION_STRING,
+///         ConnectionOptions::default()
+///     )?;
+///
+///     // Execute query and read result set as arrow batches.
+///     let arrow_record_batches = read(
+///         &connection,
+///         "SELECT * FROM MyTable WHERE a > ?",
+///         &42,
+///         &OdbcReaderBuilder::new(),
+///     )?;
+///
+///     for batch in arrow_record_batches {
+///         // ... process batch ...
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub fn read<'c>(
+    connection: &'c Connection<'_>,
+    sql: &str,
+    params: impl ParameterCollectionRef,
+    builder: &OdbcReaderBuilder,
+) -> Result<OdbcReader<CursorImpl<StatementImpl<'c>>>, Error> {
+    let cursor = connection
+        .execute(sql, params)
+        .map_err(|source| Error::Execute {
+            source,
+            sql: sql.to_owned(),
+        })?
+        .ok_or_else(|| Error::NoResultSet {
+            sql: sql.to_owned(),
+        })?;
+    if builder.dbms_name.is_some() {
+        builder.build(cursor)
+    } else {
+        let dbms_name = connection
+            .database_management_system_name()
+            .map_err(Error::UnableToRetrieveDbmsName)?;
+        builder.clone().with_dbms_name(&dbms_name).build(cursor)
+    }
+}
+
 /// Creates instances of [`OdbcReader`] based on [`odbc_api::Cursor`].
 ///
 /// Using a builder pattern instead of passing structs with all required arguments to the
@@ -204,12 +657,48 @@ pub struct OdbcReaderBuilder {
     /// for the user.
     max_num_rows_per_batch: usize,
     max_bytes_per_batch: usize,
+    budget_includes_concurrency: bool,
     schema: Option<SchemaRef>,
+    schema_metadata: HashMap<String, String>,
     max_text_size: Option<usize>,
     max_binary_size: Option<usize>,
     map_value_errors_to_null: bool,
+    mapping_error_handler: Option<MappingErrorHandler>,
     fallibale_allocations: bool,
     trim_fixed_sized_character_strings: bool,
+    trim_all_strings: bool,
+    empty_string_as_null: bool,
+    wide_for_wide_columns: bool,
+    invalid_utf8_policy: InvalidUtf8Policy,
+    decimals_as_text: bool,
+    column_name_transform: Option<ColumnNameTransform>,
+    dedup_column_names: bool,
+    skip_undescribable_columns: bool,
+    allow_unsigned_widening: bool,
+    safe_timestamp_range: bool,
+    timestamp_unit: Option<TimeUnit>,
+    all_columns_nullable: bool,
+    log_schema: bool,
+    exact_batch_rows: Option<usize>,
+    max_total_rows: Option<usize>,
+    odbc_fetch_rows: Option<usize>,
+    max_bound_columns: Option<usize>,
+    deadline: Option<Instant>,
+    progress_callback: Option<ProgressCallback>,
+    statistics: bool,
+    null_fill: Option<NullFill>,
+    query_timeout: Option<Duration>,
+    metadata_query_timeout: Option<Duration>,
+    preserve_timestamp_offset: bool,
+    boolean_columns: Vec<(String, BoolEncoding)>,
+    text_encodings: Vec<(String, TextEncoding)>,
+    text_columns: Vec<String>,
+    column_overrides: Vec<(String, DataType)>,
+    binary_columns: Vec<String>,
+    dictionary_columns: Vec<String>,
+    long_data_columns: Vec<String>,
+    dbms_name: Option<String>,
+    buffer_pool: Option<BufferPool>,
 }
 
 impl OdbcReaderBuilder {
@@ -226,15 +715,73 @@ impl OdbcReaderBuilder {
         OdbcReaderBuilder {
             max_num_rows_per_batch: DEFAULT_MAX_ROWS_PER_BATCH,
             max_bytes_per_batch: DEFAULT_MAX_BYTES_PER_BATCH,
+            budget_includes_concurrency: false,
             schema: None,
+            schema_metadata: HashMap::new(),
             max_text_size: None,
             max_binary_size: None,
             fallibale_allocations: false,
             map_value_errors_to_null: false,
+            mapping_error_handler: None,
             trim_fixed_sized_character_strings: false,
+            trim_all_strings: false,
+            empty_string_as_null: false,
+            wide_for_wide_columns: false,
+            invalid_utf8_policy: InvalidUtf8Policy::Error,
+            decimals_as_text: false,
+            column_name_transform: None,
+            dedup_column_names: false,
+            skip_undescribable_columns: false,
+            allow_unsigned_widening: false,
+            safe_timestamp_range: false,
+            timestamp_unit: None,
+            all_columns_nullable: false,
+            log_schema: false,
+            exact_batch_rows: None,
+            max_total_rows: None,
+            odbc_fetch_rows: None,
+            max_bound_columns: None,
+            deadline: None,
+            progress_callback: None,
+            statistics: false,
+            null_fill: None,
+            query_timeout: None,
+            metadata_query_timeout: None,
+            preserve_timestamp_offset: false,
+            boolean_columns: Vec::new(),
+            text_encodings: Vec::new(),
+            text_columns: Vec::new(),
+            column_overrides: Vec::new(),
+            binary_columns: Vec::new(),
+            dictionary_columns: Vec::new(),
+            long_data_columns: Vec::new(),
+            dbms_name: None,
+            buffer_pool: None,
         }
     }
 
+    /// Settings for interactive tools, like a SQL shell, which display one row at a time. Each
+    /// batch holds a single row, and the transit buffer is limited to 16 MiB, rather than the 512
+    /// MiB allocated by default. Rows requiring a larger buffer cause [`Self::build`] to fail with
+    /// [`Error::OdbcBufferTooSmall`], in which case [`Self::with_max_text_size`] may help. All
+    /// other settings are the same as for [`Self::new`] and can still be changed.
+    ///
+    /// ```
+    /// use arrow_odbc::OdbcReaderBuilder;
+    ///
+    /// let mut builder = OdbcReaderBuilder::interactive();
+    /// builder.with_max_text_size(4096);
+    /// ```
+    pub fn interactive() -> Self {
+        const INTERACTIVE_MAX_BYTES_PER_BATCH: usize = 16 * 1024 * 1024;
+
+        let mut builder = Self::new();
+        builder
+            .with_max_num_rows_per_batch(1)
+            .with_max_bytes_per_batch(INTERACTIVE_MAX_BYTES_PER_BATCH);
+        builder
+    }
+
     /// Limits the maximum amount of rows which are fetched in a single roundtrip to the datasource.
     /// Higher numbers lower the IO overhead and may speed up your runtime, but also require larger
     /// preallocated buffers and use more memory. This value defaults to `65535` which is `u16` max.
@@ -246,6 +793,75 @@ impl OdbcReaderBuilder {
         self
     }
 
+    /// Emit record batches with exactly `num_rows` rows. Only the last batch of a result set may
+    /// have fewer rows. Rows are accumulated across roundtrips to the data source, and resliced, so
+    /// the batch sizes do not depend on the size of the transit buffer. Use this if the consumer
+    /// requires batches of uniform size, e.g. for training machine learning models. Batches which
+    /// span multiple roundtrips are copied, so choose the size of the transit buffer (see
+    /// [`Self::with_max_num_rows_per_batch`]) as a multiple of `num_rows` to avoid the overhead.
+    ///
+    /// # Panics
+    ///
+    /// If `num_rows` is zero.
+    pub fn with_exact_batch_rows(&mut self, num_rows: usize) -> &mut Self {
+        assert!(num_rows > 0, "Batches must contain at least one row");
+        self.exact_batch_rows = Some(num_rows);
+        self
+    }
+
+    /// Stop after emitting `max_total_rows` rows in total, across all batches. The batch crossing
+    /// the limit is truncated, and the iteration ends afterwards without fetching further batches
+    /// from the data source. Use this e.g. for previews or sampling of result sets you can not
+    /// limit in SQL. Applies to [`ConcurrentOdbcReader`] as well. The rows of the truncated batch
+    /// have already been fetched, though, so choose a smaller batch size if the limit is small
+    /// compared to the buffer.
+    pub fn with_max_total_rows(&mut self, max_total_rows: usize) -> &mut Self {
+        self.max_total_rows = Some(max_total_rows);
+        self
+    }
+
+    /// Number of rows fetched from the data source in a single roundtrip, if it should be smaller
+    /// than the number of rows in each record batch. By default the transit buffer bound to the
+    /// cursor holds as many rows as each batch (see [`Self::with_max_num_rows_per_batch`]). Some
+    /// drivers allocate memory in proportion to the size of the row array though. With this option
+    /// the transit buffer only holds `num_rows` rows and the rows of multiple fetches are
+    /// accumulated into one record batch. Batches spanning multiple fetches are copied, so this
+    /// trades some speed for less memory on the driver side.
+    ///
+    /// Has no effect if `num_rows` is not smaller than the number of rows in each batch.
+    ///
+    /// # Panics
+    ///
+    /// If `num_rows` is zero.
+    pub fn with_odbc_fetch_rows(&mut self, num_rows: usize) -> &mut Self {
+        assert!(num_rows > 0, "Must fetch at least one row at once");
+        self.odbc_fetch_rows = Some(num_rows);
+        self
+    }
+
+    /// Upper bound for the number of columns bound to the cursor at once. Each column is bound to
+    /// its own buffer, which for result sets with thousands of columns (e.g. pivot or feature
+    /// matrix queries) may exhaust the resources of the driver. If the result set has more
+    /// columns, [`Self::build`] fails early with [`Error::TooManyColumns`], rather than with an
+    /// obscure error of the driver. Columns can not be fetched incrementally in multiple passes,
+    /// since a cursor can only be traversed once. So the only remedy is to split the query into
+    /// multiple ones, for subsets of the columns. Default is no limit.
+    pub fn with_max_bound_columns(&mut self, max_bound_columns: usize) -> &mut Self {
+        self.max_bound_columns = Some(max_bound_columns);
+        self
+    }
+
+    /// If `true`, [`Self::with_max_bytes_per_batch`] is a budget for both transit buffers of a
+    /// [`crate::ConcurrentOdbcReader`], rather than for a single one.
+    /// [`OdbcReader::into_concurrent`] allocates a second buffer of the same size as the first one,
+    /// so the size of each buffer is limited to half the budget. Since the size of the buffer is
+    /// chosen when the reader is built, this applies to readers which are never turned into
+    /// concurrent ones, too. Default is `false`.
+    pub fn with_total_byte_budget_includes_concurrency(&mut self, enabled: bool) -> &mut Self {
+        self.budget_includes_concurrency = enabled;
+        self
+    }
+
     /// In addition to a row size limit you may specify an upper bound in bytes for allocating the
     /// transit buffer. This is useful if you do not know the database schema, or your code has to
     /// work with different ones, but you know the amount of memory in your machine. This limit is
@@ -266,11 +882,24 @@ impl OdbcReaderBuilder {
     /// struggle with reporting nullability correctly and just report every column as nullable.
     /// Explicitly specifying a schema can also compensate for such shortcomings if it turns out to
     /// be relevant.
+    ///
+    /// The schema is used verbatim for the reader and the emitted record batches. Metadata attached
+    /// to the schema or its fields (e.g. semantic tags) is preserved, and only extended by entries
+    /// of [`Self::with_schema_metadata`].
     pub fn with_schema(&mut self, schema: SchemaRef) -> &mut Self {
         self.schema = Some(schema);
         self
     }
 
+    /// Key value pairs attached to the metadata of the schema of the reader, e.g. the name of the
+    /// source table or the time of the extraction. Applies to inferred schemas as well as to
+    /// schemas set with [`Self::with_schema`], whose own metadata is extended. Entries of the
+    /// schema with the same key are replaced. Default is no metadata.
+    pub fn with_schema_metadata(&mut self, metadata: HashMap<String, String>) -> &mut Self {
+        self.schema_metadata = metadata;
+        self
+    }
+
     /// An upper limit for the size of buffers bound to variadic text columns of the data source.
     /// This limit does not (directly) apply to the size of the created arrow buffers, but rather
     /// applies to the buffers used for the data in transit. Use this option if you have e.g.
@@ -314,12 +943,30 @@ impl OdbcReaderBuilder {
     /// Set to `true` in order to map a value in the database which can not be successfully
     /// converted into its target type to NULL, rather than emitting an external Arrow Error.
     /// E.g. currently mapping errors can happen if a datetime value is not in the rang
-    /// representable by arrow. Default is `false`.
+    /// representable by arrow, if a date does not exist in the calendar (e.g. the zero date
+    /// `0000-00-00` permitted by MySQL), or if the driver returns a fraction of a second which is
+    /// not a valid number of nanoseconds. Default is `false`.
     pub fn value_errors_as_null(&mut self, map_value_errors_to_null: bool) -> &mut Self {
         self.map_value_errors_to_null = map_value_errors_to_null;
         self
     }
 
+    /// Invokes `handler` for each value in the database which can not be converted into its target
+    /// type, with the zero based index of the column in the result set, the index of the row within
+    /// the batch and the error. The handler decides whether the value is emitted as `NULL`
+    /// ([`ErrorAction::SkipToNull`]), or whether the error is emitted ([`ErrorAction::Abort`]).
+    /// Use this to e.g. log invalid values, or to tolerate them only in some columns. Takes
+    /// precedence over [`Self::value_errors_as_null`]. Like with it, the fields of inferred schemas
+    /// are nullable for columns which may cause mapping errors. Text which is not correctly encoded
+    /// is governed by [`Self::with_invalid_utf8_policy`] instead. Default is no handler.
+    pub fn with_mapping_error_handler(
+        &mut self,
+        handler: impl FnMut(usize, usize, &MappingError) -> ErrorAction + Send + 'static,
+    ) -> &mut Self {
+        self.mapping_error_handler = Some(MappingErrorHandler(Arc::new(Mutex::new(handler))));
+        self
+    }
+
     /// If set to `true` text in fixed sized character columns like e.g. CHAR are trimmed of
     /// whitespaces before converted into Arrow UTF-8 arrays. Default is `false`.
     pub fn trim_fixed_sized_characters(
@@ -330,17 +977,417 @@ impl OdbcReaderBuilder {
         self
     }
 
+    /// If set to `true` text in all character columns, including variadic ones like `VARCHAR`, is
+    /// trimmed of leading and trailing whitespaces before converted into Arrow UTF-8 arrays. Use
+    /// [`Self::trim_fixed_sized_characters`] instead, if you only want to remove the padding of
+    /// fixed sized columns. Default is `false`.
+    pub fn with_trim_all_strings(&mut self, all_strings_are_trimmed: bool) -> &mut Self {
+        self.trim_all_strings = all_strings_are_trimmed;
+        self
+    }
+
+    /// If set to `true` empty strings are emitted as `NULL`. This mirrors data sources like Oracle,
+    /// which store empty strings as `NULL`. Strings are checked after trimming (see
+    /// [`Self::with_trim_all_strings`]), so e.g. values of `CHAR` columns consisting only of
+    /// padding are `NULL` too, if they are trimmed. Text columns of inferred schemas become
+    /// nullable. Default is `false`, which preserves the distinction between empty strings and
+    /// `NULL`.
+    pub fn with_empty_string_as_null(&mut self, enabled: bool) -> &mut Self {
+        self.empty_string_as_null = enabled;
+        self
+    }
+
+    /// On non windows platforms text is fetched as UTF-8 by default, and the ODBC driver is asked
+    /// to transcode it, if necessary. Some drivers are not good at this. If set to `true`, columns
+    /// with wide character types (`NCHAR`, `NVARCHAR` and `NTEXT` or similar) are fetched as UTF-16
+    /// and decoded by `arrow-odbc` instead. On windows all text is fetched as UTF-16 anyway, so
+    /// this option has no effect there. Default is `false`.
+    pub fn with_force_wide_for_wide_columns(&mut self, wide_for_wide_columns: bool) -> &mut Self {
+        self.wide_for_wide_columns = wide_for_wide_columns;
+        self
+    }
+
+    /// If set to `true` columns with a `DECIMAL` or `NUMERIC` type are fetched as arrow `Utf8`,
+    /// rather than `Decimal128`. The text is emitted exactly as rendered by the driver, without
+    /// being parsed, so the representation (e.g. trailing zeroes) is preserved. Useful for
+    /// reconciliation against the source system. Does only affect inferred schemas. Default is
+    /// `false`.
+    pub fn with_decimals_as_text(&mut self, decimals_as_text: bool) -> &mut Self {
+        self.decimals_as_text = decimals_as_text;
+        self
+    }
+
+    /// If set to `true` timestamps with a precision of more than six fractional digits (e.g.
+    /// `DATETIME2` in Microsoft SQL Server) are inferred as `Timestamp(Microsecond)` rather than
+    /// `Timestamp(Nanosecond)`. Nanoseconds since epoch in a 64 Bit integer can only represent
+    /// dates between 1677 and 2262. With microseconds the range covers any date. The trade off is
+    /// losing the last digits of precision. Does only affect inferred schemas. Default is `false`.
+    pub fn with_safe_timestamp_range(&mut self, safe_timestamp_range: bool) -> &mut Self {
+        self.safe_timestamp_range = safe_timestamp_range;
+        self
+    }
+
+    /// Timestamps with a time zone offset (e.g. `DATETIMEOFFSET` in Microsoft SQL Server) are
+    /// normalized to UTC by default, which loses the original offset. If set to `true` these
+    /// columns are inferred as a struct instead, with the naive local time in a field `ts` and the
+    /// offset to UTC in minutes in a field `offset_minutes`. E.g. `2021-04-09 18:57:50 +02:00` is
+    /// fetched as `ts: 2021-04-09 18:57:50` and `offset_minutes: 120`. The local time has
+    /// microsecond precision. Does only affect inferred schemas. Default is `false`.
+    pub fn with_preserve_timestamp_offset(&mut self, enabled: bool) -> &mut Self {
+        self.preserve_timestamp_offset = enabled;
+        self
+    }
+
+    /// Infer all timestamp columns as arrow timestamps of the given unit, independent of the
+    /// precision of the column. Use this if downstream systems require a uniform precision, e.g.
+    /// for a result set mixing `DATETIME` and `DATETIME2(6)` columns. Coarser units truncate the
+    /// fraction of a second. With nanoseconds, values outside the years 1677 to 2262 cause an
+    /// error, or are mapped to `NULL` if [`Self::value_errors_as_null`] is set. Takes precedence
+    /// over [`Self::with_safe_timestamp_range`]. Does only affect inferred schemas.
+    pub fn with_timestamp_unit(&mut self, unit: TimeUnit) -> &mut Self {
+        self.timestamp_unit = Some(unit);
+        self
+    }
+
+    /// If set to `true` every field of the inferred schema is nullable, independent of the
+    /// nullability reported by the driver. Use this if the driver misreports nullability, or if
+    /// the batches must be concatenated with batches of other sources. Columns which are not
+    /// nullable in the data source are then bound to buffers with indicators, which require a few
+    /// more bytes per row. Does only affect inferred schemas. Default is `false`.
+    pub fn with_all_columns_nullable(&mut self, all_columns_nullable: bool) -> &mut Self {
+        self.all_columns_nullable = all_columns_nullable;
+        self
+    }
+
+    /// Replace `NULL` in numeric columns with sentinel values, for consumers which can not handle
+    /// arrow nulls. Floating point columns are filled with [`NullFill::float`], which is `NaN` by
+    /// default. Integer columns are only filled if [`NullFill::integer`] is set. Inferred fields
+    /// of these columns are not nullable. If the sentinel is not representable in the type of an
+    /// integer column, [`Self::build`] fails. Default is to keep `NULL`.
+    pub fn with_numeric_null_fill(&mut self, null_fill: NullFill) -> &mut Self {
+        self.null_fill = Some(null_fill);
+        self
+    }
+
+    /// If set to `true`, building the reader logs the arrow schema it produces at info level. This
+    /// is the final schema, after inference and all overrides have been applied, listing name,
+    /// data type and nullability of each field. Useful to debug surprising type mappings, or to
+    /// compare the schemas inferred by different driver versions. Default is `false`.
+    pub fn with_log_schema(&mut self, log_schema: bool) -> &mut Self {
+        self.log_schema = log_schema;
+        self
+    }
+
+    /// Renames each field of the inferred schema, e.g. to convert column names to lowercase
+    /// snake case. The transform is called with the column name reported by the driver. Duplicates
+    /// are made unique after renaming (see [`Self::with_dedup_column_names`]), and options
+    /// referring to columns by name match the transformed names. Does only affect inferred
+    /// schemas.
+    pub fn with_column_name_transform(
+        &mut self,
+        transform: impl Fn(&str) -> String + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.column_name_transform = Some(ColumnNameTransform(Arc::new(transform)));
+        self
+    }
+
+    /// If set to `true` duplicate column names in the inferred schema are made unique by appending
+    /// a suffix. E.g. two columns named `id` become `id` and `id_1`. Useful for queries like
+    /// `SELECT *` over joins, since consumers indexing columns by name can not handle ambiguous
+    /// names. Options referring to columns by name (e.g. [`Self::with_boolean_columns`]) match the
+    /// names after renaming. Does only affect inferred schemas. Default is `false`.
+    pub fn with_dedup_column_names(&mut self, dedup_column_names: bool) -> &mut Self {
+        self.dedup_column_names = dedup_column_names;
+        self
+    }
+
+    /// If set to `true`, arrow `UInt16`, `UInt32` and `UInt64` fields of an explicit schema are
+    /// supported. ODBC has no unsigned integer types, so the column is bound as the next wider
+    /// signed integer (`i32` for `UInt16`, `i64` for `UInt32` and `UInt64`) and each value is
+    /// converted. Negative values and values too large for the arrow type cause an error, or are
+    /// mapped to `NULL` if [`Self::value_errors_as_null`] is set. Without this, these types cause
+    /// [`crate::ColumnFailure::UnsupportedArrowType`]. Columns the driver reports as unsigned
+    /// `BIGINT` (e.g. MySQL `BIGINT UNSIGNED`) are fetched into `UInt64` regardless of this
+    /// setting, without being limited to the range of `i64`. Default is `false`.
+    pub fn with_allow_unsigned_widening(&mut self, allow_unsigned_widening: bool) -> &mut Self {
+        self.allow_unsigned_widening = allow_unsigned_widening;
+        self
+    }
+
+    /// If set to `true` columns which the driver fails to describe are excluded from the inferred
+    /// schema and are not bound, rather than failing the construction of the reader. A warning is
+    /// logged for each skipped column. This allows extracting the remaining columns of a partially
+    /// broken result set. Fields of the schema are no longer necessarily at the same position as
+    /// their column in the result set. Transit buffers skipping columns are not reused by a
+    /// [`BufferPool`]. Does only affect inferred schemas. Default is `false`.
+    pub fn with_skip_undescribable_columns(&mut self, skip: bool) -> &mut Self {
+        self.skip_undescribable_columns = skip;
+        self
+    }
+
+    /// Bounds the wall clock time for reading the entire result set, rather than a single query.
+    /// The reader checks the deadline each time before it fetches the next batch. Once it is
+    /// exceeded, the reader emits an external error holding [`Error::DeadlineExceeded`] and stops
+    /// afterwards. A fetch which is already in progress is not interrupted. Use this to protect
+    /// e.g. request handlers from unbounded total latency for results with many batches. Readers
+    /// for subsequent result sets (see [`OdbcReader::next_result_set`]) share the same deadline.
+    ///
+    /// A [`ConcurrentOdbcReader`] stops consuming batches from its fetch thread the same way. The
+    /// fetch thread stalls once it has fetched the one batch it is allowed to be ahead.
+    pub fn with_deadline(&mut self, deadline: Instant) -> &mut Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Invokes `callback` each time a row group has been fetched from the data source, with the
+    /// cumulative number of rows fetched so far by the reader. Use this to report the progress of
+    /// long running extracts, without wrapping the reader. The number of rows is counted
+    /// independently for each result set (see [`OdbcReader::next_result_set`]), yet the callback
+    /// is shared.
+    ///
+    /// A [`ConcurrentOdbcReader`] invokes the callback whenever it consumes a row group from its
+    /// fetch thread. The callback is always invoked on the thread iterating the reader.
+    pub fn with_progress_callback(
+        &mut self,
+        callback: impl FnMut(usize) + Send + 'static,
+    ) -> &mut Self {
+        self.progress_callback = Some(Arc::new(Mutex::new(callback)));
+        self
+    }
+
+    /// If set to `true` the reader collects the statistics of each column of every batch it emits,
+    /// see [`OdbcReader::last_batch_statistics`]. Only [`OdbcReader`] collects statistics,
+    /// concurrent and asynchronous readers do not. Default is `false`.
+    pub fn with_statistics(&mut self, enabled: bool) -> &mut Self {
+        self.statistics = enabled;
+        self
+    }
+
+    /// Sets the query timeout of the statement before the metadata of the result set is queried to
+    /// infer the arrow schema. Some drivers execute the query in order to provide the metadata, so
+    /// without a timeout [`Self::build`] may hang indefinitly. If the timeout expires during schema
+    /// inference [`Error::InferenceTimeout`] is returned. The timeout remains set on the statement
+    /// for subsequent fetches. ODBC specifies timeouts in whole seconds, so the duration is rounded
+    /// up. Default is no timeout.
+    pub fn with_query_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.query_timeout = Some(timeout);
+        self
+    }
+
+    /// Like [`Self::with_query_timeout`], but the timeout only applies while the metadata of the
+    /// result set is queried, e.g. to describe the columns or to tell whether integers are
+    /// unsigned. Afterwards the query timeout of the statement is restored to the value it had
+    /// before, i.e. the one set with [`Self::with_query_timeout`], the one set on the statement by
+    /// the application, or no timeout at all. This way metadata calls hanging on
+    /// flaky drivers are bounded, without limiting how long fetching the rows may take.
+    ///
+    /// If the timeout expires, [`Error::InferenceTimeout`] is returned. Drivers which do not
+    /// support statement timeouts are tolerated. In that case a warning is logged and the
+    /// metadata is queried without timeout. Default is no timeout.
+    pub fn with_metadata_query_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.metadata_query_timeout = Some(timeout);
+        self
+    }
+
+    /// Many data sources do not have a native boolean type and store booleans e.g. in `CHAR(1)`
+    /// columns as `'Y'` and `'N'`, or as `0` and `1` in a `TINYINT`. The columns with the given
+    /// names are fetched as arrow `Boolean` and their values are interpreted using the associated
+    /// [`BoolEncoding`]. Comparison is case insensitive. Values which are not recognized by the
+    /// encoding cause an error, or are mapped to `NULL` if [`Self::value_errors_as_null`] is set.
+    /// If the schema is specified explicitly, these columns must be declared as `Boolean` for the
+    /// encoding to apply.
+    pub fn with_boolean_columns(&mut self, columns: &[(&str, BoolEncoding)]) -> &mut Self {
+        self.boolean_columns = columns
+            .iter()
+            .map(|&(name, encoding)| (name.to_owned(), encoding))
+            .collect();
+        self
+    }
+
+    /// What to do with text fetched from the data source, which is not correctly encoded. This is
+    /// usually caused by a column holding data in an encoding other than the one it is labeled
+    /// with, or a misconfigured locale. By default the reader fails with
+    /// [`crate::MappingError::InvalidUtf8`] (or [`crate::MappingError::InvalidUtf16`] for wide
+    /// text). [`InvalidUtf8Policy::Replace`] keeps the valid parts of the value, and
+    /// [`InvalidUtf8Policy::Null`] discards the entire value. In the latter case inferred text
+    /// columns are always nullable.
+    pub fn with_invalid_utf8_policy(&mut self, policy: InvalidUtf8Policy) -> &mut Self {
+        self.invalid_utf8_policy = policy;
+        self
+    }
+
+    /// Transfer the text of the column with the given name using the specified encoding. This
+    /// overrides the global choice, which is UTF-16 on windows and UTF-8 everywhere else (see also
+    /// [`Self::with_force_wide_for_wide_columns`]). E.g. ASCII `VARCHAR` columns are cheapest to
+    /// transfer as [`TextEncoding::Ascii`], while `NVARCHAR` columns holding CJK characters are more
+    /// compact in UTF-16 and do not depend on the locale of the client. Calling this again for the
+    /// same column replaces the encoding.
+    pub fn with_text_encoding_for(
+        &mut self,
+        column_name: &str,
+        encoding: TextEncoding,
+    ) -> &mut Self {
+        self.text_encodings.retain(|(name, _)| name != column_name);
+        self.text_encodings.push((column_name.to_owned(), encoding));
+        self
+    }
+
+    /// Fetch the columns with the given names as arrow `Utf8`, independent of their relational
+    /// type. This is an escape hatch for individual columns whose values can not be represented by
+    /// the inferred arrow type, e.g. timestamps outside of the range of nanoseconds, without
+    /// mapping value errors to `NULL` for all columns. The buffer size is derived from the column
+    /// size or the display size reported by the driver (see also [`Self::with_max_text_size`]).
+    /// Takes precedence over other options referring to the same columns. Does only affect inferred
+    /// schemas. If the schema is specified explicitly, declaring a column as `Utf8` has the same
+    /// effect.
+    pub fn with_columns_as_text(&mut self, column_names: &[&str]) -> &mut Self {
+        self.text_columns = column_names.iter().map(|&name| name.to_owned()).collect();
+        self
+    }
+
+    /// Use `data_type` for the column with the given name, rather than the inferred arrow type.
+    /// All other columns are still inferred, so other than [`Self::with_schema`] you do not need
+    /// to know the entire schema. E.g. override a `TINYINT` column to `UInt8` for a driver which
+    /// does not report it as unsigned. Or override a MySQL `SET` column to `List<Utf8>`, to split
+    /// its comma separated labels. The driver reports `SET` and `ENUM` columns as ordinary
+    /// character columns, so they are not detected during inference. Nullability is still
+    /// inferred. Takes precedence over other options referring to the same column, except
+    /// [`Self::with_columns_as_text`] and [`Self::with_long_data_streaming`]. Calling this again
+    /// for the same column replaces the override. Types which can not be fetched cause
+    /// [`Self::build`] to fail with [`crate::ColumnFailure::UnsupportedArrowType`]. Does only
+    /// affect inferred schemas.
+    pub fn with_column_override(&mut self, column_name: &str, data_type: DataType) -> &mut Self {
+        self.column_overrides
+            .retain(|(name, _)| name != column_name);
+        self.column_overrides
+            .push((column_name.to_owned(), data_type));
+        self
+    }
+
+    /// Fetch the text columns with the given names as arrow `Binary`, holding the raw bytes stored
+    /// in the data source. No encoding is involved, so this is an escape hatch for legacy systems
+    /// storing binary data in e.g. `CHAR` or `VARCHAR` columns, which would otherwise be corrupted
+    /// or fail to decode. The buffer size is derived from the column size, like for binary columns
+    /// (see also [`Self::with_max_binary_size`]). Does only affect inferred schemas. If the schema
+    /// is specified explicitly, declaring a text column as `Binary` has the same effect.
+    pub fn with_text_columns_as_binary(&mut self, column_names: &[&str]) -> &mut Self {
+        self.binary_columns = column_names.iter().map(|&name| name.to_owned()).collect();
+        self
+    }
+
+    /// Fetch the text columns with the given names dictionary encoded, as arrow
+    /// `Dictionary(Int32, Utf8)`. Reduces memory usage for columns with few distinct values, like
+    /// status or country codes, since each distinct value is only stored once. The dictionary is
+    /// shared by all batches of the reader, so keys are stable across batches and the dictionary
+    /// of each batch holds all values encountered so far. Does only affect inferred schemas. If
+    /// the schema is specified explicitly, declaring a text column as `Dictionary(Int32, Utf8)` has
+    /// the same effect.
+    pub fn with_dictionary_encoding(&mut self, column_names: &[&str]) -> &mut Self {
+        self.dictionary_columns = column_names.iter().map(|&name| name.to_owned()).collect();
+        self
+    }
+
+    /// Stream the values of the text columns with the given names piece by piece using
+    /// `SQLGetData`, rather than binding them to the transit buffer. Use this for columns like
+    /// `VARCHAR(MAX)`, whose values may be larger than any buffer you would like to allocate for
+    /// each row. Values are neither truncated nor limited by [`Self::with_max_text_size`], but only
+    /// by memory. The columns are fetched as arrow `Utf8`, using narrow (UTF-8) text.
+    ///
+    /// This comes at a cost: Rows are fetched one at a time, and the remaining columns are rebound
+    /// for each row. Batches are still assembled to hold [`OdbcReader::max_rows_per_batch`] rows.
+    /// Many drivers only support `SQLGetData` for columns to the right of the bound ones, so
+    /// consider selecting the streamed columns last. The reader can not be turned into a
+    /// [`ConcurrentOdbcReader`], nor be built asynchronously. Applies to explicit schemas, too, if
+    /// the field is declared as `Utf8`.
+    pub fn with_long_data_streaming(&mut self, column_names: &[&str]) -> &mut Self {
+        self.long_data_columns = column_names.iter().map(|&name| name.to_owned()).collect();
+        self
+    }
+
+    /// Name of the database management system the cursor is connected to. You can obtain it using
+    /// [`odbc_api::Connection::database_management_system_name`]. Knowing the data source allows
+    /// for mappings which are specific to it:
+    ///
+    /// * `Microsoft SQL Server`: Columns of user defined CLR types, most notably `GEOGRAPHY` and
+    ///   `GEOMETRY`, are fetched as arrow `Binary` holding the bytes of the native serialization,
+    ///   rather than as a hex encoded string. The field metadata `arrow.spatial` is set to
+    ///   `mssql-clr`. Please note that this is not WKB. If you require WKB, select the column using
+    ///   `STAsBinary()` instead. Since the type code is shared among all user defined types, this
+    ///   also applies to e.g. `HIERARCHYID` columns. Set [`Self::with_max_binary_size`], since the
+    ///   driver does not report a sensible upper bound for these columns.
+    ///
+    /// Does only affect inferred schemas.
+    pub fn with_dbms_name(&mut self, dbms_name: &str) -> &mut Self {
+        self.dbms_name = Some(dbms_name.to_owned());
+        self
+    }
+
+    /// Take the transit buffer from `buffer_pool` instead of allocating a new one, if a buffer
+    /// with matching shape is available. Once the reader is dropped, or destroyed using
+    /// `into_cursor` the buffer is returned to the pool. This amortizes the cost of allocating
+    /// buffers over many queries with similar result sets. See [`BufferPool`].
+    pub fn with_buffer_pool(&mut self, buffer_pool: &BufferPool) -> &mut Self {
+        self.buffer_pool = Some(buffer_pool.clone());
+        self
+    }
+
+    /// Options influencing the choice of the read strategies for the individual columns.
+    fn read_options(&self) -> ReadOptions {
+        let buffer_allocation_options = BufferAllocationOptions {
+            max_text_size: self.max_text_size,
+            max_binary_size: self.max_binary_size,
+            fallibale_allocations: self.fallibale_allocations,
+        };
+        ReadOptions {
+            buffer_allocation_options,
+            map_value_errors_to_null: self.map_value_errors_to_null,
+            mapping_error_handler: self.mapping_error_handler.clone(),
+            trim_fixed_sized_character_strings: self.trim_fixed_sized_character_strings,
+            trim_all_strings: self.trim_all_strings,
+            empty_string_as_null: self.empty_string_as_null,
+            wide_for_wide_columns: self.wide_for_wide_columns,
+            invalid_utf8_policy: self.invalid_utf8_policy,
+            decimals_as_text: self.decimals_as_text,
+            column_name_transform: self.column_name_transform.clone(),
+            dedup_column_names: self.dedup_column_names,
+            skip_undescribable_columns: self.skip_undescribable_columns,
+            allow_unsigned_widening: self.allow_unsigned_widening,
+            safe_timestamp_range: self.safe_timestamp_range,
+            timestamp_unit: self.timestamp_unit,
+            all_columns_nullable: self.all_columns_nullable,
+            null_fill: self.null_fill,
+            query_timeout: self.query_timeout,
+            metadata_query_timeout: self.metadata_query_timeout,
+            preserve_timestamp_offset: self.preserve_timestamp_offset,
+            schema_metadata: self.schema_metadata.clone(),
+            boolean_columns: self.boolean_columns.clone(),
+            text_encodings: self.text_encodings.clone(),
+            text_columns: self.text_columns.clone(),
+            column_overrides: self.column_overrides.clone(),
+            binary_columns: self.binary_columns.clone(),
+            dictionary_columns: self.dictionary_columns.clone(),
+            long_data_columns: self.long_data_columns.clone(),
+            dbms_name: self.dbms_name.clone(),
+        }
+    }
+
     /// No matter if the user explicitly specified a limit in row size, a memory limit, both or
     /// neither. In order to construct a reader we need to decide on the buffer size in rows.
     fn buffer_size_in_rows(&self, bytes_per_row: usize) -> Result<usize, Error> {
+        // Each of the two buffers of a concurrent reader gets half of the budget.
+        let max_bytes_per_batch = if self.budget_includes_concurrency {
+            self.max_bytes_per_batch / 2
+        } else {
+            self.max_bytes_per_batch
+        };
         // If schema is empty, return before division by zero error.
         if bytes_per_row == 0 {
-            return Ok(self.max_bytes_per_batch);
+            return Ok(max_bytes_per_batch);
         }
-        let rows_per_batch = self.max_bytes_per_batch / bytes_per_row;
+        let rows_per_batch = max_bytes_per_batch / bytes_per_row;
         if rows_per_batch == 0 {
             Err(Error::OdbcBufferTooSmall {
-                max_bytes_per_batch: self.max_bytes_per_batch,
+                max_bytes_per_batch,
                 bytes_per_row,
             })
         } else {
@@ -348,6 +1395,52 @@ impl OdbcReaderBuilder {
         }
     }
 
+    /// The arrow schema a reader built with these settings would emit for the result set of
+    /// `cursor`. Queries the metadata of the result set and chooses the read strategies for each
+    /// column, exactly like [`Self::build`], yet neither allocates nor binds a transit buffer. The
+    /// cursor can still be passed to [`Self::build`] afterwards. Use this to e.g. decide on the
+    /// batch size, based on the schema.
+    pub fn infer_schema(&self, cursor: &mut impl ResultSetMetadata) -> Result<SchemaRef, Error> {
+        let converter = ToRecordBatch::new(cursor, self.schema.clone(), &self.read_options())?;
+        Ok(converter.schema().clone())
+    }
+
+    /// Checks whether the columns of a result set can be fetched into the arrow fields of `schema`,
+    /// using the options of this builder, without binding any buffers. The n-th field of the
+    /// schema corresponds to the n-th column of the result set. Useful to catch mismatches between
+    /// a known projection and the actual result set early, e.g. in test harnesses. The same checks
+    /// are performed by [`Self::build`] if a schema is set with [`Self::with_schema`], but only
+    /// once the reader is created.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use anyhow::Error;
+    ///
+    /// use arrow_odbc::{
+    ///     arrow::datatypes::{DataType, Field, Schema},
+    ///     odbc_api::Connection,
+    ///     OdbcReaderBuilder,
+    /// };
+    ///
+    /// fn check_projection(connection: &Connection<'_>) -> Result<(), Error> {
+    ///     let mut prepared = connection.prepare("SELECT id, name FROM users")?;
+    ///     let schema = Schema::new(vec![
+    ///         Field::new("id", DataType::Int64, false),
+    ///         Field::new("name", DataType::Utf8, true),
+    ///     ]);
+    ///     OdbcReaderBuilder::new().validate_schema(&schema, &mut prepared)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn validate_schema(
+        &self,
+        schema: &Schema,
+        cursor: &mut impl ResultSetMetadata,
+    ) -> Result<(), Error> {
+        validate_schema(schema, cursor, &self.read_options())
+    }
+
     /// Constructs an [`OdbcReader`] which consumes the giver cursor. The cursor will also be used
     /// to infer the Arrow schema if it has not been supplied explicitly.
     ///
@@ -358,36 +1451,184 @@ impl OdbcReaderBuilder {
     ///   usually faster than fetching results row by row as it saves roundtrips to the database.
     ///   The type of these buffers will be inferred from the arrow schema. Not every arrow type is
     ///   supported though.
-    pub fn build<C>(&self, mut cursor: C) -> Result<OdbcReader<C>, Error>
+    pub fn build<C>(&self, cursor: C) -> Result<OdbcReader<C>, Error>
     where
         C: Cursor,
     {
-        let buffer_allocation_options = BufferAllocationOptions {
-            max_text_size: self.max_text_size,
-            max_binary_size: self.max_binary_size,
-            fallibale_allocations: self.fallibale_allocations,
-        };
+        self.build_with_pool(cursor, self.buffer_pool.as_ref())
+    }
+
+    /// Constructs an [`AsyncOdbcReader`] which consumes a cursor in asynchronous polling mode, as
+    /// e.g. returned by [`odbc_api::Connection::execute_polling`]. Like [`Self::build`], the
+    /// cursor is used to infer the arrow schema, if it has not been supplied explicitly. The
+    /// metadata of the result set is queried synchronously.
+    ///
+    /// All options affecting the choice of the arrow types and the buffer sizes apply. So do
+    /// [`Self::with_max_bound_columns`], [`Self::with_max_total_rows`], [`Self::with_deadline`]
+    /// and [`Self::with_progress_callback`]. [`Self::with_exact_batch_rows`],
+    /// [`Self::with_odbc_fetch_rows`] and streaming long data (see
+    /// [`Self::with_long_data_streaming`]) are not supported by the asynchronous reader and cause
+    /// an error.
+    pub fn build_async<S>(&self, mut cursor: CursorPolling<S>) -> Result<AsyncOdbcReader<S>, Error>
+    where
+        S: AsStatementRef,
+    {
+        if self.exact_batch_rows.is_some() {
+            return Err(Error::UnsupportedByAsyncReader {
+                option: "with_exact_batch_rows",
+            });
+        }
+        if self.odbc_fetch_rows.is_some() {
+            return Err(Error::UnsupportedByAsyncReader {
+                option: "with_odbc_fetch_rows",
+            });
+        }
+        self.check_max_bound_columns(&mut PollingMetadata(&mut cursor))?;
+        let read_options = self.read_options();
         let converter = ToRecordBatch::new(
-            &mut cursor,
+            &mut PollingMetadata(&mut cursor),
             self.schema.clone(),
-            buffer_allocation_options,
-            self.map_value_errors_to_null,
-            self.trim_fixed_sized_character_strings,
+            &read_options,
         )?;
+        if converter.has_long_data() {
+            return Err(Error::LongDataStreamingUnsupported);
+        }
+        if self.log_schema {
+            log_schema(converter.schema());
+        }
         let bytes_per_row = converter.row_size_in_bytes();
         let buffer_size_in_rows = self.buffer_size_in_rows(bytes_per_row)?;
-        let row_set_buffer =
-            converter.allocate_buffer(buffer_size_in_rows, self.fallibale_allocations)?;
-        let batch_stream = cursor.bind_buffer(row_set_buffer).unwrap();
+        let row_set_buffer = converter.allocate_buffer(
+            buffer_size_in_rows,
+            self.fallibale_allocations,
+            self.buffer_pool.as_ref(),
+        )?;
+        let batch_stream = cursor
+            .bind_buffer(row_set_buffer)
+            .map_err(Error::UnableToBindBuffer)?;
+        Ok(AsyncOdbcReader::new(
+            converter,
+            batch_stream,
+            self.deadline.map(Deadline::new),
+            self.progress_callback.clone().map(Progress::new),
+            self.max_total_rows.map(RowLimit::new),
+        ))
+    }
+
+    /// Fails with [`Error::TooManyColumns`], if the result set has more columns than allowed by
+    /// [`Self::with_max_bound_columns`].
+    fn check_max_bound_columns(&self, cursor: &mut impl ResultSetMetadata) -> Result<(), Error> {
+        let Some(max_bound_columns) = self.max_bound_columns else {
+            return Ok(());
+        };
+        let num_columns = cursor
+            .num_result_cols()
+            .map_err(Error::UnableToRetrieveNumCols)?
+            .try_into()
+            .unwrap();
+        if num_columns > max_bound_columns {
+            return Err(Error::TooManyColumns {
+                num_columns,
+                max_bound_columns,
+            });
+        }
+        Ok(())
+    }
+
+    /// Like [`Self::build`], but takes the transit buffer from `buffer_pool` if possible, rather
+    /// than from the pool specified by the user.
+    fn build_with_pool<C>(
+        &self,
+        mut cursor: C,
+        buffer_pool: Option<&BufferPool>,
+    ) -> Result<OdbcReader<C>, Error>
+    where
+        C: Cursor,
+    {
+        self.check_max_bound_columns(&mut cursor)?;
+        let read_options = self.read_options();
+        let converter = ToRecordBatch::new(&mut cursor, self.schema.clone(), &read_options)?;
+        if self.log_schema {
+            log_schema(converter.schema());
+        }
+        // A result set without columns has nothing to fetch, so no buffer is bound to its cursor.
+        let (cursor, exact_batch_rows, max_rows_per_batch, buffer_size_in_bytes) = if converter
+            .schema()
+            .fields()
+            .is_empty()
+        {
+            (
+                CursorState::Unbound(cursor),
+                None,
+                self.max_num_rows_per_batch,
+                0,
+            )
+        } else {
+            let bytes_per_row = converter.row_size_in_bytes();
+            let buffer_size_in_rows = self.buffer_size_in_rows(bytes_per_row)?;
+            // Streamed columns are fetched for the current row only, so rows are fetched one at
+            // a time.
+            let odbc_fetch_rows = if converter.has_long_data() {
+                Some(1)
+            } else {
+                self.odbc_fetch_rows
+            };
+            // Fetching fewer rows from the data source than each batch holds, requires
+            // accumulating multiple fetches into one batch.
+            let (fetch_rows, exact_batch_rows) = match odbc_fetch_rows {
+                Some(fetch_rows) if fetch_rows < buffer_size_in_rows => (
+                    fetch_rows,
+                    Some(self.exact_batch_rows.unwrap_or(buffer_size_in_rows)),
+                ),
+                _ => (buffer_size_in_rows, self.exact_batch_rows),
+            };
+            let row_set_buffer =
+                converter.allocate_buffer(fetch_rows, self.fallibale_allocations, buffer_pool)?;
+            let batch_stream = cursor
+                .bind_buffer(row_set_buffer)
+                .map_err(Error::UnableToBindBuffer)?;
+            (
+                CursorState::Bound(batch_stream),
+                exact_batch_rows,
+                buffer_size_in_rows,
+                fetch_rows * bytes_per_row,
+            )
+        };
 
         Ok(OdbcReader {
             converter,
-            batch_stream,
+            cursor,
             fallibale_allocations: self.fallibale_allocations,
+            buffer_pool: self.buffer_pool.clone(),
+            exact_batch_rows: exact_batch_rows.map(ExactBatchRows::new),
+            max_rows_per_batch,
+            buffer_size_in_bytes,
+            deadline: self.deadline.map(Deadline::new),
+            progress: self.progress_callback.clone().map(Progress::new),
+            row_limit: self.max_total_rows.map(RowLimit::new),
+            last_batch_statistics: Vec::new(),
+            statement_index: 0,
+            builder: self.clone(),
         })
     }
 }
 
+fn log_schema(schema: &SchemaRef) {
+    let fields: String = schema
+        .fields()
+        .iter()
+        .map(|field| {
+            let nullability = if field.is_nullable() {
+                "nullable"
+            } else {
+                "not null"
+            };
+            format!("\n{}: {} {nullability}", field.name(), field.data_type())
+        })
+        .collect();
+    info!("Arrow schema of the result set:{fields}");
+}
+
 pub fn odbc_to_arrow_error(odbc_error: odbc_api::Error) -> ArrowError {
     ArrowError::from_external_error(Box::new(odbc_error))
 }
@@ -0,0 +1,27 @@
+use std::sync::{Arc, Mutex};
+
+/// Callback invoked with the cumulative number of rows fetched from the data source. See
+/// [`crate::OdbcReaderBuilder::with_progress_callback`].
+pub type ProgressCallback = Arc<Mutex<dyn FnMut(usize) + Send>>;
+
+/// Counts the rows fetched by a reader and reports them to the callback of the user.
+pub struct Progress {
+    callback: ProgressCallback,
+    /// Rows fetched so far by the reader.
+    num_rows: usize,
+}
+
+impl Progress {
+    pub fn new(callback: ProgressCallback) -> Self {
+        Self {
+            callback,
+            num_rows: 0,
+        }
+    }
+
+    /// Called after each row group fetched from the data source.
+    pub fn report(&mut self, num_rows_in_batch: usize) {
+        self.num_rows += num_rows_in_batch;
+        (self.callback.lock().unwrap())(self.num_rows);
+    }
+}
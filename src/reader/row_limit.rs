@@ -0,0 +1,43 @@
+use std::cmp::min;
+
+use arrow::record_batch::RecordBatch;
+
+/// Caps the total number of rows emitted by a reader. See
+/// [`crate::OdbcReaderBuilder::with_max_total_rows`].
+pub struct RowLimit {
+    /// Rows which may still be emitted.
+    remaining: usize,
+}
+
+impl RowLimit {
+    pub fn new(max_total_rows: usize) -> Self {
+        Self {
+            remaining: max_total_rows,
+        }
+    }
+
+    /// `true` once the limit has been reached. The reader ends the iteration, without fetching
+    /// further batches.
+    pub fn is_reached(&self) -> bool {
+        self.remaining == 0
+    }
+
+    /// Counts the rows of the batch against the limit. The batch is truncated, if it holds more
+    /// rows than remaining.
+    pub fn apply(&mut self, batch: RecordBatch) -> RecordBatch {
+        let num_rows = self.count(batch.num_rows());
+        if num_rows == batch.num_rows() {
+            batch
+        } else {
+            batch.slice(0, num_rows)
+        }
+    }
+
+    /// Counts `num_rows` against the limit, without a batch. Returns how many of them are within
+    /// the limit.
+    pub fn count(&mut self, num_rows: usize) -> usize {
+        let num_rows = min(num_rows, self.remaining);
+        self.remaining -= num_rows;
+        num_rows
+    }
+}
@@ -0,0 +1,102 @@
+use arrow::{
+    array::{downcast_primitive_array, Array, ArrayRef},
+    record_batch::RecordBatch,
+};
+
+/// Statistics of one column of a batch emitted by a reader. See
+/// [`crate::OdbcReaderBuilder::with_statistics`].
+#[derive(Debug, Clone)]
+pub struct ColumnStats {
+    /// Number of `NULL` values in the column.
+    pub null_count: usize,
+    /// Array holding the smallest value of the column as its only element. `None` if the column is
+    /// not of a primitive type (e.g. text), or if all its values are `NULL`. `NaN` is skipped.
+    pub min: Option<ArrayRef>,
+    /// Array holding the largest value of the column as its only element. `None` if the column is
+    /// not of a primitive type (e.g. text), or if all its values are `NULL`. `NaN` is skipped.
+    pub max: Option<ArrayRef>,
+}
+
+impl ColumnStats {
+    /// Collects the statistics of every column of the batch.
+    pub fn for_batch(batch: &RecordBatch) -> Vec<ColumnStats> {
+        batch.columns().iter().map(ColumnStats::new).collect()
+    }
+
+    fn new(array: &ArrayRef) -> Self {
+        let null_count = array.null_count();
+        let (min, max) = if null_count < array.len() {
+            min_max(array).unzip()
+        } else {
+            (None, None)
+        };
+        Self {
+            null_count,
+            min,
+            max,
+        }
+    }
+}
+
+/// Smallest and largest value of a primitive column, each sliced out of the column. `None` for
+/// other columns, or if there is no value besides `NULL` and `NaN`.
+fn min_max(array: &ArrayRef) -> Option<(ArrayRef, ArrayRef)> {
+    let (min, max) = downcast_primitive_array!(
+        array => min_max_indices(array.iter()),
+        _ => None,
+    )?;
+    Some((array.slice(min, 1), array.slice(max, 1)))
+}
+
+/// Indices of the smallest and the largest value, found in a single pass. `NULL`s are skipped. So
+/// are values which are not comparable to themselves, i.e. `NaN`.
+fn min_max_indices<T>(values: impl Iterator<Item = Option<T>>) -> Option<(usize, usize)>
+where
+    T: PartialOrd + Copy,
+{
+    let mut extremes: Option<((usize, T), (usize, T))> = None;
+    for (index, value) in values.enumerate() {
+        let Some(value) = value else {
+            continue;
+        };
+        if value.partial_cmp(&value).is_none() {
+            continue;
+        }
+        extremes = match extremes {
+            None => Some(((index, value), (index, value))),
+            Some((mut min, mut max)) => {
+                if value < min.1 {
+                    min = (index, value);
+                }
+                if value > max.1 {
+                    max = (index, value);
+                }
+                Some((min, max))
+            }
+        };
+    }
+    extremes.map(|((min, _), (max, _))| (min, max))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::min_max_indices;
+
+    #[test]
+    fn nan_is_neither_min_nor_max() {
+        let values = [Some(1.5), Some(f64::NAN), None, Some(-2.0)];
+
+        let extremes = min_max_indices(values.into_iter());
+
+        assert_eq!(Some((3, 0)), extremes);
+    }
+
+    #[test]
+    fn only_nan_has_no_extremes() {
+        let values = [Some(f32::NAN), None];
+
+        let extremes = min_max_indices(values.into_iter());
+
+        assert_eq!(None, extremes);
+    }
+}
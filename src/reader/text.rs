@@ -1,23 +1,84 @@
-use std::{char::decode_utf16, cmp::min, num::NonZeroUsize, sync::Arc};
+use std::{
+    borrow::Cow,
+    char::{decode_utf16, REPLACEMENT_CHARACTER},
+    cmp::min,
+    marker::PhantomData,
+    num::NonZeroUsize,
+    sync::Arc,
+};
 
-use arrow::array::{ArrayRef, StringBuilder};
+use arrow::array::{ArrayRef, GenericStringBuilder, OffsetSizeTrait};
 use odbc_api::{
     buffers::{AnySlice, BufferDesc},
+    sys::SqlDataType,
     DataType as OdbcDataType,
 };
 
-use super::{ColumnFailure, MappingError, ReadStrategy};
+use super::{ColumnFailure, MappingError, ReadOptions, ReadStrategy};
+
+/// Encoding used to transfer text from the data source to the application.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextEncoding {
+    /// Fetch text using narrow characters, assumed to be UTF-8.
+    Utf8,
+    /// Fetch text using wide characters, which are UTF-16.
+    Utf16,
+    /// Fetch text using narrow characters, which are expected to be ASCII. Other than for
+    /// [`Self::Utf8`] the transit buffer holds exactly one byte for each character of the column,
+    /// rather than accounting for multi byte characters. Values containing bytes outside of the
+    /// ASCII range are treated like invalid UTF-8 (see [`InvalidUtf8Policy`]). Values of wider
+    /// characters are truncated by the driver.
+    Ascii,
+}
+
+/// What to do with text fetched from the data source, which is not correctly encoded. I.e. invalid
+/// UTF-8 for narrow text, or unpaired surrogates for wide text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InvalidUtf8Policy {
+    /// Emit [`crate::MappingError::InvalidUtf8`] or [`crate::MappingError::InvalidUtf16`].
+    #[default]
+    Error,
+    /// Replace the offending sequences with the Unicode replacement character `U+FFFD`.
+    Replace,
+    /// Emit `NULL` for the entire value.
+    Null,
+}
 
 /// This function decides wether this column will be queried as narrow (assumed to be utf-8) or
 /// wide text (assumed to be utf-16). The reason we do not always use narrow is that the encoding
 /// dependends on the system locals which is usually not UTF-8 on windows systems. Furthermore we
-/// are trying to adapt the buffer size to the maximum string length the column could contain.
+/// are trying to adapt the buffer size to the maximum string length the column could contain. On
+/// non windows platforms columns with wide character types (e.g. `NVARCHAR`) can also be fetched
+/// as wide text, if requested by the user. An encoding explicitly chosen for the column takes
+/// precedence over all of this.
 pub fn choose_text_strategy(
     sql_type: OdbcDataType,
     lazy_display_size: impl FnOnce() -> Result<Option<NonZeroUsize>, odbc_api::Error>,
-    max_text_size: Option<usize>,
-    trim_fixed_sized_character_strings: bool,
+    options: &ReadOptions,
+    column_name: &str,
+) -> Result<Box<dyn ReadStrategy + Send>, ColumnFailure> {
+    text_strategy::<i32>(sql_type, lazy_display_size, options, column_name)
+}
+
+/// Like [`choose_text_strategy`], but the strategy emits `LargeUtf8` arrays, whose 64 Bit offsets
+/// allow for more than 2GiB of text in a single array.
+pub fn choose_large_text_strategy(
+    sql_type: OdbcDataType,
+    lazy_display_size: impl FnOnce() -> Result<Option<NonZeroUsize>, odbc_api::Error>,
+    options: &ReadOptions,
+    column_name: &str,
+) -> Result<Box<dyn ReadStrategy + Send>, ColumnFailure> {
+    text_strategy::<i64>(sql_type, lazy_display_size, options, column_name)
+}
+
+/// Text strategy emitting arrays with offsets of type `O`.
+fn text_strategy<O: OffsetSizeTrait>(
+    sql_type: OdbcDataType,
+    lazy_display_size: impl FnOnce() -> Result<Option<NonZeroUsize>, odbc_api::Error>,
+    options: &ReadOptions,
+    column_name: &str,
 ) -> Result<Box<dyn ReadStrategy + Send>, ColumnFailure> {
+    let max_text_size = options.buffer_allocation_options.max_text_size;
     let apply_buffer_limit = |len| match (len, max_text_size) {
         (None, None) => Err(ColumnFailure::ZeroSizedColumn { sql_type }),
         (None, Some(limit)) => Ok(limit),
@@ -28,8 +89,38 @@ pub fn choose_text_strategy(
         sql_type,
         OdbcDataType::Char { .. } | OdbcDataType::WChar { .. }
     );
-    let trim = trim_fixed_sized_character_strings && is_fixed_sized_char;
-    let strategy: Box<dyn ReadStrategy + Send> = if cfg!(target_os = "windows") {
+    let trim = options.trim_all_strings
+        || (options.trim_fixed_sized_character_strings && is_fixed_sized_char);
+    let is_wide_column = matches!(
+        sql_type,
+        OdbcDataType::WChar { .. } | OdbcDataType::WVarchar { .. }
+    ) || matches!(
+        sql_type,
+        OdbcDataType::Other { data_type, .. } if data_type == SqlDataType::EXT_W_LONG_VARCHAR
+    );
+    if options.text_encoding(column_name) == Some(TextEncoding::Ascii) {
+        // Every ASCII character takes exactly one byte, so the length in characters suffices.
+        let len = sql_type
+            .column_size()
+            .map(Ok)
+            .or_else(|| lazy_display_size().transpose())
+            .transpose()
+            .map_err(|source| ColumnFailure::UnknownStringLength { sql_type, source })?;
+        let len = apply_buffer_limit(len.map(NonZeroUsize::get))?;
+        return Ok(Box::new(NarrowText::<O>::ascii(
+            len,
+            trim,
+            options.empty_string_as_null,
+            options.invalid_utf8_policy,
+            column_name.to_owned(),
+        )));
+    }
+    let use_wide = match options.text_encoding(column_name) {
+        Some(TextEncoding::Utf16) => true,
+        Some(TextEncoding::Utf8 | TextEncoding::Ascii) => false,
+        None => cfg!(target_os = "windows") || (options.wide_for_wide_columns && is_wide_column),
+    };
+    let strategy: Box<dyn ReadStrategy + Send> = if use_wide {
         let hex_len = sql_type
             .utf16_len()
             .map(Ok)
@@ -37,7 +128,13 @@ pub fn choose_text_strategy(
             .transpose()
             .map_err(|source| ColumnFailure::UnknownStringLength { sql_type, source })?;
         let hex_len = apply_buffer_limit(hex_len.map(NonZeroUsize::get))?;
-        wide_text_strategy(hex_len, trim)
+        wide_text_strategy::<O>(
+            hex_len,
+            trim,
+            options.empty_string_as_null,
+            options.invalid_utf8_policy,
+            column_name,
+        )
     } else {
         let octet_len = sql_type
             .utf8_len()
@@ -49,37 +146,87 @@ pub fn choose_text_strategy(
         // So far only Linux users seemed to have complained about panics due to garbage indices?
         // Linux usually would use UTF-8, so we only invest work in working around this for narrow
         // strategies
-        narrow_text_strategy(octet_len, trim)
+        narrow_text_strategy::<O>(
+            octet_len,
+            trim,
+            options.empty_string_as_null,
+            options.invalid_utf8_policy,
+            column_name,
+        )
     };
 
     Ok(strategy)
 }
 
-fn wide_text_strategy(u16_len: usize, trim: bool) -> Box<dyn ReadStrategy + Send> {
-    Box::new(WideText::new(u16_len, trim))
+fn wide_text_strategy<O: OffsetSizeTrait>(
+    u16_len: usize,
+    trim: bool,
+    empty_as_null: bool,
+    invalid_utf8_policy: InvalidUtf8Policy,
+    column_name: &str,
+) -> Box<dyn ReadStrategy + Send> {
+    Box::new(WideText::<O>::new(
+        u16_len,
+        trim,
+        empty_as_null,
+        invalid_utf8_policy,
+        column_name.to_owned(),
+    ))
 }
 
-fn narrow_text_strategy(octet_len: usize, trim: bool) -> Box<dyn ReadStrategy + Send> {
-    Box::new(NarrowText::new(octet_len, trim))
+fn narrow_text_strategy<O: OffsetSizeTrait>(
+    octet_len: usize,
+    trim: bool,
+    empty_as_null: bool,
+    invalid_utf8_policy: InvalidUtf8Policy,
+    column_name: &str,
+) -> Box<dyn ReadStrategy + Send> {
+    Box::new(NarrowText::<O>::new(
+        octet_len,
+        trim,
+        empty_as_null,
+        invalid_utf8_policy,
+        column_name.to_owned(),
+    ))
 }
 
 /// Strategy requesting the text from the database as UTF-16 (Wide characters) and emmitting it as
 /// UTF-8. We use it, since the narrow representation in ODBC is not always guaranteed to be UTF-8,
-/// but depends on the local instead.
-pub struct WideText {
+/// but depends on the local instead. `O` is the offset type of the emitted string arrays.
+pub struct WideText<O> {
     /// Maximum string length in u16, excluding terminating zero
     max_str_len: usize,
     /// Wether the string should be trimmed.
     trim: bool,
+    /// Emit `NULL` for empty strings.
+    empty_as_null: bool,
+    /// What to do with unpaired surrogates.
+    invalid_utf8_policy: InvalidUtf8Policy,
+    /// Name of the column. Used to give context in case the driver does not return valid UTF-16.
+    column_name: String,
+    offset: PhantomData<O>,
 }
 
-impl WideText {
-    pub fn new(max_str_len: usize, trim: bool) -> Self {
-        Self { max_str_len, trim }
+impl<O> WideText<O> {
+    pub fn new(
+        max_str_len: usize,
+        trim: bool,
+        empty_as_null: bool,
+        invalid_utf8_policy: InvalidUtf8Policy,
+        column_name: String,
+    ) -> Self {
+        Self {
+            max_str_len,
+            trim,
+            empty_as_null,
+            invalid_utf8_policy,
+            column_name,
+            offset: PhantomData,
+        }
     }
 }
 
-impl ReadStrategy for WideText {
+impl<O: OffsetSizeTrait> ReadStrategy for WideText<O> {
     fn buffer_desc(&self) -> BufferDesc {
         BufferDesc::WText {
             max_str_len: self.max_str_len,
@@ -93,21 +240,39 @@ impl ReadStrategy for WideText {
         // this is 1 to one, and also not every string is likeyl to use its maximum capacity, we
         // rather accept the reallocation in these scenarios.
         let data_capacity = self.max_str_len * item_capacity;
-        let mut builder = StringBuilder::with_capacity(item_capacity, data_capacity);
+        let mut builder = GenericStringBuilder::<O>::with_capacity(item_capacity, data_capacity);
         // Buffer used to convert individual values from utf16 to utf8.
         let mut buf_utf8 = String::new();
-        for value in view.iter() {
+        for (row, value) in view.iter().enumerate() {
             buf_utf8.clear();
             let opt = if let Some(utf16) = value {
+                let mut is_valid = true;
                 for c in decode_utf16(utf16.as_slice().iter().cloned()) {
-                    buf_utf8.push(c.unwrap());
+                    match (c, self.invalid_utf8_policy) {
+                        (Ok(c), _) => buf_utf8.push(c),
+                        (Err(_), InvalidUtf8Policy::Replace) => {
+                            buf_utf8.push(REPLACEMENT_CHARACTER)
+                        }
+                        (Err(_), InvalidUtf8Policy::Null) => is_valid = false,
+                        (Err(error), InvalidUtf8Policy::Error) => {
+                            return Err(MappingError::InvalidUtf16 {
+                                column: self.column_name.clone(),
+                                row,
+                                unpaired_surrogate: error.unpaired_surrogate(),
+                            })
+                        }
+                    }
+                }
+                if !is_valid {
+                    builder.append_null();
+                    continue;
                 }
                 let slice = if self.trim {
                     buf_utf8.trim()
                 } else {
                     buf_utf8.as_str()
                 };
-                Some(slice)
+                Some(slice).filter(|slice| !(self.empty_as_null && slice.is_empty()))
             } else {
                 None
             };
@@ -117,20 +282,94 @@ impl ReadStrategy for WideText {
     }
 }
 
-pub struct NarrowText {
+/// Strategy requesting the text from the database as narrow characters, assumed to be UTF-8. `O` is
+/// the offset type of the emitted string arrays.
+pub struct NarrowText<O> {
     /// Maximum string length in u8, excluding terminating zero
     max_str_len: usize,
     /// Wether the string should be trimmed.
     trim: bool,
+    /// Emit `NULL` for empty strings.
+    empty_as_null: bool,
+    /// What to do with invalid UTF-8.
+    invalid_utf8_policy: InvalidUtf8Policy,
+    /// Name of the column. Used to give context in case the driver does not return valid UTF-8.
+    column_name: String,
+    /// Only accept ASCII, rather than any UTF-8.
+    ascii: bool,
+    offset: PhantomData<O>,
 }
 
-impl NarrowText {
-    pub fn new(max_str_len: usize, trim: bool) -> Self {
-        Self { max_str_len, trim }
+impl<O> NarrowText<O> {
+    pub fn new(
+        max_str_len: usize,
+        trim: bool,
+        empty_as_null: bool,
+        invalid_utf8_policy: InvalidUtf8Policy,
+        column_name: String,
+    ) -> Self {
+        Self {
+            max_str_len,
+            trim,
+            empty_as_null,
+            invalid_utf8_policy,
+            column_name,
+            ascii: false,
+            offset: PhantomData,
+        }
+    }
+
+    /// Like [`Self::new`], but bytes outside of the ASCII range are treated as invalid.
+    pub fn ascii(
+        max_str_len: usize,
+        trim: bool,
+        empty_as_null: bool,
+        invalid_utf8_policy: InvalidUtf8Policy,
+        column_name: String,
+    ) -> Self {
+        Self {
+            ascii: true,
+            ..Self::new(
+                max_str_len,
+                trim,
+                empty_as_null,
+                invalid_utf8_policy,
+                column_name,
+            )
+        }
+    }
+
+    /// Text of the value, or the offset of the first byte which is not valid.
+    fn decode<'a>(&self, bytes: &'a [u8]) -> Result<&'a str, usize> {
+        if self.ascii {
+            if let Some(byte_offset) = bytes.iter().position(|byte| !byte.is_ascii()) {
+                return Err(byte_offset);
+            }
+        }
+        std::str::from_utf8(bytes).map_err(|utf8_error| utf8_error.valid_up_to())
+    }
+
+    /// Text of the value with every invalid sequence replaced by `U+FFFD`.
+    fn decode_lossy<'a>(&self, bytes: &'a [u8]) -> Cow<'a, str> {
+        if self.ascii {
+            let text = bytes
+                .iter()
+                .map(|&byte| {
+                    if byte.is_ascii() {
+                        byte as char
+                    } else {
+                        REPLACEMENT_CHARACTER
+                    }
+                })
+                .collect();
+            Cow::Owned(text)
+        } else {
+            String::from_utf8_lossy(bytes)
+        }
     }
 }
 
-impl ReadStrategy for NarrowText {
+impl<O: OffsetSizeTrait> ReadStrategy for NarrowText<O> {
     fn buffer_desc(&self) -> BufferDesc {
         BufferDesc::Text {
             max_str_len: self.max_str_len,
@@ -139,17 +378,34 @@ impl ReadStrategy for NarrowText {
 
     fn fill_arrow_array(&self, column_view: AnySlice) -> Result<ArrayRef, MappingError> {
         let view = column_view.as_text_view().unwrap();
-        let mut builder = StringBuilder::with_capacity(view.len(), self.max_str_len * view.len());
-        for value in view.iter() {
-            builder.append_option(value.map(|bytes| {
-                let untrimmed = std::str::from_utf8(bytes)
-                    .expect("ODBC driver had been expected to return valid utf8, but did not.");
-                if self.trim {
-                    untrimmed.trim()
-                } else {
-                    untrimmed
+        let mut builder =
+            GenericStringBuilder::<O>::with_capacity(view.len(), self.max_str_len * view.len());
+        for (row, value) in view.iter().enumerate() {
+            let Some(bytes) = value else {
+                builder.append_null();
+                continue;
+            };
+            let text = match (self.decode(bytes), self.invalid_utf8_policy) {
+                (Ok(text), _) => Cow::Borrowed(text),
+                (Err(_), InvalidUtf8Policy::Replace) => self.decode_lossy(bytes),
+                (Err(_), InvalidUtf8Policy::Null) => {
+                    builder.append_null();
+                    continue;
+                }
+                (Err(byte_offset), InvalidUtf8Policy::Error) => {
+                    return Err(MappingError::InvalidUtf8 {
+                        column: self.column_name.clone(),
+                        row,
+                        byte_offset,
+                    })
                 }
-            }));
+            };
+            let text = if self.trim { text.trim() } else { &text };
+            if self.empty_as_null && text.is_empty() {
+                builder.append_null();
+            } else {
+                builder.append_value(text);
+            }
         }
         Ok(Arc::new(builder.finish()))
     }
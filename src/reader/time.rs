@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, Time64NanosecondBuilder};
+use chrono::{NaiveTime, Timelike};
+use odbc_api::buffers::{AnySlice, BufferDesc};
+
+use super::{MappingError, OnValueError, ReadStrategy};
+
+/// `hh:mm:ss` and a fraction of up to nine digits.
+const MAX_STR_LEN: usize = 8 + 1 + 9;
+
+/// Fetches times of day as nanoseconds since midnight, like `Time64(Nanosecond)`. The ODBC time
+/// struct has no fractional seconds, so values are transmitted as text, e.g. `12:34:56.1234567`.
+pub struct Time64Nanosecond {
+    /// What to do with values which can not be parsed.
+    on_value_error: OnValueError,
+}
+
+impl Time64Nanosecond {
+    pub fn new(on_value_error: OnValueError) -> Self {
+        Self { on_value_error }
+    }
+}
+
+impl ReadStrategy for Time64Nanosecond {
+    fn buffer_desc(&self) -> BufferDesc {
+        BufferDesc::Text {
+            max_str_len: MAX_STR_LEN,
+        }
+    }
+
+    fn fill_arrow_array(&self, column_view: AnySlice) -> Result<ArrayRef, MappingError> {
+        let view = column_view.as_text_view().unwrap();
+        let mut builder = Time64NanosecondBuilder::with_capacity(view.len());
+        for (row, value) in view.iter().enumerate() {
+            let opt = if let Some(text) = value {
+                match parse_time_ns(text) {
+                    Some(ns) => Some(ns),
+                    None => {
+                        self.on_value_error.handle(
+                            row,
+                            MappingError::InvalidTime {
+                                value: String::from_utf8_lossy(text).into_owned(),
+                            },
+                        )?;
+                        None
+                    }
+                }
+            } else {
+                None
+            };
+            builder.append_option(opt);
+        }
+        Ok(Arc::new(builder.finish()))
+    }
+}
+
+/// Parses `hh:mm:ss[.fffffffff]` into nanoseconds since midnight.
+fn parse_time_ns(text: &[u8]) -> Option<i64> {
+    let text = std::str::from_utf8(text).ok()?.trim();
+    let time = NaiveTime::parse_from_str(text, "%H:%M:%S%.f").ok()?;
+    Some(i64::from(time.num_seconds_from_midnight()) * 1_000_000_000 + i64::from(time.nanosecond()))
+}
@@ -0,0 +1,199 @@
+use std::sync::Arc;
+
+use arrow::{
+    array::{
+        ArrayRef, Int16Array, StructArray, TimestampMicrosecondArray, TimestampMillisecondArray,
+        TimestampNanosecondArray, TimestampSecondArray,
+    },
+    buffer::NullBuffer,
+    datatypes::{DataType as ArrowDataType, Field, Fields, TimeUnit},
+};
+use chrono::{DateTime, FixedOffset};
+use odbc_api::{
+    buffers::{AnySlice, BufferDesc},
+    sys::SqlDataType,
+    DataType as OdbcDataType,
+};
+
+use super::{MappingError, OnValueError, ReadStrategy};
+
+/// `SQL_SS_TIMESTAMPOFFSET`, the relational type of Microsoft SQL Server `DATETIMEOFFSET` columns.
+/// Not part of `odbc_sys`.
+const SS_TIMESTAMPOFFSET: SqlDataType = SqlDataType(-155);
+
+/// `yyyy-mm-dd hh:mm:ss`, a fraction of up to nine digits and an offset of the form ` +hh:mm`.
+const MAX_STR_LEN: usize = 19 + 1 + 9 + 7;
+
+/// `true` if the relational type is a timestamp with a time zone offset, e.g. `DATETIMEOFFSET`.
+pub fn is_timestamp_with_offset(sql_type: OdbcDataType) -> bool {
+    matches!(sql_type, OdbcDataType::Other { data_type, .. } if data_type == SS_TIMESTAMPOFFSET)
+}
+
+/// Arrow type of timestamps with offset, if the offset is preserved (see
+/// [`crate::OdbcReaderBuilder::with_preserve_timestamp_offset`]). `ts` holds the local time,
+/// `offset_minutes` the offset of the local time to UTC.
+pub fn offset_struct_type() -> ArrowDataType {
+    ArrowDataType::Struct(Fields::from(vec![
+        Field::new(
+            "ts",
+            ArrowDataType::Timestamp(TimeUnit::Microsecond, None),
+            false,
+        ),
+        Field::new("offset_minutes", ArrowDataType::Int16, false),
+    ]))
+}
+
+/// Parses the text representation of a timestamp with offset, as transmitted by the driver.
+fn parse(text: &[u8]) -> Option<DateTime<FixedOffset>> {
+    let text = std::str::from_utf8(text).ok()?.trim();
+    DateTime::<FixedOffset>::parse_from_str(text, "%Y-%m-%d %H:%M:%S%.f %:z").ok()
+}
+
+/// Fetches timestamps with a time zone offset and normalizes them to UTC. Values are transmitted as
+/// text, e.g. `2021-04-09 18:57:50.0000000 +02:00`, since the buffers of `odbc-api` can not bind
+/// the driver specific `SQL_SS_TIMESTAMPOFFSET_STRUCT`.
+pub struct TimestampWithOffset {
+    unit: TimeUnit,
+    /// Time zone of the emitted arrays. Does not affect the values, which are always relative to
+    /// Unix epoch in UTC.
+    time_zone: Option<Arc<str>>,
+    /// What to do with values which can not be parsed or are out of range.
+    on_value_error: OnValueError,
+}
+
+impl TimestampWithOffset {
+    pub fn new(unit: TimeUnit, time_zone: Option<Arc<str>>, on_value_error: OnValueError) -> Self {
+        Self {
+            unit,
+            time_zone,
+            on_value_error,
+        }
+    }
+
+    /// Units since Unix epoch in UTC. `None` if the text can not be parsed or the value is out of
+    /// range for the unit.
+    fn since_epoch(&self, text: &[u8]) -> Option<i64> {
+        let date_time = parse(text)?.to_utc();
+        match self.unit {
+            TimeUnit::Second => Some(date_time.timestamp()),
+            TimeUnit::Millisecond => Some(date_time.timestamp_millis()),
+            TimeUnit::Microsecond => Some(date_time.timestamp_micros()),
+            TimeUnit::Nanosecond => date_time.timestamp_nanos_opt(),
+        }
+    }
+}
+
+impl ReadStrategy for TimestampWithOffset {
+    fn buffer_desc(&self) -> BufferDesc {
+        BufferDesc::Text {
+            max_str_len: MAX_STR_LEN,
+        }
+    }
+
+    fn fill_arrow_array(&self, column_view: AnySlice) -> Result<ArrayRef, MappingError> {
+        let view = column_view.as_text_view().unwrap();
+        let mut values = Vec::with_capacity(view.len());
+        for (row, value) in view.iter().enumerate() {
+            let opt = if let Some(text) = value {
+                match self.since_epoch(text) {
+                    Some(epoch) => Some(epoch),
+                    None => {
+                        self.on_value_error.handle(
+                            row,
+                            MappingError::InvalidTimestampWithOffset {
+                                value: String::from_utf8_lossy(text).into_owned(),
+                            },
+                        )?;
+                        None
+                    }
+                }
+            } else {
+                None
+            };
+            values.push(opt);
+        }
+        let time_zone = self.time_zone.clone();
+        let array: ArrayRef = match self.unit {
+            TimeUnit::Second => {
+                Arc::new(TimestampSecondArray::from(values).with_timezone_opt(time_zone))
+            }
+            TimeUnit::Millisecond => {
+                Arc::new(TimestampMillisecondArray::from(values).with_timezone_opt(time_zone))
+            }
+            TimeUnit::Microsecond => {
+                Arc::new(TimestampMicrosecondArray::from(values).with_timezone_opt(time_zone))
+            }
+            TimeUnit::Nanosecond => {
+                Arc::new(TimestampNanosecondArray::from(values).with_timezone_opt(time_zone))
+            }
+        };
+        Ok(array)
+    }
+}
+
+/// Fetches timestamps with a time zone offset into a struct of the local time and the offset, see
+/// [`offset_struct_type`]. Other than [`TimestampWithOffset`] this preserves the original offset.
+pub struct TimestampWithOffsetAsStruct {
+    /// What to do with values which can not be parsed or are out of range.
+    on_value_error: OnValueError,
+}
+
+impl TimestampWithOffsetAsStruct {
+    pub fn new(on_value_error: OnValueError) -> Self {
+        Self { on_value_error }
+    }
+}
+
+impl ReadStrategy for TimestampWithOffsetAsStruct {
+    fn buffer_desc(&self) -> BufferDesc {
+        BufferDesc::Text {
+            max_str_len: MAX_STR_LEN,
+        }
+    }
+
+    fn fill_arrow_array(&self, column_view: AnySlice) -> Result<ArrayRef, MappingError> {
+        let view = column_view.as_text_view().unwrap();
+        let mut local_times = Vec::with_capacity(view.len());
+        let mut offsets = Vec::with_capacity(view.len());
+        let mut validity = Vec::with_capacity(view.len());
+        for (row, value) in view.iter().enumerate() {
+            let parsed = if let Some(text) = value {
+                match parse(text) {
+                    Some(date_time) => Some(date_time),
+                    None => {
+                        self.on_value_error.handle(
+                            row,
+                            MappingError::InvalidTimestampWithOffset {
+                                value: String::from_utf8_lossy(text).into_owned(),
+                            },
+                        )?;
+                        None
+                    }
+                }
+            } else {
+                None
+            };
+            // Children must hold a value for each row, even if the struct itself is `NULL`.
+            let (local_time, offset) = parsed.map_or((0, 0), |date_time| {
+                let local_time = date_time.naive_local().and_utc().timestamp_micros();
+                let offset = date_time.offset().local_minus_utc() / 60;
+                (local_time, offset.try_into().unwrap())
+            });
+            local_times.push(local_time);
+            offsets.push(offset);
+            validity.push(parsed.is_some());
+        }
+        let ArrowDataType::Struct(fields) = offset_struct_type() else {
+            unreachable!("Timestamp with offset is always fetched into a struct")
+        };
+        let array = StructArray::new(
+            fields,
+            vec![
+                Arc::new(TimestampMicrosecondArray::from(local_times)),
+                Arc::new(Int16Array::from(offsets)),
+            ],
+            Some(NullBuffer::from(validity)),
+        );
+        Ok(Arc::new(array))
+    }
+}
@@ -1,71 +1,164 @@
-use std::sync::Arc;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+    time::Duration,
+};
 
 use arrow::{
-    datatypes::{Schema, SchemaRef},
+    array::ArrayRef,
+    datatypes::{DataType as ArrowDataType, Field, FieldRef, Schema, SchemaRef, TimeUnit},
+    error::ArrowError,
     record_batch::RecordBatch,
 };
-use log::info;
-use odbc_api::{buffers::ColumnarAnyBuffer, ResultSetMetadata};
+use log::{info, warn};
+use odbc_api::{
+    buffers::{AnyBuffer, BufferDesc, ColumnarAnyBuffer, ColumnarBuffer},
+    handles::{AsStatementRef, Statement},
+    sys::SqlDataType,
+    DataType as OdbcDataType, ResultSetMetadata,
+};
 
-use crate::{arrow_schema_from, BufferAllocationOptions, ColumnFailure, Error};
+use crate::{
+    schema::{arrow_field_from, is_falliable},
+    ColumnFailure, Error, InvalidUtf8Policy,
+};
 
-use super::{choose_column_strategy, MappingError, ReadStrategy};
+use super::{
+    choose_column_strategy, is_timestamp_with_offset, long_data::LongText,
+    odbc_reader::odbc_to_arrow_error, timestamp_offset::offset_struct_type, BufferPool,
+    ColumnNameTransform, MappingError, ReadOptions, ReadStrategy,
+};
 
 /// Transforms batches fetched from an ODBC data source in a
 /// [`odbc_api::bufferers::ColumnarAnyBuffer`] into arrow tables of the specified schemas. It also
 /// allocates the buffers to hold the ODBC batches with the matching buffer descriptions.
 pub struct ToRecordBatch {
-    /// Must contain one item for each field in [`Self::schema`]. Encapsulates all the column type
-    /// specific decisions which go into filling an Arrow array from an ODBC data source.
+    /// Must contain one item for each field in [`Self::schema`], which is bound to the transit
+    /// buffer. Encapsulates all the column type specific decisions which go into filling an Arrow
+    /// array from an ODBC data source.
     column_strategies: Vec<Box<dyn ReadStrategy + Send>>,
     /// Arrow schema describing the arrays we want to fill from the Odbc data source.
     schema: SchemaRef,
+    /// One based index of the column in the result set for each bound column. Columns which could
+    /// not be described, or which are streamed, are skipped, so these are not necessarily
+    /// contiguous.
+    column_indices: Vec<u16>,
+    /// Index of the field in [`Self::schema`] for each bound column.
+    bound_fields: Vec<usize>,
+    /// Columns which are not bound to the transit buffer, but streamed using `SQLGetData`.
+    /// Together with the index of their field in [`Self::schema`], in ascending order.
+    long_data: Vec<(usize, LongText)>,
 }
 
 impl ToRecordBatch {
     pub fn new(
         cursor: &mut impl ResultSetMetadata,
         schema: Option<SchemaRef>,
-        buffer_allocation_options: BufferAllocationOptions,
-        map_value_errors_to_null: bool,
-        trim_fixed_sized_character_strings: bool,
+        options: &ReadOptions,
+    ) -> Result<Self, Error> {
+        if let Some(timeout) = options.query_timeout {
+            set_query_timeout(cursor, timeout)?;
+        }
+        // Timeout of the statement to restore once the metadata has been queried. `Some` only if
+        // the metadata timeout has been set.
+        let previous_timeout = options.metadata_query_timeout.and_then(|timeout| {
+            let previous = query_timeout(cursor)?;
+            try_set_query_timeout(cursor, timeout).then_some(previous)
+        });
+        let inference_timeout = if previous_timeout.is_some() {
+            options.metadata_query_timeout
+        } else {
+            options.query_timeout
+        };
+        let result = Self::from_metadata(cursor, schema, options, inference_timeout);
+        // Restore the timeout even if querying the metadata failed, since the cursor is only
+        // borrowed by e.g. `OdbcReaderBuilder::infer_schema` and may be used again by the caller.
+        if let Some(previous) = previous_timeout {
+            try_set_query_timeout(cursor, previous);
+        }
+        result
+    }
+
+    /// Infers the schema, unless given, and chooses a read strategy for each of its fields.
+    /// `inference_timeout` is the query timeout reported, should inferring the schema time out.
+    fn from_metadata(
+        cursor: &mut impl ResultSetMetadata,
+        schema: Option<SchemaRef>,
+        options: &ReadOptions,
+        inference_timeout: Option<Duration>,
     ) -> Result<Self, Error> {
         // Infer schema if not given by the user
-        let schema = if let Some(schema) = schema {
+        let (schema, column_indices) = if let Some(schema) = schema {
+            let column_indices = (1..=schema.fields().len())
+                .map(|col_index| col_index.try_into().unwrap())
+                .collect();
+            (schema, column_indices)
+        } else {
+            infer_schema(cursor, options).map_err(|error| match inference_timeout {
+                Some(timeout) => error.into_inference_timeout(timeout),
+                None => error,
+            })?
+        };
+        let schema = if options.schema_metadata.is_empty() {
             schema
         } else {
-            Arc::new(arrow_schema_from(cursor, map_value_errors_to_null)?)
+            let mut metadata = schema.metadata().clone();
+            metadata.extend(options.schema_metadata.clone());
+            Arc::new(schema.as_ref().clone().with_metadata(metadata))
         };
 
-        let column_strategies: Vec<Box<dyn ReadStrategy + Send>> = schema
-            .fields()
-            .iter()
-            .enumerate()
-            .map(|(index, field)| {
-                let col_index = (index + 1).try_into().unwrap();
-                choose_column_strategy(
-                    field,
-                    cursor,
+        let mut column_strategies: Vec<Box<dyn ReadStrategy + Send>> = Vec::new();
+        let mut bound_indices = Vec::new();
+        let mut bound_fields = Vec::new();
+        let mut long_data = Vec::new();
+        for (index, (field, &col_index)) in schema.fields().iter().zip(&column_indices).enumerate()
+        {
+            if options.is_long_data_column(field.name())
+                && field.data_type() == &ArrowDataType::Utf8
+            {
+                let long_text = LongText::new(
+                    field.name().clone(),
                     col_index,
-                    buffer_allocation_options,
-                    map_value_errors_to_null,
-                    trim_fixed_sized_character_strings,
-                )
-                .map_err(|cause| cause.into_crate_error(field.name().clone(), index))
-            })
-            .collect::<Result<_, _>>()?;
+                    options.invalid_utf8_policy,
+                    options.trim_all_strings,
+                    options.empty_string_as_null,
+                );
+                long_data.push((index, long_text));
+                continue;
+            }
+            let strategy = choose_column_strategy(field, cursor, col_index, options)
+                .map_err(|cause| cause.into_crate_error(field.name().clone(), index))?;
+            column_strategies.push(strategy);
+            bound_indices.push(col_index);
+            bound_fields.push(index);
+        }
 
         Ok(ToRecordBatch {
             column_strategies,
             schema,
+            column_indices: bound_indices,
+            bound_fields,
+            long_data,
         })
     }
 
+    /// A converter without any columns. Used as a placeholder then moving the actual converter out
+    /// of a reader.
+    pub fn empty() -> Self {
+        ToRecordBatch {
+            column_strategies: Vec::new(),
+            schema: Arc::new(Schema::empty()),
+            column_indices: Vec::new(),
+            bound_fields: Vec::new(),
+            long_data: Vec::new(),
+        }
+    }
+
     /// Logs buffer description and sizes
     pub fn row_size_in_bytes(&self) -> usize {
         let mut total_bytes = 0;
-        for (read, field) in self.column_strategies.iter().zip(self.schema.fields()) {
-            let name = field.name();
+        for (read, &field_index) in self.column_strategies.iter().zip(&self.bound_fields) {
+            let name = self.schema.field(field_index).name();
             let desc = read.buffer_desc();
             let bytes_per_row = desc.bytes_per_row();
             info!("Column '{name}'\nBytes used per row: {bytes_per_row}");
@@ -75,60 +168,446 @@ impl ToRecordBatch {
         total_bytes
     }
 
+    /// Buffer descriptions of the transit buffer, one for each column.
+    pub fn buffer_descs(&self) -> Vec<BufferDesc> {
+        self.column_strategies
+            .iter()
+            .map(|cs| cs.buffer_desc())
+            .collect()
+    }
+
+    /// Takes a matching buffer from the pool, if one is available or allocates a new one
+    /// otherwise.
     pub fn allocate_buffer(
         &self,
         max_batch_size: usize,
         fallibale_allocations: bool,
+        buffer_pool: Option<&BufferPool>,
     ) -> Result<ColumnarAnyBuffer, Error> {
-        let descs = self.column_strategies.iter().map(|cs| cs.buffer_desc());
+        let descs = self.buffer_descs();
+        if !self.binds_contiguous_columns() {
+            return self.allocate_buffer_with_gaps(max_batch_size, fallibale_allocations, descs);
+        }
+        if let Some(buffer) = buffer_pool.and_then(|pool| pool.check_out(max_batch_size, &descs)) {
+            return Ok(buffer);
+        }
 
         let row_set_buffer = if fallibale_allocations {
             ColumnarAnyBuffer::try_from_descs(max_batch_size, descs)
-                .map_err(|err| map_allocation_error(err, &self.schema))?
+                .map_err(|err| self.map_allocation_error(err))?
         } else {
             ColumnarAnyBuffer::from_descs(max_batch_size, descs)
         };
         Ok(row_set_buffer)
     }
 
+    /// Allocates a buffer binding only the columns in [`Self::column_indices`].
+    fn allocate_buffer_with_gaps(
+        &self,
+        max_batch_size: usize,
+        fallibale_allocations: bool,
+        descs: Vec<BufferDesc>,
+    ) -> Result<ColumnarAnyBuffer, Error> {
+        let columns = self
+            .column_indices
+            .iter()
+            .zip(descs)
+            .enumerate()
+            .map(|(buffer_index, (&col_index, desc))| {
+                let buffer = if fallibale_allocations {
+                    AnyBuffer::try_from_desc(max_batch_size, desc).map_err(|error| {
+                        let error = error.add_context(buffer_index.try_into().unwrap());
+                        self.map_allocation_error(error)
+                    })?
+                } else {
+                    AnyBuffer::from_desc(max_batch_size, desc)
+                };
+                Ok((col_index, buffer))
+            })
+            .collect::<Result<_, Error>>()?;
+        Ok(ColumnarBuffer::new(columns))
+    }
+
+    /// `true` if the buffers bind the columns of the result set, starting with the first one,
+    /// without skipping any.
+    fn binds_contiguous_columns(&self) -> bool {
+        self.column_indices
+            .iter()
+            .enumerate()
+            .all(|(index, &col_index)| usize::from(col_index) == index + 1)
+    }
+
+    /// Returns a buffer allocated with [`Self::allocate_buffer`] to the pool, if any. Buffers which
+    /// skip columns are not pooled, since the pool matches buffers only by their descriptions.
+    pub fn recycle_buffer(&self, buffer: ColumnarAnyBuffer, buffer_pool: Option<&BufferPool>) {
+        if let Some(pool) = buffer_pool {
+            if self.binds_contiguous_columns() {
+                pool.check_in(self.buffer_descs(), buffer)
+            }
+        }
+    }
+
     pub fn schema(&self) -> &SchemaRef {
         &self.schema
     }
 
+    /// Converts the transit buffer into a record batch. Must not be called if
+    /// [`Self::has_long_data`] is `true`. Use [`Self::bound_arrays`] and
+    /// [`Self::assemble_record_batch`] instead.
     pub fn buffer_to_record_batch(
         &self,
         odbc_buffer: &ColumnarAnyBuffer,
     ) -> Result<RecordBatch, MappingError> {
-        let arrow_columns = self
-            .column_strategies
+        let arrow_columns = self.bound_arrays(odbc_buffer)?;
+        Ok(self.assemble_record_batch(arrow_columns, Vec::new()))
+    }
+
+    /// `true` if some columns are streamed using `SQLGetData`, rather than bound to the transit
+    /// buffer.
+    pub fn has_long_data(&self) -> bool {
+        !self.long_data.is_empty()
+    }
+
+    /// One array for each column bound to the transit buffer.
+    pub fn bound_arrays(
+        &self,
+        odbc_buffer: &ColumnarAnyBuffer,
+    ) -> Result<Vec<ArrayRef>, MappingError> {
+        self.column_strategies
             .iter()
             .enumerate()
             .map(|(index, strat)| {
                 let column_view = odbc_buffer.column(index);
                 strat.fill_arrow_array(column_view)
             })
-            .collect::<Result<Vec<_>, _>>()?;
-        let record_batch = RecordBatch::try_new(self.schema.clone(), arrow_columns).unwrap();
-        Ok(record_batch)
+            .collect()
     }
-}
 
-fn map_allocation_error(error: odbc_api::Error, schema: &Schema) -> Error {
-    match error {
-        odbc_api::Error::TooLargeColumnBufferSize {
-            buffer_index,
-            num_elements,
-            element_size,
-        } => Error::ColumnFailure {
-            name: schema.field(buffer_index as usize).name().clone(),
-            index: buffer_index as usize,
-            source: ColumnFailure::TooLarge {
+    /// One array for each streamed column, holding the value of the current row of the cursor.
+    /// The transit buffer must be unbound from the cursor. `row` is the index of the current row
+    /// within the emitted batch.
+    pub fn fetch_long_data(
+        &self,
+        cursor: &mut impl AsStatementRef,
+        row: usize,
+    ) -> Result<Vec<ArrayRef>, ArrowError> {
+        let mut buf = Vec::new();
+        self.long_data
+            .iter()
+            .map(|(_, long_text)| {
+                let is_not_null = long_text
+                    .fetch(cursor, &mut buf)
+                    .map_err(odbc_to_arrow_error)?;
+                long_text
+                    .to_array(is_not_null.then_some(&buf), row)
+                    .map_err(|mapping_error| ArrowError::ExternalError(Box::new(mapping_error)))
+            })
+            .collect()
+    }
+
+    /// Combines the arrays of the bound columns with those of the streamed columns into a record
+    /// batch, in the order of the fields in [`Self::schema`].
+    pub fn assemble_record_batch(
+        &self,
+        bound: Vec<ArrayRef>,
+        long_data: Vec<ArrayRef>,
+    ) -> RecordBatch {
+        let mut long_data = self
+            .long_data
+            .iter()
+            .map(|&(field_index, _)| field_index)
+            .zip(long_data)
+            .peekable();
+        let mut bound = bound.into_iter();
+        let arrow_columns = (0..self.schema.fields().len())
+            .map(|field_index| {
+                if long_data
+                    .peek()
+                    .is_some_and(|&(index, _)| index == field_index)
+                {
+                    long_data.next().unwrap().1
+                } else {
+                    bound.next().unwrap()
+                }
+            })
+            .collect();
+        RecordBatch::try_new(self.schema.clone(), arrow_columns).unwrap()
+    }
+
+    fn map_allocation_error(&self, error: odbc_api::Error) -> Error {
+        match error {
+            odbc_api::Error::TooLargeColumnBufferSize {
+                buffer_index,
                 num_elements,
                 element_size,
-            },
-        },
-        _ => {
-            panic!("Unexpected error in upstream ODBC api error library")
+            } => {
+                let index = self.bound_fields[buffer_index as usize];
+                Error::ColumnFailure {
+                    name: self.schema.field(index).name().clone(),
+                    index,
+                    source: ColumnFailure::TooLarge {
+                        num_elements,
+                        element_size,
+                    },
+                }
+            }
+            _ => {
+                panic!("Unexpected error in upstream ODBC api error library")
+            }
+        }
+    }
+}
+
+/// Sets the query timeout of the statement. ODBC specifies the timeout in whole seconds, so the
+/// duration is rounded up. Otherwise a sub second timeout would be interpreted as no timeout at
+/// all.
+fn set_query_timeout(cursor: &mut impl AsStatementRef, timeout: Duration) -> Result<(), Error> {
+    let timeout_sec = timeout.as_secs() + u64::from(timeout.subsec_nanos() != 0);
+    let mut stmt = cursor.as_stmt_ref();
+    stmt.set_query_timeout_sec(timeout_sec.try_into().unwrap())
+        .into_result(&stmt)
+        .map_err(Error::UnableToSetQueryTimeout)
+}
+
+/// Query timeout currently set on the statement. `None` if the driver can not report it, in which
+/// case a warning is logged.
+fn query_timeout(cursor: &mut impl AsStatementRef) -> Option<Duration> {
+    let mut stmt = cursor.as_stmt_ref();
+    match stmt.query_timeout_sec().into_result(&stmt) {
+        Ok(timeout_sec) => Some(Duration::from_secs(timeout_sec.try_into().unwrap())),
+        Err(error) => {
+            warn!("Unable to query the timeout of the statement: {error}");
+            None
         }
     }
 }
+
+/// Like [`set_query_timeout`], but logs a warning rather than failing, if the driver does not
+/// support query timeouts. `true` if the timeout has been set. A zero duration disables the
+/// timeout.
+fn try_set_query_timeout(cursor: &mut impl AsStatementRef, timeout: Duration) -> bool {
+    match set_query_timeout(cursor, timeout) {
+        Ok(()) => true,
+        Err(error) => {
+            warn!("Unable to set the query timeout of the statement: {error}");
+            false
+        }
+    }
+}
+
+/// Queries the metadata of the result set to infer an arrow schema, which respects the options of
+/// the user. Also returns the one based column index of each field.
+fn infer_schema(
+    cursor: &mut impl ResultSetMetadata,
+    options: &ReadOptions,
+) -> Result<(SchemaRef, Vec<u16>), Error> {
+    let num_cols: u16 = cursor
+        .num_result_cols()
+        .map_err(Error::UnableToRetrieveNumCols)?
+        .try_into()
+        .unwrap();
+    let mut fields = Vec::new();
+    let mut sql_types = Vec::new();
+    let mut column_indices = Vec::new();
+    // Nullability due to value errors depends on the final type of the field, so it is decided
+    // after applying the options of the user.
+    for index in 0..num_cols {
+        match arrow_field_from(cursor, index, false) {
+            Ok((field, sql_type)) => {
+                fields.push(Arc::new(field));
+                sql_types.push(sql_type);
+                column_indices.push(index + 1);
+            }
+            Err(Error::ColumnFailure {
+                source: ColumnFailure::FailedToDescribeColumn(cause),
+                ..
+            }) if options.skip_undescribable_columns => {
+                warn!("Skipping column {index}, since it can not be described: {cause}");
+            }
+            Err(error) => return Err(error),
+        }
+    }
+    let fields = if let Some(ColumnNameTransform(transform)) = &options.column_name_transform {
+        fields
+            .into_iter()
+            .map(|field| Arc::new(field.as_ref().clone().with_name(transform(field.name()))))
+            .collect()
+    } else {
+        fields
+    };
+    // Deduplicate first, so options referring to columns by name see the final names.
+    let fields = if options.dedup_column_names {
+        dedup_column_names(fields)
+    } else {
+        fields
+    };
+    let fields: Vec<_> = fields
+        .iter()
+        .zip(&sql_types)
+        .map(|(field, &sql_type)| adapt_inferred_field(field, sql_type, options))
+        .collect();
+    let fields = if options.all_columns_nullable {
+        fields
+            .into_iter()
+            .map(|field| Arc::new(field.as_ref().clone().with_nullable(true)))
+            .collect()
+    } else {
+        fields
+    };
+    Ok((Arc::new(Schema::new(fields)), column_indices))
+}
+
+/// Renames fields whose name is already taken by a field to their left, by appending the smallest
+/// suffix `_1`, `_2`, ... which yields a name not used by any other field.
+fn dedup_column_names(fields: Vec<FieldRef>) -> Vec<FieldRef> {
+    let original: HashSet<String> = fields.iter().map(|field| field.name().clone()).collect();
+    let mut taken = HashSet::new();
+    fields
+        .into_iter()
+        .map(|field| {
+            if taken.insert(field.name().clone()) {
+                return field;
+            }
+            let name = (1..)
+                .map(|suffix| format!("{}_{suffix}", field.name()))
+                .find(|name| !original.contains(name) && !taken.contains(name))
+                .unwrap();
+            taken.insert(name.clone());
+            Arc::new(field.as_ref().clone().with_name(name))
+        })
+        .collect()
+}
+
+/// Applies the options of the user to a field inferred from the metadata of the result set. The
+/// type is chosen first, the nullability is decided for the final type.
+fn adapt_inferred_field(
+    field: &FieldRef,
+    sql_type: OdbcDataType,
+    options: &ReadOptions,
+) -> FieldRef {
+    let adapted = adapt_inferred_type(field, sql_type, options);
+    // Columns are free of nulls, if nulls are replaced with sentinels.
+    let nullable = if options
+        .null_fill
+        .is_some_and(|null_fill| null_fill.applies_to(adapted.data_type()))
+    {
+        false
+    } else {
+        field.is_nullable() || may_replace_values_with_null(&adapted, sql_type, options)
+    };
+    Arc::new(adapted.with_nullable(nullable))
+}
+
+/// `true` if values of the column may be fetched as `NULL` into the field, even if the column in
+/// the data source is not nullable.
+fn may_replace_values_with_null(
+    field: &Field,
+    sql_type: OdbcDataType,
+    options: &ReadOptions,
+) -> bool {
+    // Text which is not correctly encoded, or empty text, is replaced with `NULL`.
+    let replaces_text =
+        options.invalid_utf8_policy == InvalidUtf8Policy::Null || options.empty_string_as_null;
+    let is_falliable = is_falliable(field.data_type(), sql_type)
+        || (field.data_type() == &ArrowDataType::Boolean
+            && options.bool_encoding(field.name()).is_some());
+    (replaces_text && is_fetched_as_text(field.data_type()))
+        || (is_falliable && options.value_errors_may_be_null())
+}
+
+/// `true` if columns of the type are fetched using the text strategies, e.g. lists of labels or
+/// dictionary encoded text.
+fn is_fetched_as_text(data_type: &ArrowDataType) -> bool {
+    match data_type {
+        ArrowDataType::Utf8 | ArrowDataType::LargeUtf8 => true,
+        ArrowDataType::List(item) => item.data_type() == &ArrowDataType::Utf8,
+        ArrowDataType::Dictionary(_, value) => value.as_ref() == &ArrowDataType::Utf8,
+        _ => false,
+    }
+}
+
+/// Chooses the type of a field inferred from the metadata of the result set, according to the
+/// options of the user. The nullability of the returned field is not adapted.
+fn adapt_inferred_type(field: &FieldRef, sql_type: OdbcDataType, options: &ReadOptions) -> Field {
+    let with_type = |data_type| Field::new(field.name(), data_type, field.is_nullable());
+    // Columns explicitly declared as text, or to be streamed, are fetched as text, independent of
+    // their relational type.
+    if options.is_text_column(field.name()) || options.is_long_data_column(field.name()) {
+        return with_type(ArrowDataType::Utf8);
+    }
+    // Types chosen explicitly for a column take precedence over the remaining options.
+    if let Some(data_type) = options.column_override(field.name()) {
+        return with_type(data_type.clone());
+    }
+    // Columns explicitly declared as booleans are mapped to arrow booleans, independent of the
+    // relational type reported by the driver.
+    if options.bool_encoding(field.name()).is_some() {
+        return with_type(ArrowDataType::Boolean);
+    }
+    // Text columns explicitly declared as binary are fetched as raw bytes, without decoding.
+    if options.is_binary_column(field.name())
+        && matches!(
+            field.data_type(),
+            ArrowDataType::Utf8 | ArrowDataType::LargeUtf8
+        )
+    {
+        return with_type(ArrowDataType::Binary);
+    }
+    // Text columns explicitly declared as dictionary encoded.
+    if options.is_dictionary_column(field.name()) && field.data_type() == &ArrowDataType::Utf8 {
+        return with_type(ArrowDataType::Dictionary(
+            Box::new(ArrowDataType::Int32),
+            Box::new(ArrowDataType::Utf8),
+        ));
+    }
+    if options.decimals_as_text
+        && matches!(
+            field.data_type(),
+            ArrowDataType::Decimal128(..) | ArrowDataType::Decimal256(..)
+        )
+    {
+        return with_type(ArrowDataType::Utf8);
+    }
+    if options.preserve_timestamp_offset && is_timestamp_with_offset(sql_type) {
+        return with_type(offset_struct_type());
+    }
+    if let (Some(unit), ArrowDataType::Timestamp(_, time_zone)) =
+        (options.timestamp_unit, field.data_type())
+    {
+        return with_type(ArrowDataType::Timestamp(unit, time_zone.clone()));
+    }
+    if options.safe_timestamp_range {
+        if let ArrowDataType::Timestamp(TimeUnit::Nanosecond, time_zone) = field.data_type() {
+            return with_type(ArrowDataType::Timestamp(
+                TimeUnit::Microsecond,
+                time_zone.clone(),
+            ));
+        }
+    }
+    if options.is_mssql() && is_mssql_udt(sql_type) {
+        return spatial_field(field);
+    }
+    field.as_ref().clone()
+}
+
+/// Type code SQL Server uses for user defined CLR types, like `GEOGRAPHY`, `GEOMETRY` or
+/// `HIERARCHYID`.
+const SQL_SS_UDT: i16 = -151;
+
+/// Metadata key attached to fields holding spatial data.
+const SPATIAL_METADATA_KEY: &str = "arrow.spatial";
+
+/// `true` if the relational type is a user defined type of SQL Server.
+fn is_mssql_udt(sql_type: OdbcDataType) -> bool {
+    matches!(
+        sql_type,
+        OdbcDataType::Other { data_type, .. } if data_type == SqlDataType(SQL_SS_UDT)
+    )
+}
+
+/// SQL Server spatial values are fetched as binary, rather than as a hex string. The bytes are in
+/// the native serialization format of SQL Server, which is recorded in the field metadata.
+fn spatial_field(field: &Field) -> Field {
+    let metadata = HashMap::from([(SPATIAL_METADATA_KEY.to_owned(), "mssql-clr".to_owned())]);
+    Field::new(field.name(), ArrowDataType::Binary, field.is_nullable()).with_metadata(metadata)
+}
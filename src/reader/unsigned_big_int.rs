@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, UInt64Builder};
+use odbc_api::buffers::{AnySlice, BufferDesc};
+
+use super::{MappingError, OnValueError, ReadStrategy};
+
+/// Number of digits of `u64::MAX`.
+const MAX_STR_LEN: usize = 20;
+
+/// Fetches unsigned 64 Bit integers, e.g. MySQL `BIGINT UNSIGNED`. Values are transmitted as text,
+/// since the buffers of `odbc-api` can not bind `SQL_C_UBIGINT` and values above `i64::MAX` do not
+/// fit into a signed 64 Bit integer.
+pub struct UInt64FromText {
+    /// What to do with values which can not be parsed.
+    on_value_error: OnValueError,
+}
+
+impl UInt64FromText {
+    pub fn new(on_value_error: OnValueError) -> Self {
+        Self { on_value_error }
+    }
+}
+
+impl ReadStrategy for UInt64FromText {
+    fn buffer_desc(&self) -> BufferDesc {
+        BufferDesc::Text {
+            max_str_len: MAX_STR_LEN,
+        }
+    }
+
+    fn fill_arrow_array(&self, column_view: AnySlice) -> Result<ArrayRef, MappingError> {
+        let view = column_view.as_text_view().unwrap();
+        let mut builder = UInt64Builder::with_capacity(view.len());
+        for (row, value) in view.iter().enumerate() {
+            let opt = if let Some(text) = value {
+                match parse_u64(text) {
+                    Some(value) => Some(value),
+                    None => {
+                        self.on_value_error.handle(
+                            row,
+                            MappingError::InvalidUnsignedInteger {
+                                value: String::from_utf8_lossy(text).into_owned(),
+                            },
+                        )?;
+                        None
+                    }
+                }
+            } else {
+                None
+            };
+            builder.append_option(opt);
+        }
+        Ok(Arc::new(builder.finish()))
+    }
+}
+
+fn parse_u64(text: &[u8]) -> Option<u64> {
+    std::str::from_utf8(text).ok()?.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_u64;
+
+    #[test]
+    fn parse_values_beyond_range_of_signed_integers() {
+        assert_eq!(Some(0), parse_u64(b"0"));
+        assert_eq!(
+            Some(9_223_372_036_854_775_808),
+            parse_u64(b"9223372036854775808")
+        );
+        assert_eq!(Some(u64::MAX), parse_u64(b"18446744073709551615"));
+    }
+
+    #[test]
+    fn parse_ignores_surrounding_whitespace() {
+        assert_eq!(Some(42), parse_u64(b" 42 "));
+    }
+
+    #[test]
+    fn parse_rejects_invalid_values() {
+        assert_eq!(None, parse_u64(b"-1"));
+        assert_eq!(None, parse_u64(b"18446744073709551616"));
+        assert_eq!(None, parse_u64(b"4.2"));
+        assert_eq!(None, parse_u64(b""));
+    }
+}
@@ -0,0 +1,67 @@
+use std::{
+    fmt,
+    sync::{Arc, Mutex},
+};
+
+use super::MappingError;
+
+/// Decision of a handler passed to [`crate::OdbcReaderBuilder::with_mapping_error_handler`] about
+/// a value which can not be mapped to its arrow type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorAction {
+    /// Emit `NULL` for the value and continue.
+    SkipToNull,
+    /// Emit the [`MappingError`], ending the batch.
+    Abort,
+}
+
+/// Handler deciding for each value which can not be mapped, whether it is replaced with `NULL`.
+/// Invoked with the zero based index of the column in the result set, the index of the row within
+/// the batch and the error. See [`crate::OdbcReaderBuilder::with_mapping_error_handler`].
+#[derive(Clone)]
+pub struct MappingErrorHandler(pub Arc<Mutex<HandlerFn>>);
+
+type HandlerFn = dyn FnMut(usize, usize, &MappingError) -> ErrorAction + Send;
+
+impl fmt::Debug for MappingErrorHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("MappingErrorHandler")
+    }
+}
+
+/// What a read strategy does with values which can not be mapped to the arrow type.
+#[derive(Clone, Debug)]
+pub enum OnValueError {
+    /// Emit the [`MappingError`].
+    Abort,
+    /// Emit `NULL`, see [`crate::OdbcReaderBuilder::value_errors_as_null`].
+    Null,
+    /// Let the handler of the user decide for each value.
+    Handler {
+        handler: MappingErrorHandler,
+        /// Zero based index of the column in the result set.
+        column: usize,
+    },
+}
+
+impl OnValueError {
+    /// `true` if values may be replaced with `NULL`. In this case strategies produce nullable
+    /// arrays, even if the column is not nullable.
+    pub fn may_emit_null(&self) -> bool {
+        !matches!(self, OnValueError::Abort)
+    }
+
+    /// `Ok` if the value in `row` is to be replaced with `NULL`, or the error otherwise.
+    pub fn handle(&self, row: usize, error: MappingError) -> Result<(), MappingError> {
+        match self {
+            OnValueError::Abort => Err(error),
+            OnValueError::Null => Ok(()),
+            OnValueError::Handler { handler, column } => {
+                match (handler.0.lock().unwrap())(*column, row, &error) {
+                    ErrorAction::SkipToNull => Ok(()),
+                    ErrorAction::Abort => Err(error),
+                }
+            }
+        }
+    }
+}
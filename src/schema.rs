@@ -1,9 +1,14 @@
 use arrow::datatypes::{DataType as ArrowDataType, Field, Schema, TimeUnit};
 use log::debug;
-use odbc_api::{ColumnDescription, DataType as OdbcDataType, ResultSetMetadata};
+use odbc_api::{sys::SqlDataType, ColumnDescription, DataType as OdbcDataType, ResultSetMetadata};
 use std::convert::TryInto;
 
-use crate::{ColumnFailure, Error};
+use crate::{
+    reader::{
+        choose_column_strategy, is_interval_day_to_second, is_timestamp_with_offset, ReadOptions,
+    },
+    ColumnFailure, Error,
+};
 
 /// Query the metadata to create an arrow schema. This method is invoked automatically for you by
 /// [`crate::OdbcReaderBuilder::build`]. You may want to call this method in situtation ther you want
@@ -41,29 +46,191 @@ pub fn arrow_schema_from(
         .unwrap();
     let mut fields = Vec::new();
     for index in 0..num_cols {
-        let field = arrow_field_from(resut_set_metadata, index, map_value_errors_to_null)?;
+        let (field, _sql_type) =
+            arrow_field_from(resut_set_metadata, index, map_value_errors_to_null)?;
 
         fields.push(field)
     }
     Ok(Schema::new(fields))
 }
 
-fn arrow_field_from(
+/// Checks whether the columns of a result set can be fetched into the arrow fields of `schema`,
+/// without binding any buffers. See [`crate::OdbcReaderBuilder::validate_schema`].
+pub(crate) fn validate_schema(
+    schema: &Schema,
+    resut_set_metadata: &mut impl ResultSetMetadata,
+    options: &ReadOptions,
+) -> Result<(), Error> {
+    let num_columns: usize = resut_set_metadata
+        .num_result_cols()
+        .map_err(Error::UnableToRetrieveNumCols)?
+        .try_into()
+        .unwrap();
+    let num_fields = schema.fields().len();
+    if num_fields != num_columns {
+        return Err(Error::SchemaColumnCountMismatch {
+            num_fields,
+            num_columns,
+        });
+    }
+    for (index, field) in schema.fields().iter().enumerate() {
+        let col_index = (index + 1).try_into().unwrap();
+        choose_column_strategy(field, resut_set_metadata, col_index, options)
+            .map_err(|cause| cause.into_crate_error(field.name().clone(), index))?;
+    }
+    Ok(())
+}
+
+/// How faithfully values of a relational type are represented by the arrow type they are mapped
+/// to. See [`describe_mapping`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fidelity {
+    /// Every value is represented exactly by the arrow type.
+    Lossless,
+    /// There is no matching arrow type, so the values are fetched as text. The value is preserved
+    /// in its textual representation, its type is not. E.g. `DECIMAL(80,2)` or `TIME`.
+    Text,
+    /// Values outside of a range can not be represented. Timestamps with more than six fractional
+    /// digits are mapped to nanoseconds, which limits them to years between 1677 and 2262.
+    /// Likewise intervals of days to seconds are limited to about 292 years.
+    RangeLimited,
+    /// Part of each value is discarded. Timestamps with an offset (e.g. `DATETIMEOFFSET`) are
+    /// normalized to UTC microseconds, so the offset and any finer fractional digits are lost.
+    Lossy,
+}
+
+/// Describes how a column is mapped to arrow during schema inference.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnMapping {
+    /// Name of the column
+    pub name: String,
+    /// Relational type reported by the ODBC driver
+    pub sql_type: OdbcDataType,
+    /// Arrow type inferred for the column
+    pub arrow_type: ArrowDataType,
+    /// Whether values of the column are represented losslessly by the arrow type
+    pub fidelity: Fidelity,
+}
+
+/// Like [`arrow_schema_from`], but rather than a schema it describes for each column how it is
+/// mapped from its relational type to arrow, and whether the mapping is lossless. Useful for
+/// documenting and verifying the fidelity of data contracts, without fetching any data. The
+/// mapping is the one of the default schema inference, not taking any options of
+/// [`crate::OdbcReaderBuilder`] into account.
+pub fn describe_mapping(
+    resut_set_metadata: &mut impl ResultSetMetadata,
+) -> Result<Vec<ColumnMapping>, Error> {
+    let num_cols: u16 = resut_set_metadata
+        .num_result_cols()
+        .map_err(Error::UnableToRetrieveNumCols)?
+        .try_into()
+        .unwrap();
+    (0..num_cols)
+        .map(|index| {
+            let (field, sql_type) = arrow_field_from(resut_set_metadata, index, false)?;
+            Ok(ColumnMapping {
+                name: field.name().clone(),
+                sql_type,
+                arrow_type: field.data_type().clone(),
+                fidelity: fidelity(sql_type, field.data_type()),
+            })
+        })
+        .collect()
+}
+
+fn fidelity(sql_type: OdbcDataType, arrow_type: &ArrowDataType) -> Fidelity {
+    let is_text = matches!(
+        sql_type,
+        OdbcDataType::Char { .. }
+            | OdbcDataType::WChar { .. }
+            | OdbcDataType::Varchar { .. }
+            | OdbcDataType::WVarchar { .. }
+            | OdbcDataType::LongVarchar { .. }
+    ) || matches!(
+        sql_type,
+        OdbcDataType::Other { data_type, .. } if data_type == SqlDataType::EXT_W_LONG_VARCHAR
+    );
+    if is_timestamp_with_offset(sql_type) {
+        return Fidelity::Lossy;
+    }
+    match arrow_type {
+        ArrowDataType::Timestamp(TimeUnit::Nanosecond, _)
+        | ArrowDataType::Duration(TimeUnit::Nanosecond) => Fidelity::RangeLimited,
+        ArrowDataType::Utf8 if !is_text => Fidelity::Text,
+        _ => Fidelity::Lossless,
+    }
+}
+
+/// Query the metadata to obtain the name, relational type and nullability of each column, as
+/// reported by the ODBC driver. Other than [`arrow_schema_from`] this does not map the columns to
+/// arrow. Useful to debug surprising mappings, without enabling debug logging. Columns are
+/// considered nullable, unless the driver reports them as not nullable.
+///
+/// # Example
+///
+/// ```
+/// use anyhow::Error;
+///
+/// use arrow_odbc::{odbc_schema_from, odbc_api::Connection};
+///
+/// fn print_relational_types(
+///     table_name: &str,
+///     connection: &Connection<'_>
+/// ) -> Result<(), Error> {
+///     let sql = format!("SELECT * FROM {}", table_name);
+///     let mut prepared = connection.prepare(&sql)?;
+///     for (name, sql_type, nullable) in odbc_schema_from(&mut prepared)? {
+///         println!("{name}: {sql_type:?} nullable: {nullable}");
+///     }
+///     Ok(())
+/// }
+/// ```
+pub fn odbc_schema_from(
+    resut_set_metadata: &mut impl ResultSetMetadata,
+) -> Result<Vec<(String, OdbcDataType, bool)>, Error> {
+    let num_cols: u16 = resut_set_metadata
+        .num_result_cols()
+        .map_err(Error::UnableToRetrieveNumCols)?
+        .try_into()
+        .unwrap();
+    (0..num_cols)
+        .map(|index| {
+            let (name, column_description) = describe_column(resut_set_metadata, index)?;
+            let nullable = column_description.could_be_nullable();
+            Ok((name, column_description.data_type, nullable))
+        })
+        .collect()
+}
+
+/// Name and description of the column with the zero based index, as reported by the driver.
+fn describe_column(
     resut_set_metadata: &mut impl ResultSetMetadata,
     index: u16,
-    map_value_errors_to_null: bool,
-) -> Result<Field, Error> {
+) -> Result<(String, ColumnDescription), Error> {
     let mut column_description = ColumnDescription::default();
-    resut_set_metadata
-        .describe_col(index + 1, &mut column_description)
-        .map_err(|cause| Error::ColumnFailure {
-            name: "Unknown".to_owned(),
+    if let Err(cause) = resut_set_metadata.describe_col(index + 1, &mut column_description) {
+        // Describing the column failed, yet the driver may still be able to tell us its name.
+        let name = resut_set_metadata.col_name(index + 1).unwrap_or_default();
+        return Err(Error::ColumnFailure {
+            name,
             index: index as usize,
             source: ColumnFailure::FailedToDescribeColumn(cause),
-        })?;
+        });
+    }
     let name = column_description
         .name_to_string()
         .map_err(|source| Error::EncodingInvalid { source })?;
+    Ok((name, column_description))
+}
+
+/// Infers the arrow field for the column. Also returns the relational type reported by the
+/// driver.
+pub(crate) fn arrow_field_from(
+    resut_set_metadata: &mut impl ResultSetMetadata,
+    index: u16,
+    map_value_errors_to_null: bool,
+) -> Result<(Field, OdbcDataType), Error> {
+    let (name, column_description) = describe_column(resut_set_metadata, index)?;
     debug!(
         "ODBC driver reported for column {index}. Relational type: {:?}; Nullability: {:?}; \
             Name: '{name}';",
@@ -78,6 +245,14 @@ fn arrow_field_from(
             precision: p @ 0..=38,
             scale,
         } => ArrowDataType::Decimal128(p as u8, scale.try_into().unwrap()),
+        OdbcDataType::Numeric {
+            precision: p @ 39..=76,
+            scale,
+        }
+        | OdbcDataType::Decimal {
+            precision: p @ 39..=76,
+            scale,
+        } => ArrowDataType::Decimal256(p as u8, scale.try_into().unwrap()),
         OdbcDataType::Integer => ArrowDataType::Int32,
         OdbcDataType::SmallInt => ArrowDataType::Int16,
         OdbcDataType::Real | OdbcDataType::Float { precision: 0..=24 } => ArrowDataType::Float32,
@@ -95,7 +270,20 @@ fn arrow_field_from(
         OdbcDataType::Timestamp { precision: _ } => {
             ArrowDataType::Timestamp(TimeUnit::Nanosecond, None)
         }
-        OdbcDataType::BigInt => ArrowDataType::Int64,
+        OdbcDataType::BigInt => {
+            let is_unsigned = resut_set_metadata
+                .column_is_unsigned(index + 1)
+                .map_err(|e| Error::ColumnFailure {
+                    name: name.clone(),
+                    index: index as usize,
+                    source: ColumnFailure::FailedToDescribeColumn(e),
+                })?;
+            if is_unsigned {
+                ArrowDataType::UInt64
+            } else {
+                ArrowDataType::Int64
+            }
+        }
         OdbcDataType::TinyInt => {
             let is_unsigned = resut_set_metadata
                 .column_is_unsigned(index + 1)
@@ -128,6 +316,19 @@ fn arrow_field_from(
         OdbcDataType::LongVarbinary { length: _ } | OdbcDataType::Varbinary { length: _ } => {
             ArrowDataType::Binary
         }
+        // Intervals of days to seconds are elapsed time, independent of the calendar. Intervals
+        // of years and months are not, so they stay text.
+        sql_type if is_interval_day_to_second(sql_type) => {
+            ArrowDataType::Duration(TimeUnit::Nanosecond)
+        }
+        // Timestamps with an offset (e.g. `DATETIMEOFFSET`) are normalized to UTC
+        sql_type if is_timestamp_with_offset(sql_type) => {
+            ArrowDataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into()))
+        }
+        // GUIDs are fetched as their 16 bytes, rather than as a 36 character string
+        OdbcDataType::Other { data_type, .. } if data_type == SqlDataType::EXT_GUID => {
+            ArrowDataType::FixedSizeBinary(16)
+        }
         OdbcDataType::Unknown
         | OdbcDataType::Time { precision: _ }
         | OdbcDataType::Numeric { .. }
@@ -143,9 +344,33 @@ fn arrow_field_from(
         | OdbcDataType::LongVarchar { length: _ }
         | OdbcDataType::Varchar { length: _ } => ArrowDataType::Utf8,
     };
-    let is_falliable = matches!(data_type, ArrowDataType::Timestamp(TimeUnit::Nanosecond, _));
-    let nullable =
-        column_description.could_be_nullable() || (is_falliable && map_value_errors_to_null);
+    let nullable = column_description.could_be_nullable()
+        || (is_falliable(&data_type, column_description.data_type) && map_value_errors_to_null);
     let field = Field::new(name, data_type, nullable);
-    Ok(field)
+    Ok((field, column_description.data_type))
+}
+
+/// `true` if values of the relational type may fail to map to the arrow type, e.g. because they are
+/// out of range. If value errors are mapped to `NULL`, fields of such columns must be nullable.
+pub(crate) fn is_falliable(data_type: &ArrowDataType, sql_type: OdbcDataType) -> bool {
+    // Timestamps with offset are parsed from text, which could fail.
+    if is_timestamp_with_offset(sql_type) {
+        return true;
+    }
+    match data_type {
+        // Narrower integers are converted from `BIGINT`, days since epoch from dates.
+        ArrowDataType::Int16 => sql_type == OdbcDataType::BigInt,
+        ArrowDataType::Int32 => matches!(sql_type, OdbcDataType::BigInt | OdbcDataType::Date),
+        // Unsigned integers are parsed from text, or converted from wider signed integers.
+        ArrowDataType::UInt16 | ArrowDataType::UInt32 | ArrowDataType::UInt64 => true,
+        // Dates may not exist in the calendar (e.g. `0000-00-00`).
+        ArrowDataType::Date32 | ArrowDataType::Date64 => true,
+        // Timestamps may not exist in the calendar (e.g. `0000-00-00 00:00:00`), the fraction of
+        // timestamps with sub second precision may be invalid.
+        ArrowDataType::Timestamp(..) => true,
+        ArrowDataType::Duration(TimeUnit::Nanosecond) => is_interval_day_to_second(sql_type),
+        // Times of day and arrays are parsed from text.
+        ArrowDataType::Time64(TimeUnit::Nanosecond) | ArrowDataType::FixedSizeList(..) => true,
+        _ => false,
+    }
 }
@@ -1,17 +1,27 @@
-use std::{sync::Arc, thread};
+use std::{
+    collections::HashMap,
+    future::Future,
+    pin::pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Wake, Waker},
+    thread,
+    time::{Duration, Instant},
+};
 
 use arrow::{
     array::{
         Array, ArrayRef, BinaryArray, BooleanArray, Date32Array, Date64Array, Decimal128Array,
-        Decimal256Builder, FixedSizeBinaryArray, Float16Array, Float32Array, Int16Array,
-        Int32Array, Int64Array, Int8Array, LargeStringArray, StringArray, Time32MillisecondArray,
-        Time32SecondArray, Time64MicrosecondArray, Time64NanosecondArray,
-        TimestampMicrosecondArray, TimestampMillisecondArray, TimestampNanosecondArray,
-        TimestampSecondArray, UInt8Array,
+        Decimal256Array, Decimal256Builder, DictionaryArray, DurationSecondArray,
+        FixedSizeBinaryArray, FixedSizeListArray, Float16Array, Float32Array, Int16Array,
+        Int32Array, Int64Array, Int8Array, LargeBinaryArray, LargeStringArray, ListArray,
+        NullArray, StringArray, StructArray, Time32MillisecondArray, Time32SecondArray,
+        Time64MicrosecondArray, Time64NanosecondArray, TimestampMicrosecondArray,
+        TimestampMillisecondArray, TimestampNanosecondArray, TimestampSecondArray, UInt32Array,
+        UInt64Array, UInt8Array,
     },
     datatypes::{
-        ArrowPrimitiveType, DataType, Decimal256Type, Field, Float16Type, Schema, SchemaRef,
-        TimeUnit,
+        ArrowPrimitiveType, DataType, Decimal256Type, Field, Float16Type, Int32Type, Schema,
+        SchemaRef, TimeUnit,
     },
     error::ArrowError,
     record_batch::{RecordBatch, RecordBatchReader},
@@ -26,14 +36,17 @@ type F16 = <Float16Type as ArrowPrimitiveType>::Native;
 
 use arrow_odbc::{
     arrow::array::Float64Array,
-    arrow_schema_from, insert_into_table,
+    arrow_schema_from, check_insert_compatibility, copy_cursor_to_table, describe_mapping,
+    insert_batches, insert_into_table, insert_into_table_counted, merge_sorted_readers,
     odbc_api::{
-        buffers::TextRowSet,
+        buffers::{BufferDesc, TextRowSet},
         sys::{AttrConnectionPooling, AttrCpMatch},
-        Connection, ConnectionOptions, Cursor, CursorImpl, Environment, IntoParameter,
-        StatementConnection,
+        Connection, ConnectionOptions, Cursor, CursorImpl, DataType as OdbcDataType, Environment,
+        IntoParameter, StatementConnection,
     },
-    ColumnFailure, Error, OdbcReaderBuilder, OdbcWriter, WriterError,
+    odbc_schema_from, read, BoolEncoding, BufferPool, ColumnFailure, DecimalInsertPolicy, Error,
+    ErrorAction, Fidelity, IdentifierQuoting, Incompatibility, InvalidUtf8Policy, MappingError,
+    NullFill, OdbcReaderBuilder, OdbcWriter, OdbcWriterBuilder, TextEncoding, WriterError,
 };
 
 use stdext::function_name;
@@ -58,6 +71,40 @@ lazy_static! {
     };
 }
 
+#[test]
+fn insert_into_reserved_word_column_with_square_brackets() {
+    // Given a table with a column named like a reserved word
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    let drop_table = &format!("DROP TABLE IF EXISTS {table_name}");
+    let create_table =
+        format!("CREATE TABLE {table_name} (id int IDENTITY(1,1), [order] INTEGER);");
+    conn.execute(drop_table, ()).unwrap();
+    conn.execute(&create_table, ()).unwrap();
+    let array = Int32Array::from(vec![Some(42)]);
+    let schema = Arc::new(Schema::new(vec![Field::new(
+        "order",
+        DataType::Int32,
+        true,
+    )]));
+    let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(array)]).unwrap();
+
+    // When inserting with column names quoted in square brackets
+    let mut writer = OdbcWriterBuilder::new()
+        .with_identifier_quoting(IdentifierQuoting::SquareBracket)
+        .build_with_connection(&conn, &schema, table_name, 10)
+        .unwrap();
+    writer.write_batch(&batch).unwrap();
+    writer.flush().unwrap();
+
+    // Then
+    let actual = table_to_string(&conn, table_name, &["[order]"]);
+    let expected = "42";
+    assert_eq!(expected, actual);
+}
+
 /// Fill a record batch with non nullable Integer 32 Bit directly from the datasource
 #[test]
 fn fetch_nullable_32bit_integer() {
@@ -147,1106 +194,4227 @@ fn fetch_8bit_unsigned_integer_explicit_schema() {
     assert_eq!([1, 2, 3], *array_vals.values());
 }
 
-/// Fill a record batch with non nullable Integer 8 Bit usigned integer. Since that type would never
-/// interferred from the Database automatically it must be specified explicitly in a schema
+/// Spatial columns in SQL Server are fetched as binary, if the DBMS is known
 #[test]
-fn fetch_decimal128_negative_scale_unsupported() {
+fn fetch_geography_as_binary() {
     let table_name = function_name!().rsplit_once(':').unwrap().1;
-    // Setup table with dummy value, we won't be able to read it though
+    // Given a geography column holding a point
     let conn = ENV
         .connect_with_connection_string(MSSQL, Default::default())
         .unwrap();
-    setup_empty_table(&conn, table_name, &["NUMERIC(5,0) NOT NULL"]).unwrap();
-    let sql = format!("INSERT INTO {table_name} (a) VALUES (12300)");
+    setup_empty_table(&conn, table_name, &["GEOGRAPHY"]).unwrap();
+    let sql =
+        format!("INSERT INTO {table_name} (a) VALUES (geography::Point(47.65, -122.34, 4326))");
     conn.execute(&sql, ()).unwrap();
-
-    // Query column with values to get a cursor
+    let dbms_name = conn.database_management_system_name().unwrap();
     let sql = format!("SELECT a FROM {table_name}");
     let cursor = conn.execute(&sql, ()).unwrap().unwrap();
 
-    // Specify Uint8 manually, since inference of the arrow type from the sql type would yield a
-    // signed 8 bit integer.
-    let schema = Arc::new(Schema::new(vec![Field::new(
-        "a",
-        DataType::Decimal128(3, -2),
-        false,
-    )]));
-    let result = OdbcReaderBuilder::new().with_schema(schema).build(cursor);
+    // When
+    let mut reader = OdbcReaderBuilder::new()
+        .with_dbms_name(&dbms_name)
+        .with_max_binary_size(1024)
+        .build(cursor)
+        .unwrap();
+    let record_batch = reader.next().unwrap().unwrap();
 
-    assert!(matches!(
-        result,
-        Err(Error::ColumnFailure {
-            source: ColumnFailure::UnsupportedArrowType(DataType::Decimal128(3, -2)),
-            index: 0,
-            name: _
-        })
-    ))
+    // Then we get the bytes of the native serialization, starting with the SRID
+    let field = record_batch.schema().field(0).clone();
+    assert_eq!(&DataType::Binary, field.data_type());
+    assert_eq!("mssql-clr", field.metadata()["arrow.spatial"]);
+    let array_vals = record_batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<BinaryArray>()
+        .unwrap();
+    // SRID 4326 (4 Bytes), version (1 Byte), properties (1 Byte), latitude and longitude.
+    assert_eq!(22, array_vals.value(0).len());
+    assert_eq!(&[0xE6, 0x10, 0, 0], &array_vals.value(0)[..4]);
 }
 
-/// Observe that an explicitly specified Uint16 triggers an unsupported error
+/// Read dates as plain days since epoch into an Int32 array
 #[test]
-fn unsupported_16bit_unsigned_integer() {
+fn fetch_date_as_int32() {
     let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a DATE column
+    let cursor = cursor_over(table_name, "DATE", "('1970-01-02'),(NULL),('2020-01-01')");
 
-    // Setup a table on the database with some floats (so we can fetch them)
-    let conn = ENV
-        .connect_with_connection_string(MSSQL, Default::default())
+    // When reading it with an explicit Int32 schema
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+    let mut reader = OdbcReaderBuilder::new()
+        .with_schema(schema)
+        .build(cursor)
         .unwrap();
-    setup_empty_table(&conn, table_name, &["SMALLINT NOT NULL"]).unwrap();
-    let sql = format!("INSERT INTO {table_name} (a) VALUES (1),(2),(3)");
-    conn.execute(&sql, ()).unwrap();
-
-    // Query column with values to get a cursor
-    let sql = format!("SELECT a FROM {table_name}");
-    let cursor = conn.execute(&sql, ()).unwrap().unwrap();
-
-    // Specify Uint16 manually, since inference of the arrow type from the sql type would yield a
-    // signed 16 bit integer.
-    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::UInt16, false)]));
-    let result = OdbcReaderBuilder::new().with_schema(schema).build(cursor);
+    let arrow_batch = reader.next().unwrap().unwrap();
 
-    assert!(matches!(
-        result,
-        Err(Error::ColumnFailure {
-            source: ColumnFailure::UnsupportedArrowType(DataType::UInt16),
-            index: 0,
-            name: _
-        })
-    ))
+    // Then we get the number of days since 1970-01-01
+    let array_vals = arrow_batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<Int32Array>()
+        .unwrap();
+    assert_eq!(1, array_vals.value(0));
+    assert!(array_vals.is_null(1));
+    assert_eq!(18262, array_vals.value(2));
 }
 
-/// Fill a record batch with non nullable Boolean from Bits
+/// A query timeout must not interfere with schema inference on a healthy connection
 #[test]
-fn fetch_boolean() {
+fn query_timeout_during_schema_inference() {
     let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given
+    let cursor = cursor_over(table_name, "INTEGER", "(42)");
 
-    let array_any = fetch_arrow_data(table_name, "BIT NOT NULL", "(1),(0),(1)").unwrap();
-
-    let array_vals = array_any.as_any().downcast_ref::<BooleanArray>().unwrap();
+    // When
+    let mut reader = OdbcReaderBuilder::new()
+        .with_query_timeout(Duration::from_secs(10))
+        .build(cursor)
+        .unwrap();
+    let arrow_batch = reader.next().unwrap().unwrap();
 
-    // Assert that the correct values are found within the arrow batch
-    assert!(array_vals.value(0));
-    assert!(!array_vals.value(1));
-    assert!(array_vals.value(2));
+    // Then
+    let array_vals = arrow_batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<Int32Array>()
+        .unwrap();
+    assert_eq!(42, array_vals.value(0));
 }
 
-/// Fill a record batch with nullable Booleans from Bits
+/// A metadata query timeout does not interfere with reading on a healthy connection, and does not
+/// remain set on the statement
 #[test]
-fn fetch_nullable_boolean() {
+fn metadata_query_timeout_during_schema_inference() {
     let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given
+    let cursor = cursor_over(table_name, "INTEGER", "(42)");
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    let mut prepared = conn
+        .prepare(&format!("SELECT a FROM {table_name}"))
+        .unwrap();
+    let mut builder = OdbcReaderBuilder::new();
+    builder.with_metadata_query_timeout(Duration::from_secs(10));
 
-    let array_any = fetch_arrow_data(table_name, "BIT", "(1),(NULL),(0)").unwrap();
-
-    let array_vals = array_any.as_any().downcast_ref::<BooleanArray>().unwrap();
+    // When
+    let mut reader = builder.build(cursor).unwrap();
+    let arrow_batch = reader.next().unwrap().unwrap();
+    let schema = builder.infer_schema(&mut prepared).unwrap();
 
-    // Assert that the correct values are found within the arrow batch
-    assert!(array_vals.is_valid(0));
-    assert!(array_vals.value(0));
-    assert!(array_vals.is_null(1));
-    assert!(array_vals.is_valid(2));
-    assert!(!array_vals.value(2));
+    // Then
+    let array_vals = arrow_batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<Int32Array>()
+        .unwrap();
+    assert_eq!(42, array_vals.value(0));
+    assert_eq!(&DataType::Int32, schema.field(0).data_type());
+    assert_eq!(0, prepared.query_timeout_sec().unwrap());
 }
 
-/// Fill a record batch with non nullable `f32` directly from the datasource
+/// A query timeout set on the statement by the application survives the metadata query timeout
 #[test]
-fn fetch_32bit_floating_point() {
+fn metadata_query_timeout_restores_timeout_of_statement() {
     let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["INTEGER"]).unwrap();
+    let mut prepared = conn
+        .prepare(&format!("SELECT a FROM {table_name}"))
+        .unwrap();
+    prepared.set_query_timeout_sec(7).unwrap();
+    let mut builder = OdbcReaderBuilder::new();
+    builder.with_metadata_query_timeout(Duration::from_secs(10));
 
-    let array_any = fetch_arrow_data(table_name, "REAL NOT NULL", "(1),(2),(3)").unwrap();
+    // When
+    builder.infer_schema(&mut prepared).unwrap();
 
-    let array_vals = array_any.as_any().downcast_ref::<Float32Array>().unwrap();
-    assert_float_eq!([1., 2., 3.][..], array_vals.values(), abs_all <= 000.1);
+    // Then
+    assert_eq!(7, prepared.query_timeout_sec().unwrap());
 }
 
-/// Fill a record batch with non nullable `f64` directly from the datasource
+/// The query timeout of the statement is restored, even if querying the metadata fails
 #[test]
-fn fetch_64bit_floating_point() {
+fn metadata_query_timeout_restored_after_error() {
     let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a schema with a field which can not be fetched
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["INTEGER"]).unwrap();
+    let mut prepared = conn
+        .prepare(&format!("SELECT a FROM {table_name}"))
+        .unwrap();
+    prepared.set_query_timeout_sec(7).unwrap();
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Float16, true)]));
+    let mut builder = OdbcReaderBuilder::new();
+    builder
+        .with_schema(schema)
+        .with_metadata_query_timeout(Duration::from_secs(10));
 
-    let array_any =
-        fetch_arrow_data(table_name, "DOUBLE PRECISION NOT NULL", "(1),(2),(3)").unwrap();
-
-    let array_vals = array_any.as_any().downcast_ref::<Float64Array>().unwrap();
+    // When
+    let result = builder.infer_schema(&mut prepared);
 
-    // Assert that the correct values are found within the arrow batch
-    assert_float_eq!([1., 2., 3.][..], array_vals.values(), abs_all <= 000.1);
+    // Then
+    assert!(result.is_err());
+    assert_eq!(7, prepared.query_timeout_sec().unwrap());
 }
 
-/// Fill a record batch with non nullable `i64` directly from the datasource
+/// Preset for fetching one row at a time
 #[test]
-fn fetch_64bit_integer() {
+fn interactive_reader_fetches_one_row_at_a_time() {
     let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given
+    let cursor = cursor_over(table_name, "INTEGER", "(1),(2)");
 
-    let array_any = fetch_arrow_data(table_name, "BIGINT NOT NULL", "(1),(2),(3)").unwrap();
-
-    let array_vals = array_any.as_any().downcast_ref::<Int64Array>().unwrap();
+    // When
+    let mut reader = OdbcReaderBuilder::interactive().build(cursor).unwrap();
+    let first = reader.fetch_one().unwrap().unwrap();
+    let second = reader.fetch_one().unwrap().unwrap();
+    let third = reader.fetch_one().unwrap();
 
-    // Assert that the correct values are found within the arrow batch
-    assert_eq!([1, 2, 3], *array_vals.values());
+    // Then
+    assert_eq!(1, reader.max_rows_per_batch());
+    assert_eq!(1, first.num_rows());
+    assert_eq!(1, second.num_rows());
+    assert!(third.is_none());
 }
 
-/// Fill a record batch of Strings from a varchar source column
+/// Stop reading once the deadline for the entire result set has passed
 #[test]
-fn fetch_varchar() {
+fn read_with_exceeded_deadline() {
     let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a result set spanning multiple batches
+    let cursor = cursor_over(table_name, "INTEGER", "(1),(2),(3)");
 
-    let array_any =
-        fetch_arrow_data(table_name, "VARCHAR(50)", "('Hello'),('Bonjour'),(NULL)").unwrap();
-
-    let array_vals = array_any.as_any().downcast_ref::<StringArray>().unwrap();
+    // When reading it with a deadline which already passed
+    let mut reader = OdbcReaderBuilder::new()
+        .with_max_num_rows_per_batch(1)
+        .with_deadline(Instant::now())
+        .build(cursor)
+        .unwrap();
+    let first = reader.next().unwrap();
+    let second = reader.next();
 
-    // Assert that the correct values are found within the arrow batch
-    assert_eq!("Hello", array_vals.value(0));
-    assert_eq!("Bonjour", array_vals.value(1));
-    assert!(array_vals.is_null(2));
+    // Then the deadline is reported, and the reader stops afterwards
+    let Err(ArrowError::ExternalError(error)) = first else {
+        panic!("Expected an external error")
+    };
+    assert!(matches!(
+        error.downcast_ref::<Error>(),
+        Some(Error::DeadlineExceeded)
+    ));
+    assert!(second.is_none());
 }
 
-/// Fill a record batch of Strings from a varchar source column
+/// Batches have a uniform size, independent of the size of the transit buffer
 #[test]
-fn trim_fixed_sized_character_data() {
+fn exact_batch_rows() {
     let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a table with seven rows
+    let cursor = cursor_over(table_name, "INTEGER", "(1),(2),(3),(4),(5),(6),(7)");
 
-    let cursor = cursor_over(table_name, "CHAR(4)", "('1234'),(' 123'),('123 ')");
-    // Now that we have a cursor, we want to iterate over its rows and fill an arrow batch with it.
-    let mut reader = OdbcReaderBuilder::new()
-        .with_max_num_rows_per_batch(4)
-        .trim_fixed_sized_characters(true)
+    // When fetching in roundtrips of three rows, emitting batches of two rows
+    let reader = OdbcReaderBuilder::new()
+        .with_max_num_rows_per_batch(3)
+        .with_exact_batch_rows(2)
         .build(cursor)
-        .unwrap()
-        .into_concurrent()
         .unwrap();
-    // Batch for batch copy values from ODBC buffer into arrow batches
-    let record_batch = reader.next().unwrap().unwrap();
-    let array_any = record_batch.column(0).clone();
+    let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
 
-    // Assert that the correct values are found within the arrow batch
-    let array_vals = array_any.as_any().downcast_ref::<StringArray>().unwrap();
-    assert_eq!("1234", array_vals.value(0));
-    assert_eq!("123", array_vals.value(1));
-    assert_eq!("123", array_vals.value(2));
+    // Then all batches have two rows, except the last one
+    let sizes: Vec<_> = batches.iter().map(RecordBatch::num_rows).collect();
+    assert_eq!(vec![2, 2, 2, 1], sizes);
+    let values: Vec<_> = batches
+        .iter()
+        .flat_map(|batch| {
+            batch
+                .column(0)
+                .as_any()
+                .downcast_ref::<Int32Array>()
+                .unwrap()
+                .values()
+                .to_vec()
+        })
+        .collect();
+    assert_eq!(vec![1, 2, 3, 4, 5, 6, 7], values);
 }
 
-/// Fill a record batch of Strings from a nvarchar source column
+/// The batch crossing the row limit is truncated and the iteration ends afterwards
 #[test]
-fn fetch_nvarchar() {
+fn max_total_rows() {
     let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a table with five rows
+    let cursor = cursor_over(table_name, "INTEGER", "(1),(2),(3),(4),(5)");
 
-    let array_any =
-        fetch_arrow_data(table_name, "NVARCHAR(50)", "('Hello'),('Bonjour'),(NULL)").unwrap();
-
-    let array_vals = array_any.as_any().downcast_ref::<StringArray>().unwrap();
+    // When limiting the rows to three
+    let reader = OdbcReaderBuilder::new()
+        .with_max_total_rows(3)
+        .build(cursor)
+        .unwrap();
+    let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
 
-    // Assert that the correct values are found within the arrow batch
-    assert_eq!("Hello", array_vals.value(0));
-    assert_eq!("Bonjour", array_vals.value(1));
-    assert!(array_vals.is_null(2));
+    // Then we get a single batch with the first three rows
+    assert_eq!(1, batches.len());
+    let values = batches[0]
+        .column(0)
+        .as_any()
+        .downcast_ref::<Int32Array>()
+        .unwrap();
+    assert_eq!([1, 2, 3], values.values().as_ref());
 }
 
-/// Fill a record batch of Dates
+/// Fetch arrays in the text representation of PostgreSQL (e.g. `integer[]` columns) as lists of
+/// fixed size. Microsoft SQL Server has no array types, so we emulate them with text.
 #[test]
-fn fetch_dates() {
+fn fetch_array_text_as_fixed_size_list() {
     let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a text column holding arrays of three integers
+    let cursor = cursor_over(
+        table_name,
+        "VARCHAR(20)",
+        "('{1,2,3}'),('{4, NULL, 6}'),(NULL)",
+    );
 
-    let array_any =
-        fetch_arrow_data(table_name, "DATE", "('2021-04-09'),(NULL),('2002-09-30')").unwrap();
-
-    let array_vals = array_any.as_any().downcast_ref::<Date32Array>().unwrap();
+    // When reading it with an explicit FixedSizeList<Int32> schema
+    let item = Arc::new(Field::new("item", DataType::Int32, true));
+    let schema = Arc::new(Schema::new(vec![Field::new(
+        "a",
+        DataType::FixedSizeList(item, 3),
+        true,
+    )]));
+    let mut reader = OdbcReaderBuilder::new()
+        .with_schema(schema)
+        .build(cursor)
+        .unwrap();
+    let record_batch = reader.next().unwrap().unwrap();
 
-    // Assert that the correct values are found within the arrow batch
-    assert_eq!(
-        Some(NaiveDate::from_ymd_opt(2021, 4, 9).unwrap()),
-        array_vals.value_as_date(0)
-    );
-    assert!(array_vals.is_null(1));
-    assert_eq!(
-        Some(NaiveDate::from_ymd_opt(2002, 9, 30).unwrap()),
-        array_vals.value_as_date(2)
-    );
+    // Then each array is parsed into its elements
+    let lists = record_batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<FixedSizeListArray>()
+        .unwrap();
+    let elements = |index| {
+        lists
+            .value(index)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap()
+            .iter()
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(vec![Some(1), Some(2), Some(3)], elements(0));
+    assert_eq!(vec![Some(4), None, Some(6)], elements(1));
+    assert!(lists.is_null(2));
 }
 
-/// Fill a record batch of non nullable Dates
+/// Arrays with the wrong number of elements can not be fetched into a list of fixed size
 #[test]
-fn fetch_non_null_dates() {
+fn fetch_array_text_with_wrong_length_as_fixed_size_list() {
     let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a text column holding an array of two integers
+    let cursor = cursor_over(table_name, "VARCHAR(20)", "('{1,2}')");
 
-    let array_any =
-        fetch_arrow_data(table_name, "DATE NOT NULL", "('2021-04-09'),('2002-09-30')").unwrap();
-
-    let array_vals = array_any.as_any().downcast_ref::<Date32Array>().unwrap();
+    // When reading it as FixedSizeList<Int32> of length three
+    let item = Arc::new(Field::new("item", DataType::Int32, true));
+    let schema = Arc::new(Schema::new(vec![Field::new(
+        "a",
+        DataType::FixedSizeList(item, 3),
+        true,
+    )]));
+    let mut reader = OdbcReaderBuilder::new()
+        .with_schema(schema)
+        .build(cursor)
+        .unwrap();
+    let result = reader.next().unwrap();
 
-    // Assert that the correct values are found within the arrow batch
-    assert_eq!(
-        Some(NaiveDate::from_ymd_opt(2021, 4, 9).unwrap()),
-        array_vals.value_as_date(0)
-    );
-    assert_eq!(
-        Some(NaiveDate::from_ymd_opt(2002, 9, 30).unwrap()),
-        array_vals.value_as_date(1)
-    );
+    // Then
+    assert!(result.is_err());
 }
 
-/// Fill a record batch of non nullable timestamps with milliseconds precision
+/// Fetch comma separated labels, as e.g. returned for MySQL `SET` columns, as a list of strings
 #[test]
-fn fetch_non_null_date_time() {
+fn fetch_comma_separated_text_as_list() {
     let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a text column holding comma separated labels
+    let cursor = cursor_over(table_name, "VARCHAR(10)", "('a,c'),(''),(NULL)");
 
-    let array_any = fetch_arrow_data(
-        table_name,
-        "DATETIME NOT NULL",
-        "('2021-04-09 18:57:50.12'),('2002-09-30 12:43:17.45')",
-    )
-    .unwrap();
+    // When reading it with an explicit List<Utf8> schema
+    let item = Arc::new(Field::new("item", DataType::Utf8, true));
+    let schema = Arc::new(Schema::new(vec![Field::new(
+        "a",
+        DataType::List(item),
+        true,
+    )]));
+    let mut reader = OdbcReaderBuilder::new()
+        .with_schema(schema)
+        .build(cursor)
+        .unwrap();
+    let record_batch = reader.next().unwrap().unwrap();
 
-    let array_vals = array_any
+    // Then each value is split into its labels
+    let lists = record_batch
+        .column(0)
         .as_any()
-        .downcast_ref::<TimestampMillisecondArray>()
+        .downcast_ref::<ListArray>()
         .unwrap();
-
-    // Assert that the correct values are found within the arrow batch
-    assert_eq!(
-        Some(
-            NaiveDate::from_ymd_opt(2021, 4, 9)
-                .unwrap()
-                .and_hms_milli_opt(18, 57, 50, 120)
-                .unwrap()
-        ),
-        array_vals.value_as_datetime(0)
-    );
-    assert_eq!(
-        Some(
-            NaiveDate::from_ymd_opt(2002, 9, 30)
-                .unwrap()
-                .and_hms_milli_opt(12, 43, 17, 450)
-                .unwrap()
-        ),
-        array_vals.value_as_datetime(1)
-    );
+    let labels = |index| {
+        lists
+            .value(index)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap()
+            .iter()
+            .map(|label| label.unwrap().to_owned())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(vec!["a", "c"], labels(0));
+    assert!(labels(1).is_empty());
+    assert!(lists.is_null(2));
 }
 
-/// Fill a record batch of nullable timestamps with milliseconds precision
+/// Comma separated labels are split into a list, if the column is overridden as List<Utf8>
 #[test]
-fn fetch_date_time_us() {
+fn fetch_comma_separated_text_as_list_with_column_override() {
     let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a text column holding comma separated labels
+    let cursor = cursor_over(table_name, "VARCHAR(10)", "('a,c')");
 
-    let array_any = fetch_arrow_data(
-        table_name,
-        "DATETIME2(6)",
-        "('2021-04-09 18:57:50'),(NULL),('2002-09-30 12:43:17')",
-    )
-    .unwrap();
+    // When overriding the inferred type of the column
+    let item = Arc::new(Field::new("item", DataType::Utf8, true));
+    let mut reader = OdbcReaderBuilder::new()
+        .with_column_override("a", DataType::List(item))
+        .build(cursor)
+        .unwrap();
+    let record_batch = reader.next().unwrap().unwrap();
 
-    let array_vals = array_any
+    // Then the value is split into its labels
+    let lists = record_batch
+        .column(0)
         .as_any()
-        .downcast_ref::<TimestampMicrosecondArray>()
+        .downcast_ref::<ListArray>()
         .unwrap();
-
-    // Assert that the correct values are found within the arrow batch
-    assert_eq!(
-        Some(
-            NaiveDate::from_ymd_opt(2021, 4, 9)
-                .unwrap()
-                .and_hms_milli_opt(18, 57, 50, 0)
-                .unwrap()
-        ),
-        array_vals.value_as_datetime(0)
-    );
-    assert!(array_vals.is_null(1));
+    let labels = lists.value(0);
+    let labels = labels.as_any().downcast_ref::<StringArray>().unwrap();
     assert_eq!(
-        Some(
-            NaiveDate::from_ymd_opt(2002, 9, 30)
-                .unwrap()
-                .and_hms_milli_opt(12, 43, 17, 00)
-                .unwrap()
-        ),
-        array_vals.value_as_datetime(2)
+        vec![Some("a"), Some("c")],
+        labels.iter().collect::<Vec<_>>()
     );
 }
 
-/// Fill a record batch of nullable timestamps with milliseconds precision
+/// Fetch decimals as text, preserving the exact representation emitted by the driver
 #[test]
-fn fetch_date_time_ms() {
+fn fetch_decimal_as_text() {
     let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a DECIMAL column
+    let cursor = cursor_over(table_name, "DECIMAL(5,2)", "(678.90),(NULL)");
 
-    let array_any = fetch_arrow_data(
-        table_name,
-        "DATETIME",
-        "('2021-04-09 18:57:50'),(NULL),('2002-09-30 12:43:17')",
-    )
-    .unwrap();
+    // When reading it with decimals as text
+    let mut reader = OdbcReaderBuilder::new()
+        .with_decimals_as_text(true)
+        .build(cursor)
+        .unwrap();
+    let arrow_batch = reader.next().unwrap().unwrap();
 
-    let array_vals = array_any
+    // Then the trailing zero is preserved
+    assert_eq!(&DataType::Utf8, arrow_batch.schema().field(0).data_type());
+    let array_vals = arrow_batch
+        .column(0)
         .as_any()
-        .downcast_ref::<TimestampMillisecondArray>()
+        .downcast_ref::<StringArray>()
         .unwrap();
-
-    // Assert that the correct values are found within the arrow batch
-    assert_eq!(
-        Some(
-            NaiveDate::from_ymd_opt(2021, 4, 9)
-                .unwrap()
-                .and_hms_milli_opt(18, 57, 50, 0)
-                .unwrap()
-        ),
-        array_vals.value_as_datetime(0)
-    );
+    assert_eq!("678.90", array_vals.value(0));
     assert!(array_vals.is_null(1));
-    assert_eq!(
-        Some(
-            NaiveDate::from_ymd_opt(2002, 9, 30)
-                .unwrap()
-                .and_hms_milli_opt(12, 43, 17, 00)
-                .unwrap()
-        ),
-        array_vals.value_as_datetime(2)
-    );
 }
 
-/// Fetch a timestamp older than unix epoch, i.e 1970-01-01 00:00:00. See issue:
-/// <https://github.com/pacman82/arrow-odbc/issues/111>
+/// SQL Server reports MONEY and SMALLMONEY as decimals with a scale of four
 #[test]
-fn fetch_date_time_ms_before_epoch() {
+fn fetch_money_as_decimal() {
     let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["MONEY", "SMALLMONEY"]).unwrap();
+    let insert =
+        format!("INSERT INTO {table_name} (a, b) VALUES (1234.5678, -12.34), (NULL, NULL)");
+    conn.execute(&insert, ()).unwrap();
+    let query = format!("SELECT a, b FROM {table_name} ORDER BY id");
+    let cursor = conn.into_cursor(&query, ()).unwrap().unwrap();
 
-    let array_any =
-        fetch_arrow_data(table_name, "DATETIME", "('1900-01-01 12:43:17.123')").unwrap();
+    // When
+    let mut reader = OdbcReaderBuilder::new().build(cursor).unwrap();
+    let arrow_batch = reader.next().unwrap().unwrap();
 
-    let array_vals = array_any
+    // Then
+    let schema = arrow_batch.schema();
+    assert_eq!(&DataType::Decimal128(19, 4), schema.field(0).data_type());
+    assert_eq!(&DataType::Decimal128(10, 4), schema.field(1).data_type());
+    let money = arrow_batch
+        .column(0)
         .as_any()
-        .downcast_ref::<TimestampMillisecondArray>()
+        .downcast_ref::<Decimal128Array>()
         .unwrap();
-
-    // Assert that the correct values are found within the arrow batch
-    assert_eq!(
-        Some(
-            NaiveDate::from_ymd_opt(1900, 1, 1)
-                .unwrap()
-                .and_hms_milli_opt(12, 43, 17, 123)
-                .unwrap()
-        ),
-        array_vals.value_as_datetime(0)
-    );
+    let small_money = arrow_batch
+        .column(1)
+        .as_any()
+        .downcast_ref::<Decimal128Array>()
+        .unwrap();
+    assert_eq!("1234.5678", money.value_as_string(0));
+    assert_eq!("-12.3400", small_money.value_as_string(0));
+    assert!(money.is_null(1));
+    assert!(small_money.is_null(1));
 }
 
-/// Overflows could occur if reusing the same conversion logic across different time units (e.g. ns
-/// and ms) due to the difference in time ranges an i64 associated with each unit might be able
-/// to represent.
+/// Fetch a BIT column as raw integer flags
 #[test]
-fn fetch_timestamp_ms_which_could_not_be_represented_as_i64_ns() {
+fn fetch_bit_as_int16() {
     let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a BIT column
+    let cursor = cursor_over(table_name, "BIT", "(1),(NULL),(0)");
 
-    // Earliest date representable in ns is  1677-09-21T00:12:43.145224192, so this is earlier, but
-    // should still work, due to the precision, being set to ms
-    let array_any =
-        fetch_arrow_data(table_name, "DATETIME2(3)", "('1600-06-18T23:12:44.123Z')").unwrap();
+    // When reading it with an explicit Int16 schema
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int16, true)]));
+    let mut reader = OdbcReaderBuilder::new()
+        .with_schema(schema)
+        .build(cursor)
+        .unwrap();
+    let arrow_batch = reader.next().unwrap().unwrap();
 
-    let array_vals = array_any
+    // Then
+    let array_vals = arrow_batch
+        .column(0)
         .as_any()
-        .downcast_ref::<TimestampMillisecondArray>()
+        .downcast_ref::<Int16Array>()
         .unwrap();
-
-    // Assert that the correct values are found within the arrow batch
-    assert_eq!(
-        Some(
-            NaiveDate::from_ymd_opt(1600, 6, 18)
-                .unwrap()
-                .and_hms_milli_opt(23, 12, 44, 123)
-                .unwrap()
-        ),
-        array_vals.value_as_datetime(0)
-    );
+    assert_eq!(1, array_vals.value(0));
+    assert!(array_vals.is_null(1));
+    assert_eq!(0, array_vals.value(2));
 }
 
-/// Fill a record batch of non nullable timestamps with nanoseconds precision
+/// Fetch a BIGINT column into a narrower integer type declared in the schema
 #[test]
-fn fetch_non_null_date_time_ns() {
+fn fetch_bigint_as_int32() {
     let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a BIGINT column with a value exceeding the range of a 32 Bit integer
+    let cursor = cursor_over(table_name, "BIGINT", "(42),(NULL),(3000000000)");
 
-    let array_any = fetch_arrow_data(
-        table_name,
-        "DATETIME2 NOT NULL",
-        "('2021-04-09 18:57:50.1234567'),('2002-09-30 12:43:17.456')",
-    )
-    .unwrap();
+    // When reading it with an explicit Int32 schema, mapping value errors to NULL
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+    let mut reader = OdbcReaderBuilder::new()
+        .with_schema(schema)
+        .value_errors_as_null(true)
+        .build(cursor)
+        .unwrap();
+    let arrow_batch = reader.next().unwrap().unwrap();
 
-    let array_vals = array_any
+    // Then values in range are fetched and the one out of range is NULL
+    let array_vals = arrow_batch
+        .column(0)
         .as_any()
-        .downcast_ref::<TimestampNanosecondArray>()
+        .downcast_ref::<Int32Array>()
         .unwrap();
-
-    // Assert that the correct values are found within the arrow batch
-    assert_eq!(
-        Some(
-            NaiveDate::from_ymd_opt(2021, 4, 9)
-                .unwrap()
-                .and_hms_nano_opt(18, 57, 50, 123_456_700)
-                .unwrap()
-        ),
-        array_vals.value_as_datetime(0)
-    );
-    assert_eq!(
-        Some(
-            NaiveDate::from_ymd_opt(2002, 9, 30)
-                .unwrap()
-                .and_hms_nano_opt(12, 43, 17, 456_000_000)
-                .unwrap()
-        ),
-        array_vals.value_as_datetime(1)
-    );
+    assert_eq!(42, array_vals.value(0));
+    assert!(array_vals.is_null(1));
+    assert!(array_vals.is_null(2));
 }
 
-/// Precision 7 timestamps need to be mapped to nanoseconds. Nanoseconds timestamps have a valid
-/// range in arrow between 1677-09-21 00:12:44 and 2262-04-11 23:47:16.854775807 due to be
-/// represented as a signed 64Bit Integer
+/// Replace NULL in a nullable integer column with a sentinel value
 #[test]
-fn fetch_out_of_range_date_time_ns() {
+fn fill_null_integers_with_sentinel() {
     let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a nullable INTEGER column containing NULL
+    let cursor = cursor_over(table_name, "INTEGER", "(1),(NULL)");
 
-    let error = fetch_arrow_data(
+    // When reading it with -1 as sentinel for integers
+    let mut reader = OdbcReaderBuilder::new()
+        .with_numeric_null_fill(NullFill {
+            integer: Some(-1),
+            ..NullFill::default()
+        })
+        .build(cursor)
+        .unwrap();
+    let arrow_batch = reader.next().unwrap().unwrap();
+
+    // Then the field is not nullable and NULL is replaced with the sentinel
+    assert!(!arrow_batch.schema().field(0).is_nullable());
+    let array_vals = arrow_batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<Int32Array>()
+        .unwrap();
+    assert_eq!(0, array_vals.null_count());
+    assert_eq!([1, -1], array_vals.values()[..]);
+}
+
+/// Fetch bytes stored in a text column, which are not valid UTF-8, as binary
+#[test]
+fn fetch_text_column_as_binary() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a CHAR column holding bytes, which are not valid UTF-8
+    let cursor = cursor_over(table_name, "CHAR(2)", "(CAST(0xFF41 AS CHAR(2))),(NULL)");
+
+    // When reading it as binary
+    let mut reader = OdbcReaderBuilder::new()
+        .with_text_columns_as_binary(&["a"])
+        .build(cursor)
+        .unwrap();
+    let arrow_batch = reader.next().unwrap().unwrap();
+
+    // Then the raw bytes are fetched
+    let array_vals = arrow_batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<BinaryArray>()
+        .unwrap();
+    assert_eq!([0xFF, 0x41], array_vals.value(0));
+    assert!(array_vals.is_null(1));
+}
+
+/// Fetch decimals as `Decimal256`. Microsoft SQL Server supports up to 38 digits only, so we
+/// declare the wider precision in an explicit schema.
+#[test]
+fn fetch_decimal_256() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given
+    let cursor = cursor_over(
         table_name,
-        "DATETIME2 NOT NULL",
-        "('2300-01-01 00:00:00.1234567')",
-    )
-    .unwrap_err();
+        "NUMERIC(38,10)",
+        "(-1234567890123456789012345678.0123456789),(0.5),(NULL)",
+    );
+    let schema = Arc::new(Schema::new(vec![Field::new(
+        "a",
+        DataType::Decimal256(50, 10),
+        true,
+    )]));
+
+    // When
+    let mut reader = OdbcReaderBuilder::new()
+        .with_schema(schema)
+        .build(cursor)
+        .unwrap();
+    let arrow_batch = reader.next().unwrap().unwrap();
+
+    // Then
+    let array_vals = arrow_batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<Decimal256Array>()
+        .unwrap();
+    assert_eq!(
+        "-1234567890123456789012345678.0123456789",
+        array_vals.value_as_string(0)
+    );
+    assert_eq!("0.5000000000", array_vals.value_as_string(1));
+    assert!(array_vals.is_null(2));
+}
+
+/// Low cardinality text columns can be fetched dictionary encoded, with keys stable across batches
+#[test]
+fn fetch_text_column_dictionary_encoded() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a column with three distinct values, repeated many times
+    let values = ["('DE')", "('FR')", "('US')"].repeat(4).join(",");
+    let cursor = cursor_over(table_name, "VARCHAR(2)", &values);
+
+    // When reading it dictionary encoded, in batches of five rows
+    let mut reader = OdbcReaderBuilder::new()
+        .with_dictionary_encoding(&["a"])
+        .with_max_num_rows_per_batch(5)
+        .build(cursor)
+        .unwrap();
+    let batches = reader.by_ref().collect::<Result<Vec<_>, _>>().unwrap();
+
+    // Then the schema reflects the encoding
+    assert_eq!(
+        &DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
+        reader.schema().field(0).data_type()
+    );
+    // And the dictionary of the last batch has three entries
+    let last = batches.last().unwrap();
+    let array_vals = last
+        .column(0)
+        .as_any()
+        .downcast_ref::<DictionaryArray<Int32Type>>()
+        .unwrap();
+    assert_eq!(3, array_vals.values().len());
+    // And every value is represented by the same key in every batch
+    let keys: Vec<i32> = batches
+        .iter()
+        .flat_map(|batch| {
+            let array = batch
+                .column(0)
+                .as_any()
+                .downcast_ref::<DictionaryArray<Int32Type>>()
+                .unwrap();
+            array.keys().values().to_vec()
+        })
+        .collect();
+    assert_eq!([0, 1, 2].repeat(4), keys);
+}
+
+/// Override the type of a single column, while inferring the others
+#[test]
+fn override_type_of_single_column() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["SMALLINT", "REAL"]).unwrap();
+    let insert = format!("INSERT INTO {table_name} (a, b) VALUES (200, 1.5)");
+    conn.execute(&insert, ()).unwrap();
+    let query = format!("SELECT a, b FROM {table_name}");
+    let cursor = conn.into_cursor(&query, ()).unwrap().unwrap();
+
+    // When
+    let mut reader = OdbcReaderBuilder::new()
+        .with_column_override("a", DataType::UInt8)
+        .build(cursor)
+        .unwrap();
+    let arrow_batch = reader.next().unwrap().unwrap();
+
+    // Then
+    let schema = arrow_batch.schema();
+    assert_eq!(&DataType::UInt8, schema.field(0).data_type());
+    assert_eq!(&DataType::Float32, schema.field(1).data_type());
+    let a = arrow_batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<UInt8Array>()
+        .unwrap();
+    assert_eq!(200, a.value(0));
+}
+
+/// Overriding a column with a type which can not be fetched reports the column
+#[test]
+fn override_column_with_unsupported_type() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given
+    let cursor = cursor_over(table_name, "INTEGER", "(1)");
+
+    // When
+    let result = OdbcReaderBuilder::new()
+        .with_column_override("a", DataType::Utf8View)
+        .build(cursor);
+
+    // Then
+    assert!(matches!(
+        result,
+        Err(Error::ColumnFailure {
+            index: 0,
+            source: ColumnFailure::UnsupportedArrowType(DataType::Utf8View),
+            ..
+        })
+    ));
+}
+
+/// The nullability of an overridden column is derived from the type of the override. Values out
+/// of range for the override are mapped to NULL, even if the column is not nullable.
+#[test]
+fn override_not_nullable_column_with_value_errors_as_null() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a timestamp outside of the range representable with nanoseconds precision
+    let cursor = cursor_over(
+        table_name,
+        "DATETIME2(3) NOT NULL",
+        "('2300-01-01 00:00:00.123'),('2002-09-30 12:43:17.456')",
+    );
+
+    // When
+    let mut reader = OdbcReaderBuilder::new()
+        .with_column_override("a", DataType::Timestamp(TimeUnit::Nanosecond, None))
+        .value_errors_as_null(true)
+        .build(cursor)
+        .unwrap();
+    let batch = reader.next().unwrap().unwrap();
+
+    // Then
+    assert!(batch.schema().field(0).is_nullable());
+    let array_vals = batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<TimestampNanosecondArray>()
+        .unwrap();
+    assert!(array_vals.is_null(0));
+    assert!(array_vals.is_valid(1));
+}
+
+/// A schema with a type which can not be fetched is rejected, without creating a reader
+#[test]
+fn validate_unsupported_schema_against_cursor() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given
+    let mut cursor = cursor_over(table_name, "INTEGER", "(1)");
+    let schema = Schema::new(vec![Field::new("a", DataType::UInt16, false)]);
+
+    // When
+    let result = OdbcReaderBuilder::new().validate_schema(&schema, &mut cursor);
+
+    // Then
+    assert!(matches!(
+        result,
+        Err(Error::ColumnFailure {
+            index: 0,
+            source: ColumnFailure::UnsupportedArrowType(DataType::UInt16),
+            ..
+        })
+    ));
+}
+
+/// A schema with more fields than the result set has columns is rejected
+#[test]
+fn validate_schema_with_too_many_fields_against_cursor() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given
+    let mut cursor = cursor_over(table_name, "INTEGER", "(1)");
+    let schema = Schema::new(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Int32, true),
+    ]);
+
+    // When
+    let result = OdbcReaderBuilder::new().validate_schema(&schema, &mut cursor);
+
+    // Then
+    assert!(matches!(
+        result,
+        Err(Error::SchemaColumnCountMismatch {
+            num_fields: 2,
+            num_columns: 1
+        })
+    ));
+}
+
+/// ASCII columns are bound with one byte per character
+#[test]
+fn fetch_char_as_ascii() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given
+    let cursor = cursor_over(table_name, "CHAR(10)", "('Hello'),(NULL)");
+
+    // When
+    let mut reader = OdbcReaderBuilder::new()
+        .with_max_num_rows_per_batch(2)
+        .with_text_encoding_for("a", TextEncoding::Ascii)
+        .build(cursor)
+        .unwrap();
+
+    // Then ten bytes, the terminating zero and the indicator are bound for each row
+    assert_eq!(
+        2 * (10 + 1 + std::mem::size_of::<isize>()),
+        reader.buffer_size_in_bytes()
+    );
+    let arrow_batch = reader.next().unwrap().unwrap();
+    let array_vals = arrow_batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap();
+    assert_eq!("Hello     ", array_vals.value(0));
+    assert!(array_vals.is_null(1));
+}
+
+/// Characters outside of the ASCII range are reported like invalid UTF-8
+#[test]
+fn fetch_non_ascii_as_ascii() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a column holding the UTF-8 encoded `aä`
+    let cursor = cursor_over(
+        table_name,
+        "VARCHAR(4) COLLATE Latin1_General_100_BIN2_UTF8",
+        "(N'a\u{e4}')",
+    );
+
+    // When
+    let mut reader = OdbcReaderBuilder::new()
+        .with_text_encoding_for("a", TextEncoding::Ascii)
+        .build(cursor)
+        .unwrap();
+    let result = reader.next().unwrap();
+
+    // Then
+    let Err(ArrowError::ExternalError(source)) = result else {
+        panic!("Expected external error");
+    };
+    assert!(matches!(
+        source.downcast_ref::<MappingError>(),
+        Some(MappingError::InvalidUtf8 {
+            row: 0,
+            byte_offset: 1,
+            ..
+        })
+    ));
+}
+
+/// Invalid UTF-8 causes an error by default
+#[test]
+fn invalid_utf8_policy_error() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a column holding bytes, which are not valid UTF-8
+    let cursor = invalid_utf8_cursor(table_name);
+
+    // When
+    let mut reader = OdbcReaderBuilder::new()
+        .with_text_encoding_for("a", TextEncoding::Utf8)
+        .with_invalid_utf8_policy(InvalidUtf8Policy::Error)
+        .build(cursor)
+        .unwrap();
+    let result = reader.next().unwrap();
+
+    // Then
+    let Err(ArrowError::ExternalError(source)) = result else {
+        panic!("Expected external error");
+    };
+    assert!(matches!(
+        source.downcast_ref::<MappingError>(),
+        Some(MappingError::InvalidUtf8 { row: 0, .. })
+    ));
+}
+
+/// Invalid UTF-8 is replaced with the replacement character
+#[test]
+fn invalid_utf8_policy_replace() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a column holding bytes, which are not valid UTF-8
+    let cursor = invalid_utf8_cursor(table_name);
+
+    // When
+    let mut reader = OdbcReaderBuilder::new()
+        .with_text_encoding_for("a", TextEncoding::Utf8)
+        .with_invalid_utf8_policy(InvalidUtf8Policy::Replace)
+        .build(cursor)
+        .unwrap();
+    let arrow_batch = reader.next().unwrap().unwrap();
+
+    // Then
+    let array_vals = arrow_batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap();
+    assert_eq!("\u{FFFD}A", array_vals.value(0));
+    assert_eq!("ok", array_vals.value(1));
+}
+
+/// Invalid UTF-8 is replaced with NULL
+#[test]
+fn invalid_utf8_policy_null() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a column holding bytes, which are not valid UTF-8
+    let cursor = invalid_utf8_cursor(table_name);
+
+    // When
+    let mut reader = OdbcReaderBuilder::new()
+        .with_text_encoding_for("a", TextEncoding::Utf8)
+        .with_invalid_utf8_policy(InvalidUtf8Policy::Null)
+        .build(cursor)
+        .unwrap();
+    let arrow_batch = reader.next().unwrap().unwrap();
+
+    // Then
+    let array_vals = arrow_batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap();
+    assert!(array_vals.is_null(0));
+    assert_eq!("ok", array_vals.value(1));
+}
+
+/// Metadata attached to an explicitly specified schema is preserved by the reader
+#[test]
+fn preserve_metadata_of_explicit_schema() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a schema with metadata attached to a field and the schema itself
+    let cursor = cursor_over(table_name, "INTEGER", "(1)");
+    let field_metadata = HashMap::from([("semantic".to_owned(), "customer_id".to_owned())]);
+    let schema_metadata = HashMap::from([("source".to_owned(), "crm".to_owned())]);
+    let schema = Arc::new(
+        Schema::new(vec![
+            Field::new("a", DataType::Int32, true).with_metadata(field_metadata)
+        ])
+        .with_metadata(schema_metadata),
+    );
+
+    // When reading with it
+    let mut reader = OdbcReaderBuilder::new()
+        .with_schema(schema.clone())
+        .build(cursor)
+        .unwrap();
+    let arrow_batch = reader.next().unwrap().unwrap();
+
+    // Then the schema of the reader and the batches equals the one specified, metadata included
+    assert_eq!(schema, reader.schema());
+    assert_eq!(schema, arrow_batch.schema());
+}
+
+/// Fetch an integer column into an unsigned arrow array, wider than 8 Bit
+#[test]
+fn fetch_integer_as_uint32() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given an INTEGER column
+    let cursor = cursor_over(table_name, "INTEGER", "(1),(NULL),(2147483647)");
+
+    // When reading it with an explicit UInt32 schema
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::UInt32, true)]));
+    let mut reader = OdbcReaderBuilder::new()
+        .with_schema(schema)
+        .with_allow_unsigned_widening(true)
+        .build(cursor)
+        .unwrap();
+    let arrow_batch = reader.next().unwrap().unwrap();
+
+    // Then
+    let array_vals = arrow_batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<UInt32Array>()
+        .unwrap();
+    assert_eq!(1, array_vals.value(0));
+    assert!(array_vals.is_null(1));
+    assert_eq!(2147483647, array_vals.value(2));
+}
+
+/// Negative values can not be fetched into unsigned arrow arrays
+#[test]
+fn fetch_negative_integer_as_uint32() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given an INTEGER column with a negative value
+    let cursor = cursor_over(table_name, "INTEGER", "(-1)");
+
+    // When reading it with an explicit UInt32 schema
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::UInt32, true)]));
+    let mut reader = OdbcReaderBuilder::new()
+        .with_schema(schema)
+        .with_allow_unsigned_widening(true)
+        .build(cursor)
+        .unwrap();
+    let result = reader.next().unwrap();
+
+    // Then
+    assert!(result.is_err());
+}
+
+/// GUIDs are fetched as 16 bytes in canonical order
+#[test]
+fn fetch_uniqueidentifier_as_fixed_size_binary() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a UNIQUEIDENTIFIER column
+    let cursor = cursor_over(
+        table_name,
+        "UNIQUEIDENTIFIER",
+        "('6F9619FF-8B86-D011-B42D-00C04FC964FF'),(NULL)",
+    );
+
+    // When inferring the schema
+    let mut reader = OdbcReaderBuilder::new().build(cursor).unwrap();
+    let arrow_batch = reader.next().unwrap().unwrap();
+
+    // Then the bytes are in the order of the text representation
+    assert_eq!(
+        &DataType::FixedSizeBinary(16),
+        arrow_batch.schema().field(0).data_type()
+    );
+    let array_vals = arrow_batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<FixedSizeBinaryArray>()
+        .unwrap();
+    assert_eq!(
+        [
+            0x6F, 0x96, 0x19, 0xFF, 0x8B, 0x86, 0xD0, 0x11, 0xB4, 0x2D, 0x00, 0xC0, 0x4F, 0xC9,
+            0x64, 0xFF
+        ],
+        array_vals.value(0)
+    );
+    assert!(array_vals.is_null(1));
+}
+
+/// GUIDs can still be fetched as text using an explicit schema
+#[test]
+fn fetch_uniqueidentifier_as_text() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a UNIQUEIDENTIFIER column
+    let cursor = cursor_over(
+        table_name,
+        "UNIQUEIDENTIFIER",
+        "('6F9619FF-8B86-D011-B42D-00C04FC964FF')",
+    );
+
+    // When reading it with an explicit Utf8 schema
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Utf8, true)]));
+    let mut reader = OdbcReaderBuilder::new()
+        .with_schema(schema)
+        .build(cursor)
+        .unwrap();
+    let arrow_batch = reader.next().unwrap().unwrap();
+
+    // Then
+    let array_vals = arrow_batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap();
+    assert_eq!("6F9619FF-8B86-D011-B42D-00C04FC964FF", array_vals.value(0));
+}
+
+/// Interpret an integer column holding elapsed seconds as arrow duration
+#[test]
+fn fetch_integer_as_duration() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given an integer column holding elapsed seconds
+    let cursor = cursor_over(table_name, "INTEGER", "(1),(NULL),(3600)");
+
+    // When reading it with an explicit duration schema
+    let schema = Arc::new(Schema::new(vec![Field::new(
+        "a",
+        DataType::Duration(TimeUnit::Second),
+        true,
+    )]));
+    let mut reader = OdbcReaderBuilder::new()
+        .with_schema(schema)
+        .build(cursor)
+        .unwrap();
+    let arrow_batch = reader.next().unwrap().unwrap();
+
+    // Then
+    let array_vals = arrow_batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<DurationSecondArray>()
+        .unwrap();
+    assert_eq!(1, array_vals.value(0));
+    assert!(array_vals.is_null(1));
+    assert_eq!(3600, array_vals.value(2));
+}
+
+/// Fill a record batch with non nullable Integer 8 Bit usigned integer. Since that type would never
+/// interferred from the Database automatically it must be specified explicitly in a schema
+#[test]
+fn fetch_decimal128_negative_scale_unsupported() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Setup table with dummy value, we won't be able to read it though
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["NUMERIC(5,0) NOT NULL"]).unwrap();
+    let sql = format!("INSERT INTO {table_name} (a) VALUES (12300)");
+    conn.execute(&sql, ()).unwrap();
+
+    // Query column with values to get a cursor
+    let sql = format!("SELECT a FROM {table_name}");
+    let cursor = conn.execute(&sql, ()).unwrap().unwrap();
+
+    // Specify Uint8 manually, since inference of the arrow type from the sql type would yield a
+    // signed 8 bit integer.
+    let schema = Arc::new(Schema::new(vec![Field::new(
+        "a",
+        DataType::Decimal128(3, -2),
+        false,
+    )]));
+    let result = OdbcReaderBuilder::new().with_schema(schema).build(cursor);
+
+    assert!(matches!(
+        result,
+        Err(Error::ColumnFailure {
+            source: ColumnFailure::UnsupportedArrowType(DataType::Decimal128(3, -2)),
+            index: 0,
+            name: _
+        })
+    ))
+}
+
+/// Observe that an explicitly specified Uint16 triggers an unsupported error
+#[test]
+fn unsupported_16bit_unsigned_integer() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+
+    // Setup a table on the database with some floats (so we can fetch them)
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["SMALLINT NOT NULL"]).unwrap();
+    let sql = format!("INSERT INTO {table_name} (a) VALUES (1),(2),(3)");
+    conn.execute(&sql, ()).unwrap();
+
+    // Query column with values to get a cursor
+    let sql = format!("SELECT a FROM {table_name}");
+    let cursor = conn.execute(&sql, ()).unwrap().unwrap();
+
+    // Specify Uint16 manually, since inference of the arrow type from the sql type would yield a
+    // signed 16 bit integer.
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::UInt16, false)]));
+    let result = OdbcReaderBuilder::new().with_schema(schema).build(cursor);
+
+    assert!(matches!(
+        result,
+        Err(Error::ColumnFailure {
+            source: ColumnFailure::UnsupportedArrowType(DataType::UInt16),
+            index: 0,
+            name: _
+        })
+    ))
+}
+
+/// Fill a record batch with non nullable Boolean from Bits
+#[test]
+fn fetch_boolean() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+
+    let array_any = fetch_arrow_data(table_name, "BIT NOT NULL", "(1),(0),(1)").unwrap();
+
+    let array_vals = array_any.as_any().downcast_ref::<BooleanArray>().unwrap();
+
+    // Assert that the correct values are found within the arrow batch
+    assert!(array_vals.value(0));
+    assert!(!array_vals.value(1));
+    assert!(array_vals.value(2));
+}
+
+/// Fill a record batch with nullable Booleans from Bits
+#[test]
+fn fetch_nullable_boolean() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+
+    let array_any = fetch_arrow_data(table_name, "BIT", "(1),(NULL),(0)").unwrap();
+
+    let array_vals = array_any.as_any().downcast_ref::<BooleanArray>().unwrap();
+
+    // Assert that the correct values are found within the arrow batch
+    assert!(array_vals.is_valid(0));
+    assert!(array_vals.value(0));
+    assert!(array_vals.is_null(1));
+    assert!(array_vals.is_valid(2));
+    assert!(!array_vals.value(2));
+}
+
+/// Fetch booleans stored as `'Y'` and `'N'` in a character column
+#[test]
+fn fetch_boolean_from_yes_no_characters() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a CHAR(1) column holding booleans encoded as 'Y' and 'N'
+    let cursor = cursor_over(table_name, "CHAR(1)", "('Y'),(NULL),('n')");
+
+    // When reading it with the YN encoding
+    let mut reader = OdbcReaderBuilder::new()
+        .with_boolean_columns(&[("a", BoolEncoding::YN)])
+        .build(cursor)
+        .unwrap();
+    let record_batch = reader.next().unwrap().unwrap();
+
+    // Then we get an arrow boolean array
+    let array_vals = record_batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<BooleanArray>()
+        .unwrap();
+    assert!(array_vals.value(0));
+    assert!(array_vals.is_null(1));
+    assert!(!array_vals.value(2));
+}
+
+/// Values not matching the boolean encoding are reported as errors
+#[test]
+fn invalid_boolean_representation() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a CHAR(1) column holding a value which is neither 'T' nor 'F'
+    let cursor = cursor_over(table_name, "CHAR(1)", "('T'),('X')");
+
+    // When reading it with the TF encoding
+    let mut reader = OdbcReaderBuilder::new()
+        .with_boolean_columns(&[("a", BoolEncoding::TF)])
+        .build(cursor)
+        .unwrap();
+    let result = reader.next().unwrap();
+
+    // Then an error is emitted, naming the value and the encoding
+    let Err(ArrowError::ExternalError(source)) = result else {
+        panic!("Expected external error");
+    };
+    assert!(matches!(
+        source.downcast_ref::<MappingError>(),
+        Some(MappingError::InvalidBoolean {
+            value,
+            encoding: BoolEncoding::TF,
+        }) if value == "X"
+    ));
+}
+
+/// Fill a record batch with non nullable `f32` directly from the datasource
+#[test]
+fn fetch_32bit_floating_point() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+
+    let array_any = fetch_arrow_data(table_name, "REAL NOT NULL", "(1),(2),(3)").unwrap();
+
+    let array_vals = array_any.as_any().downcast_ref::<Float32Array>().unwrap();
+    assert_float_eq!([1., 2., 3.][..], array_vals.values(), abs_all <= 000.1);
+}
+
+/// Fill a record batch with non nullable `f64` directly from the datasource
+#[test]
+fn fetch_64bit_floating_point() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+
+    let array_any =
+        fetch_arrow_data(table_name, "DOUBLE PRECISION NOT NULL", "(1),(2),(3)").unwrap();
+
+    let array_vals = array_any.as_any().downcast_ref::<Float64Array>().unwrap();
+
+    // Assert that the correct values are found within the arrow batch
+    assert_float_eq!([1., 2., 3.][..], array_vals.values(), abs_all <= 000.1);
+}
+
+/// Fill a record batch with non nullable `i64` directly from the datasource
+#[test]
+fn fetch_64bit_integer() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+
+    let array_any = fetch_arrow_data(table_name, "BIGINT NOT NULL", "(1),(2),(3)").unwrap();
+
+    let array_vals = array_any.as_any().downcast_ref::<Int64Array>().unwrap();
+
+    // Assert that the correct values are found within the arrow batch
+    assert_eq!([1, 2, 3], *array_vals.values());
+}
+
+/// Fill a record batch of Strings from a varchar source column
+#[test]
+fn fetch_varchar() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+
+    let array_any =
+        fetch_arrow_data(table_name, "VARCHAR(50)", "('Hello'),('Bonjour'),(NULL)").unwrap();
+
+    let array_vals = array_any.as_any().downcast_ref::<StringArray>().unwrap();
+
+    // Assert that the correct values are found within the arrow batch
+    assert_eq!("Hello", array_vals.value(0));
+    assert_eq!("Bonjour", array_vals.value(1));
+    assert!(array_vals.is_null(2));
+}
+
+/// Empty strings are fetched as `NULL`, if requested
+#[test]
+fn fetch_empty_string_as_null() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given
+    let cursor = cursor_over(table_name, "VARCHAR(50) NOT NULL", "('Hello'),('')");
+
+    // When
+    let mut reader = OdbcReaderBuilder::new()
+        .with_empty_string_as_null(true)
+        .build(cursor)
+        .unwrap();
+    let record_batch = reader.next().unwrap().unwrap();
+
+    // Then
+    assert!(record_batch.schema().field(0).is_nullable());
+    let array_vals = record_batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap();
+    assert_eq!("Hello", array_vals.value(0));
+    assert!(array_vals.is_null(1));
+}
+
+/// Empty strings of streamed columns can be fetched as NULL, too
+#[test]
+fn fetch_empty_string_as_null_with_long_data_streaming() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given
+    let cursor = cursor_over(table_name, "VARCHAR(MAX) NOT NULL", "('Hello'),('')");
+
+    // When
+    let mut reader = OdbcReaderBuilder::new()
+        .with_long_data_streaming(&["a"])
+        .with_empty_string_as_null(true)
+        .build(cursor)
+        .unwrap();
+    let first = reader.next().unwrap().unwrap();
+    let second = reader.next().unwrap().unwrap();
+
+    // Then
+    assert!(first.schema().field(0).is_nullable());
+    let value = |batch: &RecordBatch| {
+        let array = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        array.is_valid(0).then(|| array.value(0).to_owned())
+    };
+    assert_eq!(Some("Hello".to_owned()), value(&first));
+    assert_eq!(None, value(&second));
+}
+
+/// Empty strings of dictionary encoded columns can be fetched as NULL, too
+#[test]
+fn fetch_empty_string_as_null_with_dictionary_encoding() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given
+    let cursor = cursor_over(table_name, "VARCHAR(50) NOT NULL", "('Hello'),('')");
+
+    // When
+    let mut reader = OdbcReaderBuilder::new()
+        .with_dictionary_encoding(&["a"])
+        .with_empty_string_as_null(true)
+        .build(cursor)
+        .unwrap();
+    let record_batch = reader.next().unwrap().unwrap();
+
+    // Then
+    assert!(record_batch.schema().field(0).is_nullable());
+    let array_vals = record_batch.column(0);
+    assert!(array_vals.is_valid(0));
+    assert!(array_vals.is_null(1));
+}
+
+/// Empty strings and `NULL` are distinguished by default
+#[test]
+fn fetch_empty_string() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+
+    let array_any = fetch_arrow_data(table_name, "VARCHAR(50)", "(''),(NULL)").unwrap();
+
+    let array_vals = array_any.as_any().downcast_ref::<StringArray>().unwrap();
+    assert!(array_vals.is_valid(0));
+    assert_eq!("", array_vals.value(0));
+    assert!(array_vals.is_null(1));
+}
+
+/// Fill a record batch of large strings, if requested explicitly by the schema
+#[test]
+fn fetch_varchar_as_large_utf8() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given
+    let cursor = cursor_over(table_name, "VARCHAR(50)", "('Hello'),('Bonjour'),(NULL)");
+    let schema = Arc::new(Schema::new(vec![Field::new(
+        "a",
+        DataType::LargeUtf8,
+        true,
+    )]));
+
+    // When
+    let mut reader = OdbcReaderBuilder::new()
+        .with_schema(schema)
+        .build(cursor)
+        .unwrap();
+    let record_batch = reader.next().unwrap().unwrap();
+
+    // Then
+    let array_vals = record_batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<LargeStringArray>()
+        .unwrap();
+    assert_eq!("Hello", array_vals.value(0));
+    assert_eq!("Bonjour", array_vals.value(1));
+    assert!(array_vals.is_null(2));
+}
+
+/// Wide text can be fetched into large strings, too
+#[test]
+fn fetch_nvarchar_as_large_utf8() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given
+    let cursor = cursor_over(table_name, "NVARCHAR(10)", "(N'Ölkännchen'),(NULL)");
+    let schema = Arc::new(Schema::new(vec![Field::new(
+        "a",
+        DataType::LargeUtf8,
+        true,
+    )]));
+
+    // When
+    let mut reader = OdbcReaderBuilder::new()
+        .with_schema(schema)
+        .with_force_wide_for_wide_columns(true)
+        .build(cursor)
+        .unwrap();
+    let record_batch = reader.next().unwrap().unwrap();
+
+    // Then
+    let array_vals = record_batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<LargeStringArray>()
+        .unwrap();
+    assert_eq!("Ölkännchen", array_vals.value(0));
+    assert!(array_vals.is_null(1));
+}
+
+/// Fetch wide character columns as UTF-16, even on non windows platforms
+#[test]
+fn fetch_nvarchar_as_wide_text() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given an NVARCHAR column with non ASCII characters
+    let cursor = cursor_over(table_name, "NVARCHAR(10)", "(N'Ölkännchen'),(NULL),(N'€')");
+
+    // When
+    let mut reader = OdbcReaderBuilder::new()
+        .with_force_wide_for_wide_columns(true)
+        .build(cursor)
+        .unwrap();
+    let record_batch = reader.next().unwrap().unwrap();
+
+    // Then
+    let array_vals = record_batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap();
+    assert_eq!("Ölkännchen", array_vals.value(0));
+    assert!(array_vals.is_null(1));
+    assert_eq!("€", array_vals.value(2));
+}
+
+/// Choose the encoding used to transfer text for each column individually
+#[test]
+fn text_encoding_per_column() {
+    // Given a result set with an ASCII VARCHAR and an NVARCHAR column holding CJK characters
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    let cursor = conn
+        .execute(
+            "SELECT CAST('ascii' AS VARCHAR(10)) AS a, CAST(N'漢字' AS NVARCHAR(10)) AS b",
+            (),
+        )
+        .unwrap()
+        .unwrap();
+
+    // When fetching the first as UTF-8 and the second as UTF-16
+    let mut reader = OdbcReaderBuilder::new()
+        .with_text_encoding_for("a", TextEncoding::Utf8)
+        .with_text_encoding_for("b", TextEncoding::Utf16)
+        .build(cursor)
+        .unwrap();
+    let record_batch = reader.next().unwrap().unwrap();
+
+    // Then both are decoded correctly
+    let narrow = record_batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap();
+    assert_eq!("ascii", narrow.value(0));
+    let wide = record_batch
+        .column(1)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap();
+    assert_eq!("漢字", wide.value(0));
+}
+
+/// Trim whitespaces of values in variadic character columns
+#[test]
+fn trim_all_strings() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a VARCHAR column with padded values
+    let cursor = cursor_over(table_name, "VARCHAR(10)", "('  abc  '),('def ')");
+
+    // When reading it with all strings trimmed
+    let mut reader = OdbcReaderBuilder::new()
+        .with_trim_all_strings(true)
+        .build(cursor)
+        .unwrap();
+    let record_batch = reader.next().unwrap().unwrap();
+
+    // Then
+    let array_vals = record_batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap();
+    assert_eq!("abc", array_vals.value(0));
+    assert_eq!("def", array_vals.value(1));
+}
+
+/// Fill a record batch of Strings from a varchar source column
+#[test]
+fn trim_fixed_sized_character_data() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+
+    let cursor = cursor_over(table_name, "CHAR(4)", "('1234'),(' 123'),('123 ')");
+    // Now that we have a cursor, we want to iterate over its rows and fill an arrow batch with it.
+    let mut reader = OdbcReaderBuilder::new()
+        .with_max_num_rows_per_batch(4)
+        .trim_fixed_sized_characters(true)
+        .build(cursor)
+        .unwrap()
+        .into_concurrent()
+        .unwrap();
+    // Batch for batch copy values from ODBC buffer into arrow batches
+    let record_batch = reader.next().unwrap().unwrap();
+    let array_any = record_batch.column(0).clone();
+
+    // Assert that the correct values are found within the arrow batch
+    let array_vals = array_any.as_any().downcast_ref::<StringArray>().unwrap();
+    assert_eq!("1234", array_vals.value(0));
+    assert_eq!("123", array_vals.value(1));
+    assert_eq!("123", array_vals.value(2));
+}
+
+/// Fill a record batch of Strings from a nvarchar source column
+#[test]
+fn fetch_nvarchar() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+
+    let array_any =
+        fetch_arrow_data(table_name, "NVARCHAR(50)", "('Hello'),('Bonjour'),(NULL)").unwrap();
+
+    let array_vals = array_any.as_any().downcast_ref::<StringArray>().unwrap();
+
+    // Assert that the correct values are found within the arrow batch
+    assert_eq!("Hello", array_vals.value(0));
+    assert_eq!("Bonjour", array_vals.value(1));
+    assert!(array_vals.is_null(2));
+}
+
+/// Fill a record batch of Dates
+#[test]
+fn fetch_dates() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+
+    let array_any =
+        fetch_arrow_data(table_name, "DATE", "('2021-04-09'),(NULL),('2002-09-30')").unwrap();
+
+    let array_vals = array_any.as_any().downcast_ref::<Date32Array>().unwrap();
+
+    // Assert that the correct values are found within the arrow batch
+    assert_eq!(
+        Some(NaiveDate::from_ymd_opt(2021, 4, 9).unwrap()),
+        array_vals.value_as_date(0)
+    );
+    assert!(array_vals.is_null(1));
+    assert_eq!(
+        Some(NaiveDate::from_ymd_opt(2002, 9, 30).unwrap()),
+        array_vals.value_as_date(2)
+    );
+}
+
+/// Fill a record batch of Date64, if requested explicitly by the schema
+#[test]
+fn fetch_dates_as_date64() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given
+    let cursor = cursor_over(table_name, "DATE", "('2021-04-09'),(NULL)");
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Date64, true)]));
+
+    // When
+    let mut reader = OdbcReaderBuilder::new()
+        .with_schema(schema)
+        .build(cursor)
+        .unwrap();
+    let record_batch = reader.next().unwrap().unwrap();
+
+    // Then
+    let array_vals = record_batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<Date64Array>()
+        .unwrap();
+    // 18726 days since epoch
+    assert_eq!(18_726 * 86_400_000, array_vals.value(0));
+    assert!(array_vals.is_null(1));
+}
+
+/// Date64 can also be fetched from `DATETIME` columns
+#[test]
+fn fetch_datetime_as_date64() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given
+    let cursor = cursor_over(table_name, "DATETIME", "('2021-04-09 00:00:00')");
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Date64, true)]));
+
+    // When
+    let mut reader = OdbcReaderBuilder::new()
+        .with_schema(schema)
+        .build(cursor)
+        .unwrap();
+    let record_batch = reader.next().unwrap().unwrap();
+
+    // Then
+    let array_vals = record_batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<Date64Array>()
+        .unwrap();
+    assert_eq!(
+        Some(NaiveDate::from_ymd_opt(2021, 4, 9).unwrap()),
+        array_vals.value_as_date(0)
+    );
+}
+
+/// Fill a record batch of non nullable Dates
+#[test]
+fn fetch_non_null_dates() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+
+    let array_any =
+        fetch_arrow_data(table_name, "DATE NOT NULL", "('2021-04-09'),('2002-09-30')").unwrap();
+
+    let array_vals = array_any.as_any().downcast_ref::<Date32Array>().unwrap();
+
+    // Assert that the correct values are found within the arrow batch
+    assert_eq!(
+        Some(NaiveDate::from_ymd_opt(2021, 4, 9).unwrap()),
+        array_vals.value_as_date(0)
+    );
+    assert_eq!(
+        Some(NaiveDate::from_ymd_opt(2002, 9, 30).unwrap()),
+        array_vals.value_as_date(1)
+    );
+}
+
+/// Fill a record batch of non nullable timestamps with milliseconds precision
+#[test]
+fn fetch_non_null_date_time() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+
+    let array_any = fetch_arrow_data(
+        table_name,
+        "DATETIME NOT NULL",
+        "('2021-04-09 18:57:50.12'),('2002-09-30 12:43:17.45')",
+    )
+    .unwrap();
+
+    let array_vals = array_any
+        .as_any()
+        .downcast_ref::<TimestampMillisecondArray>()
+        .unwrap();
+
+    // Assert that the correct values are found within the arrow batch
+    assert_eq!(
+        Some(
+            NaiveDate::from_ymd_opt(2021, 4, 9)
+                .unwrap()
+                .and_hms_milli_opt(18, 57, 50, 120)
+                .unwrap()
+        ),
+        array_vals.value_as_datetime(0)
+    );
+    assert_eq!(
+        Some(
+            NaiveDate::from_ymd_opt(2002, 9, 30)
+                .unwrap()
+                .and_hms_milli_opt(12, 43, 17, 450)
+                .unwrap()
+        ),
+        array_vals.value_as_datetime(1)
+    );
+}
+
+/// Fill a record batch of nullable timestamps with milliseconds precision
+#[test]
+fn fetch_date_time_us() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+
+    let array_any = fetch_arrow_data(
+        table_name,
+        "DATETIME2(6)",
+        "('2021-04-09 18:57:50'),(NULL),('2002-09-30 12:43:17')",
+    )
+    .unwrap();
+
+    let array_vals = array_any
+        .as_any()
+        .downcast_ref::<TimestampMicrosecondArray>()
+        .unwrap();
+
+    // Assert that the correct values are found within the arrow batch
+    assert_eq!(
+        Some(
+            NaiveDate::from_ymd_opt(2021, 4, 9)
+                .unwrap()
+                .and_hms_milli_opt(18, 57, 50, 0)
+                .unwrap()
+        ),
+        array_vals.value_as_datetime(0)
+    );
+    assert!(array_vals.is_null(1));
+    assert_eq!(
+        Some(
+            NaiveDate::from_ymd_opt(2002, 9, 30)
+                .unwrap()
+                .and_hms_milli_opt(12, 43, 17, 00)
+                .unwrap()
+        ),
+        array_vals.value_as_datetime(2)
+    );
+}
+
+/// Times with seven fractional digits can be fetched as nanoseconds since midnight
+#[test]
+fn fetch_time_as_time64_ns() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given
+    let cursor = cursor_over(table_name, "TIME(7)", "('12:34:56.1234567'),(NULL)");
+    let schema = Arc::new(Schema::new(vec![Field::new(
+        "a",
+        DataType::Time64(TimeUnit::Nanosecond),
+        true,
+    )]));
+
+    // When
+    let mut reader = OdbcReaderBuilder::new()
+        .with_schema(schema)
+        .build(cursor)
+        .unwrap();
+    let arrow_batch = reader.next().unwrap().unwrap();
+
+    // Then
+    let array_vals = arrow_batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<Time64NanosecondArray>()
+        .unwrap();
+    assert_eq!(
+        ((12 * 60 + 34) * 60 + 56) * 1_000_000_000 + 123_456_700,
+        array_vals.value(0)
+    );
+    assert!(array_vals.is_null(1));
+}
+
+/// Timestamps with time zone offset are normalized to UTC
+#[test]
+fn fetch_datetimeoffset_as_utc() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+
+    let array_any = fetch_arrow_data(
+        table_name,
+        "DATETIMEOFFSET",
+        "('2021-04-09 18:57:50 +02:00'),(NULL)",
+    )
+    .unwrap();
+
+    assert_eq!(
+        &DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+        array_any.data_type()
+    );
+    let array_vals = array_any
+        .as_any()
+        .downcast_ref::<TimestampMicrosecondArray>()
+        .unwrap();
+    assert_eq!(
+        Some(
+            NaiveDate::from_ymd_opt(2021, 4, 9)
+                .unwrap()
+                .and_hms_opt(16, 57, 50)
+                .unwrap()
+        ),
+        array_vals.value_as_datetime(0)
+    );
+    assert!(array_vals.is_null(1));
+}
+
+/// Timestamps with time zone offset are fetched as local time and offset, if it is preserved
+#[test]
+fn fetch_datetimeoffset_preserving_offset() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let cursor = cursor_over(
+        table_name,
+        "DATETIMEOFFSET",
+        "('2021-04-09 18:57:50.1234567 +02:00'),(NULL),('2021-04-09 18:57:50 -05:30')",
+    );
+
+    let mut reader = OdbcReaderBuilder::new()
+        .with_preserve_timestamp_offset(true)
+        .build(cursor)
+        .unwrap();
+    let batch = reader.next().unwrap().unwrap();
+
+    let array_any = batch.column(0);
+    let array_vals = array_any.as_any().downcast_ref::<StructArray>().unwrap();
+    let local_times = array_vals
+        .column_by_name("ts")
+        .unwrap()
+        .as_any()
+        .downcast_ref::<TimestampMicrosecondArray>()
+        .unwrap();
+    let offsets = array_vals
+        .column_by_name("offset_minutes")
+        .unwrap()
+        .as_any()
+        .downcast_ref::<Int16Array>()
+        .unwrap();
+    assert_eq!(
+        Some(
+            NaiveDate::from_ymd_opt(2021, 4, 9)
+                .unwrap()
+                .and_hms_micro_opt(18, 57, 50, 123_456)
+                .unwrap()
+        ),
+        local_times.value_as_datetime(0)
+    );
+    assert_eq!(120, offsets.value(0));
+    assert!(array_vals.is_null(1));
+    assert_eq!(
+        Some(
+            NaiveDate::from_ymd_opt(2021, 4, 9)
+                .unwrap()
+                .and_hms_opt(18, 57, 50)
+                .unwrap()
+        ),
+        local_times.value_as_datetime(2)
+    );
+    assert_eq!(-330, offsets.value(2));
+}
+
+/// Fill a record batch of nullable timestamps with milliseconds precision
+#[test]
+fn fetch_date_time_ms() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+
+    let array_any = fetch_arrow_data(
+        table_name,
+        "DATETIME",
+        "('2021-04-09 18:57:50'),(NULL),('2002-09-30 12:43:17')",
+    )
+    .unwrap();
+
+    let array_vals = array_any
+        .as_any()
+        .downcast_ref::<TimestampMillisecondArray>()
+        .unwrap();
+
+    // Assert that the correct values are found within the arrow batch
+    assert_eq!(
+        Some(
+            NaiveDate::from_ymd_opt(2021, 4, 9)
+                .unwrap()
+                .and_hms_milli_opt(18, 57, 50, 0)
+                .unwrap()
+        ),
+        array_vals.value_as_datetime(0)
+    );
+    assert!(array_vals.is_null(1));
+    assert_eq!(
+        Some(
+            NaiveDate::from_ymd_opt(2002, 9, 30)
+                .unwrap()
+                .and_hms_milli_opt(12, 43, 17, 00)
+                .unwrap()
+        ),
+        array_vals.value_as_datetime(2)
+    );
+}
+
+/// Fetch a timestamp older than unix epoch, i.e 1970-01-01 00:00:00. See issue:
+/// <https://github.com/pacman82/arrow-odbc/issues/111>
+#[test]
+fn fetch_date_time_ms_before_epoch() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+
+    let array_any =
+        fetch_arrow_data(table_name, "DATETIME", "('1900-01-01 12:43:17.123')").unwrap();
+
+    let array_vals = array_any
+        .as_any()
+        .downcast_ref::<TimestampMillisecondArray>()
+        .unwrap();
+
+    // Assert that the correct values are found within the arrow batch
+    assert_eq!(
+        Some(
+            NaiveDate::from_ymd_opt(1900, 1, 1)
+                .unwrap()
+                .and_hms_milli_opt(12, 43, 17, 123)
+                .unwrap()
+        ),
+        array_vals.value_as_datetime(0)
+    );
+}
+
+/// Overflows could occur if reusing the same conversion logic across different time units (e.g. ns
+/// and ms) due to the difference in time ranges an i64 associated with each unit might be able
+/// to represent.
+#[test]
+fn fetch_timestamp_ms_which_could_not_be_represented_as_i64_ns() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+
+    // Earliest date representable in ns is  1677-09-21T00:12:43.145224192, so this is earlier, but
+    // should still work, due to the precision, being set to ms
+    let array_any =
+        fetch_arrow_data(table_name, "DATETIME2(3)", "('1600-06-18T23:12:44.123Z')").unwrap();
+
+    let array_vals = array_any
+        .as_any()
+        .downcast_ref::<TimestampMillisecondArray>()
+        .unwrap();
+
+    // Assert that the correct values are found within the arrow batch
+    assert_eq!(
+        Some(
+            NaiveDate::from_ymd_opt(1600, 6, 18)
+                .unwrap()
+                .and_hms_milli_opt(23, 12, 44, 123)
+                .unwrap()
+        ),
+        array_vals.value_as_datetime(0)
+    );
+}
+
+/// Fill a record batch of non nullable timestamps with nanoseconds precision
+#[test]
+fn fetch_non_null_date_time_ns() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+
+    let array_any = fetch_arrow_data(
+        table_name,
+        "DATETIME2 NOT NULL",
+        "('2021-04-09 18:57:50.1234567'),('2002-09-30 12:43:17.456')",
+    )
+    .unwrap();
+
+    let array_vals = array_any
+        .as_any()
+        .downcast_ref::<TimestampNanosecondArray>()
+        .unwrap();
+
+    // Assert that the correct values are found within the arrow batch
+    assert_eq!(
+        Some(
+            NaiveDate::from_ymd_opt(2021, 4, 9)
+                .unwrap()
+                .and_hms_nano_opt(18, 57, 50, 123_456_700)
+                .unwrap()
+        ),
+        array_vals.value_as_datetime(0)
+    );
+    assert_eq!(
+        Some(
+            NaiveDate::from_ymd_opt(2002, 9, 30)
+                .unwrap()
+                .and_hms_nano_opt(12, 43, 17, 456_000_000)
+                .unwrap()
+        ),
+        array_vals.value_as_datetime(1)
+    );
+}
+
+/// With a safe timestamp range precision 7 timestamps are mapped to microseconds, so dates outside
+/// of the range representable with nanoseconds can be fetched.
+#[test]
+fn fetch_date_time_with_safe_timestamp_range() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let cursor = cursor_over(
+        table_name,
+        "DATETIME2 NOT NULL",
+        "('2300-01-01 00:00:00.1234567')",
+    );
+
+    let mut reader = OdbcReaderBuilder::new()
+        .with_safe_timestamp_range(true)
+        .build(cursor)
+        .unwrap();
+    let record_batch = reader.next().unwrap().unwrap();
+
+    assert_eq!(
+        &DataType::Timestamp(TimeUnit::Microsecond, None),
+        record_batch.schema().field(0).data_type()
+    );
+    let array_vals = record_batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<TimestampMicrosecondArray>()
+        .unwrap();
+    assert_eq!(
+        Some(
+            NaiveDate::from_ymd_opt(2300, 1, 1)
+                .unwrap()
+                .and_hms_micro_opt(0, 0, 0, 123456)
+                .unwrap()
+        ),
+        array_vals.value_as_datetime(0)
+    );
+}
+
+/// Timestamps of different precision are inferred with the same unit
+#[test]
+fn fetch_mixed_precision_timestamps_with_uniform_unit() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a table with a DATETIME (milliseconds) and a DATETIME2(6) (microseconds) column
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["DATETIME", "DATETIME2(6)"]).unwrap();
+    let sql = format!(
+        "INSERT INTO {table_name} (a, b) VALUES \
+        ('2020-01-01 00:00:00.123', '2020-01-01 00:00:00.123456')"
+    );
+    conn.execute(&sql, ()).unwrap();
+    let sql = format!("SELECT a, b FROM {table_name}");
+    let cursor = conn.execute(&sql, ()).unwrap().unwrap();
+
+    // When reading it with microseconds as unit for all timestamps
+    let mut reader = OdbcReaderBuilder::new()
+        .with_timestamp_unit(TimeUnit::Microsecond)
+        .build(cursor)
+        .unwrap();
+    let record_batch = reader.next().unwrap().unwrap();
+
+    // Then both columns are fetched as microseconds
+    let schema = record_batch.schema();
+    for field in schema.fields() {
+        assert_eq!(
+            &DataType::Timestamp(TimeUnit::Microsecond, None),
+            field.data_type()
+        );
+    }
+    let values = |index: usize| {
+        record_batch
+            .column(index)
+            .as_any()
+            .downcast_ref::<TimestampMicrosecondArray>()
+            .unwrap()
+            .value_as_datetime(0)
+            .unwrap()
+    };
+    let expected = |micros| {
+        NaiveDate::from_ymd_opt(2020, 1, 1)
+            .unwrap()
+            .and_hms_micro_opt(0, 0, 0, micros)
+            .unwrap()
+    };
+    assert_eq!(expected(123000), values(0));
+    assert_eq!(expected(123456), values(1));
+}
+
+/// Individual columns can be fetched as text, e.g. to preserve timestamps out of range for
+/// nanoseconds.
+#[test]
+fn fetch_out_of_range_date_time_ns_as_text() {
+    // Given
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let cursor = cursor_over(
+        table_name,
+        "DATETIME2 NOT NULL",
+        "('2300-01-01 00:00:00.1234567')",
+    );
+
+    // When
+    let mut reader = OdbcReaderBuilder::new()
+        .with_columns_as_text(&["a"])
+        .build(cursor)
+        .unwrap();
+    let batch = reader.next().unwrap().unwrap();
+
+    // Then
+    assert_eq!(&DataType::Utf8, reader.schema().field(0).data_type());
+    let array_vals = batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap();
+    assert_eq!("2300-01-01 00:00:00.1234567", array_vals.value(0));
+}
+
+/// Columns fetched as text respect the options replacing text with NULL, even if the column is
+/// not nullable
+#[test]
+fn fetch_column_as_text_with_empty_string_as_null() {
+    // Given
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let cursor = cursor_over(table_name, "VARCHAR(10) NOT NULL", "('Hello'),('')");
+
+    // When
+    let mut reader = OdbcReaderBuilder::new()
+        .with_columns_as_text(&["a"])
+        .with_empty_string_as_null(true)
+        .build(cursor)
+        .unwrap();
+    let batch = reader.next().unwrap().unwrap();
+
+    // Then
+    assert!(reader.schema().field(0).is_nullable());
+    let array_vals = batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap();
+    assert_eq!("Hello", array_vals.value(0));
+    assert!(array_vals.is_null(1));
+}
+
+/// Values of streamed columns are fetched in full, even if far larger than the text buffers.
+#[test]
+fn fetch_long_text_with_streaming() {
+    // Given
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, ConnectionOptions::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["INT NOT NULL", "VARCHAR(MAX)"]).unwrap();
+    let sql = format!(
+        "INSERT INTO {table_name} (a, b) VALUES \
+        (1, REPLICATE(CAST('x' AS VARCHAR(MAX)), 200000)), (2, NULL), (3, 'short')"
+    );
+    conn.execute(&sql, ()).unwrap();
+    let sql = format!("SELECT a, b FROM {table_name} ORDER BY id");
+    let cursor = conn.into_cursor(&sql, ()).unwrap().unwrap();
+
+    // When
+    let mut reader = OdbcReaderBuilder::new()
+        .with_max_text_size(16)
+        .with_long_data_streaming(&["b"])
+        .build(cursor)
+        .unwrap();
+    let batch = reader.next().unwrap().unwrap();
+
+    // Then
+    assert_eq!(&DataType::Utf8, reader.schema().field(1).data_type());
+    assert_eq!(3, batch.num_rows());
+    let ids = batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<Int32Array>()
+        .unwrap();
+    assert_eq!([1, 2, 3], ids.values().as_ref());
+    let texts = batch
+        .column(1)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap();
+    assert_eq!(200_000, texts.value(0).len());
+    assert!(texts.value(0).bytes().all(|byte| byte == b'x'));
+    assert!(texts.is_null(1));
+    assert_eq!("short", texts.value(2));
+    assert!(reader.next().is_none());
+}
+
+/// The fraction of millisecond and microsecond timestamps may be invalid, so these columns are
+/// inferred as nullable, if value errors are mapped to NULL.
+#[test]
+fn infer_sub_second_timestamps_as_nullable_with_value_errors_as_null() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, ConnectionOptions::default())
+        .unwrap();
+    setup_empty_table(
+        &conn,
+        table_name,
+        &["DATETIME2(3) NOT NULL", "DATETIME2(6) NOT NULL"],
+    )
+    .unwrap();
+    let sql = format!("SELECT a, b FROM {table_name}");
+    let cursor = conn.execute(&sql, ()).unwrap().unwrap();
+
+    // When
+    let reader = OdbcReaderBuilder::new()
+        .value_errors_as_null(true)
+        .build(cursor)
+        .unwrap();
+
+    // Then
+    let schema = reader.schema();
+    assert_eq!(
+        &DataType::Timestamp(TimeUnit::Millisecond, None),
+        schema.field(0).data_type()
+    );
+    assert!(schema.field(0).is_nullable());
+    assert!(schema.field(1).is_nullable());
+}
+
+/// Precision 7 timestamps need to be mapped to nanoseconds. Nanoseconds timestamps have a valid
+/// range in arrow between 1677-09-21 00:12:44 and 2262-04-11 23:47:16.854775807 due to be
+/// represented as a signed 64Bit Integer
+#[test]
+fn fetch_out_of_range_date_time_ns() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+
+    let error = fetch_arrow_data(
+        table_name,
+        "DATETIME2 NOT NULL",
+        "('2300-01-01 00:00:00.1234567')",
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        "External error: Timestamp is not representable in arrow: 2300-01-01 00:00:00.123456700\n\
+        Timestamps with nanoseconds precision are represented using a signed 64 Bit integer. This \
+        limits their range to values between 1677-09-21 00:12:44 and \
+        2262-04-11 23:47:16.854775807. The value returned from the database is outside of this \
+        range. Suggestions to fix this error either reduce the precision or fetch the values as \
+        text.",
+        error.to_string()
+    )
+}
+
+/// Precision 7 timestamps need to be mapped to nanoseconds. Nanoseconds timestamps have a valid
+/// range in arrow between 1677-09-21 00:12:44 and 2262-04-11 23:47:16.854775807 due to be
+/// represented as a signed 64Bit Integer. Default behaviour is to emit an error. In this case we
+/// want to map such values to NULL though.
+#[test]
+fn map_out_of_range_date_time_to_null() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let cursor = cursor_over(
+        table_name,
+        "DATETIME2 NOT NULL",
+        "('2300-01-01 00:00:00.1234567'),('2002-09-30 12:43:17.456')",
+    );
+
+    // Now that we have a cursor, we want to iterate over its rows and fill an arrow batch with it.
+    let mut reader = OdbcReaderBuilder::new()
+        .with_max_num_rows_per_batch(100)
+        .value_errors_as_null(true)
+        .build(cursor)
+        .unwrap()
+        .into_concurrent()
+        .unwrap();
+    // Batch for batch copy values from ODBC buffer into arrow batches
+    let record_batch = reader.next().unwrap().unwrap();
+    let array = record_batch.column(0).clone();
+
+    // Assert that the correct values are found within the arrow batch
+    let array_vals = array
+        .as_any()
+        .downcast_ref::<TimestampNanosecondArray>()
+        .unwrap();
+    assert!(array_vals.is_null(0));
+    assert_eq!(
+        Some(
+            NaiveDate::from_ymd_opt(2002, 9, 30)
+                .unwrap()
+                .and_hms_nano_opt(12, 43, 17, 456_000_000)
+                .unwrap()
+        ),
+        array_vals.value_as_datetime(1)
+    );
+}
+
+/// The mapping error handler is told about each value which can not be mapped and decides to
+/// emit NULL instead
+#[test]
+fn mapping_error_handler_skips_to_null() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a timestamp outside of the range representable with nanoseconds precision
+    let cursor = cursor_over(
+        table_name,
+        "DATETIME2 NOT NULL",
+        "('2002-09-30 12:43:17.456'),('2300-01-01 00:00:00.1234567')",
+    );
+    let reported = Arc::new(Mutex::new(Vec::new()));
+    let sink = reported.clone();
+
+    // When
+    let mut reader = OdbcReaderBuilder::new()
+        .with_mapping_error_handler(move |column, row, error| {
+            let is_out_of_range = matches!(error, MappingError::OutOfRangeTimestampNs { .. });
+            sink.lock().unwrap().push((column, row, is_out_of_range));
+            ErrorAction::SkipToNull
+        })
+        .build(cursor)
+        .unwrap();
+    let batch = reader.next().unwrap().unwrap();
+
+    // Then
+    assert!(batch.schema().field(0).is_nullable());
+    let array_vals = batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<TimestampNanosecondArray>()
+        .unwrap();
+    assert!(array_vals.is_valid(0));
+    assert!(array_vals.is_null(1));
+    assert_eq!([(0, 1, true)], reported.lock().unwrap().as_slice());
+}
+
+/// The mapping error handler may decide to emit the error
+#[test]
+fn mapping_error_handler_aborts() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a timestamp outside of the range representable with nanoseconds precision
+    let cursor = cursor_over(
+        table_name,
+        "DATETIME2 NOT NULL",
+        "('2300-01-01 00:00:00.1234567')",
+    );
+
+    // When
+    let mut reader = OdbcReaderBuilder::new()
+        .with_mapping_error_handler(|_column, _row, _error| ErrorAction::Abort)
+        .build(cursor)
+        .unwrap();
+    let result = reader.next().unwrap();
+
+    // Then
+    assert!(result.is_err());
+}
+
+/// Fill a record batch of Decimals
+#[test]
+fn fetch_decimals() {
+    // Given a cursor over a table with one decimal column
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let cursor = cursor_over(table_name, "DECIMAL(5,2) NOT NULL", "(123.45),(678.90)");
+
+    // When fetching it in batches of five
+    let mut reader = OdbcReaderBuilder::new()
+        .with_max_num_rows_per_batch(5)
+        .build(cursor)
+        .unwrap();
+    let record_batch = reader.next().unwrap().unwrap();
+
+    // Then the elements in the first column of the first batch must match the decimals in the
+    // database.
+    let column = record_batch.column(0).clone();
+    let array_vals = column.as_any().downcast_ref::<Decimal128Array>().unwrap();
+
+    // Assert that the correct values are found within the arrow batch
+    assert_eq!("123.45", array_vals.value_as_string(0));
+    assert_eq!("678.90", array_vals.value_as_string(1));
+}
+
+/// Ensure we do not drop sign in Decimal parsing
+#[test]
+fn fetch_negative_decimal() {
+    // Given a cursor over a table with one decimal column
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let cursor = cursor_over(table_name, "DECIMAL(5,2) NOT NULL", "(-123.45)");
+
+    // When fetching it in batches of five
+    let mut reader = OdbcReaderBuilder::new()
+        .with_max_num_rows_per_batch(5)
+        .build(cursor)
+        .unwrap();
+    let record_batch = reader.next().unwrap().unwrap();
+
+    // Then the elements in the first column of the first batch must match the decimals in the
+    // database.
+    let column = record_batch.column(0).clone();
+    let array_vals = column.as_any().downcast_ref::<Decimal128Array>().unwrap();
+
+    // Assert that the correct values are found within the arrow batch
+    assert_eq!("-123.45", array_vals.value_as_string(0));
+}
+
+/// Fetch variable sized binary data binary data
+#[test]
+fn fetch_varbinary_data() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+
+    // Setup a table on the database with some values (so we can fetch them)
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["VARBINARY(30) NOT NULL"]).unwrap();
+    let sql = format!("INSERT INTO {table_name} (a) VALUES (?)");
+    // Use prepared query and arguments for insertion, since literal representation depends a lot
+    // on the DB under test.
+    let mut insert = conn.prepare(&sql).unwrap();
+    insert.execute(&b"Hello".into_parameter()).unwrap();
+    insert.execute(&b"World".into_parameter()).unwrap();
+
+    // Query column with values to get a cursor
+    let sql = format!("SELECT a FROM {table_name} ORDER BY id");
+    let cursor = conn.execute(&sql, ()).unwrap().unwrap();
+
+    // Now that we have a cursor, we want to iterate over its rows and fill an arrow batch with it.
+    let mut reader = OdbcReaderBuilder::new()
+        // Batches will contain at most 100 entries.
+        .with_max_num_rows_per_batch(100)
+        // Instantiate reader with Arrow schema and ODBC cursor
+        .build(cursor)
+        .unwrap();
+
+    // Batch for batch copy values from ODBC buffer into arrow batches
+    let arrow_batch = reader.next().unwrap().unwrap();
+
+    // Assert that the correct values are found within the arrow batch
+    let array_vals = arrow_batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<BinaryArray>()
+        .unwrap();
+    assert_eq!(b"Hello", array_vals.value(0));
+    assert_eq!(b"World", array_vals.value(1));
+}
+
+/// Fetch fixed sized binary data binary data
+#[test]
+fn fetch_fixed_sized_binary_data() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+
+    // Setup a table on the database with some values (so we can fetch them)
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["BINARY(5) NOT NULL"]).unwrap();
+    let sql = format!("INSERT INTO {table_name} (a) VALUES (?)");
+    // Use prepared query and arguments for insertion, since literal representation depends a lot
+    // on the DB under test.
+    let mut insert = conn.prepare(&sql).unwrap();
+    insert.execute(&b"Hello".into_parameter()).unwrap();
+    insert.execute(&b"World".into_parameter()).unwrap();
+
+    // Query column with values to get a cursor
+    let sql = format!("SELECT a FROM {table_name} ORDER BY id");
+    let cursor = conn.execute(&sql, ()).unwrap().unwrap();
+
+    // Now that we have a cursor, we want to iterate over its rows and fill an arrow batch with it.
+    let mut reader = OdbcReaderBuilder::new()
+        // Batches will contain at most 100 entries.
+        .with_max_num_rows_per_batch(100)
+        // Instantiate reader with Arrow schema and ODBC cursor
+        .build(cursor)
+        .unwrap();
+
+    // Batch for batch copy values from ODBC buffer into arrow batches
+    let arrow_batch = reader.next().unwrap().unwrap();
+
+    // Assert that the correct values are found within the arrow batch
+    let array_vals = arrow_batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<FixedSizeBinaryArray>()
+        .unwrap();
+    assert_eq!(b"Hello", array_vals.value(0));
+    assert_eq!(b"World", array_vals.value(1));
+}
+
+/// Like [`fetch_32bit_floating_point`], but utilizing a prepared query instead of a one shot.
+#[test]
+fn prepared_query() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+
+    // Setup a table on the database with some floats (so we can fetch them)
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["REAL NOT NULL"]).unwrap();
+    let sql = format!("INSERT INTO {table_name} (a) VALUES (1),(2),(3)");
+    conn.execute(&sql, ()).unwrap();
+
+    // Query column with values to get a cursor
+    let sql = format!("SELECT a FROM {table_name}");
+    let mut prepared = conn.prepare(&sql).unwrap();
+    let cursor = prepared.execute(()).unwrap().unwrap();
+
+    // Now that we have a cursor, we want to iterate over its rows and fill an arrow batch with it.
+    let mut reader = OdbcReaderBuilder::new()
+        // Batches will contain at most 100 entries.
+        .with_max_num_rows_per_batch(100)
+        // Instantiate reader with Arrow schema and ODBC cursor
+        .build(cursor)
+        .unwrap();
+
+    // Batch for batch copy values from ODBC buffer into arrow batches
+    let arrow_batch = reader.next().unwrap().unwrap();
+
+    // Assert that the correct values are found within the arrow batch
+    let array_vals = arrow_batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<Float32Array>()
+        .unwrap();
+    assert_float_eq!([1., 2., 3.][..], array_vals.values(), abs_all <= 000.1);
+}
+
+#[test]
+fn infer_schema() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+
+    // Setup a table on the database with some floats (so we can fetch them)
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["REAL NOT NULL"]).unwrap();
+
+    // Prepare query to get metadata
+    let sql = format!("SELECT a FROM {table_name}");
+    let cursor = conn.execute(&sql, ()).unwrap().unwrap();
+
+    // Now that we have a cursor, we want to iterate over its rows and fill an arrow batch with it.
+    let reader = OdbcReaderBuilder::new()
+        .with_max_num_rows_per_batch(1)
+        // Instantiate reader with Arrow schema and ODBC cursor
+        .build(cursor)
+        .unwrap();
+
+    let actual = reader.schema();
+    let expected = Arc::new(Schema::new(vec![Field::new("a", DataType::Float32, false)]));
+
+    assert_eq!(expected, actual)
+}
+
+#[test]
+fn fetch_schema_for_table() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+
+    // Setup a table on the database with some floats (so we can fetch them)
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["REAL NOT NULL"]).unwrap();
+
+    // Prepare query to get metadata
+    let sql = format!("SELECT a FROM {table_name}");
+    let mut prepared = conn.prepare(&sql).unwrap();
+
+    // Now that we have prepared statement, we want to use it to query metadata.
+    let schema = arrow_schema_from(&mut prepared, false).unwrap();
+
+    assert_eq!(
+        "Field { \
+            name: \"a\", \
+            data_type: Float32, \
+            nullable: false, \
+            dict_id: 0, \
+            dict_is_ordered: false, \
+            metadata: {} \
+        }",
+        schema.to_string()
+    )
+}
+
+/// Allocating octet length bytes is not enough if the column on the database is encoded in UTF-16
+/// since all codepoints in range from U+0800 to U+FFFF take three bytes in UTF-8 but only two bytes
+/// in UTF-16. We test this with the 'Trade Mark Sign' (`™`) (U+2122).
+///
+/// For this test to be meaningful it must run on a Linux platform, since only then we do query the
+/// wide column with a narrow buffer and therfore convert UTF-16 to UTF-8 within the dbms.
+#[test]
+fn should_allocate_enough_memory_for_wchar_column_bound_to_u8() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let cursor = query_single_value(table_name, "NCHAR(1) NOT NULL", "™");
+
+    // Now that we have a cursor, we want to iterate over its rows and fill an arrow batch with it.
+    let mut reader = OdbcReaderBuilder::new()
+        .with_max_num_rows_per_batch(1)
+        // Instantiate reader with Arrow schema and ODBC cursor
+        .build(cursor)
+        .unwrap();
+    // Batch for batch copy values from ODBC buffer into arrow batches
+    let record_batch = reader.next().unwrap().unwrap();
+    let array_any = record_batch.column(0).clone();
+
+    // Assert that the correct values are found within the arrow batch
+    let array_vals = array_any.as_any().downcast_ref::<StringArray>().unwrap();
+    assert_eq!("™", array_vals.value(0));
+}
+
+/// Allocating octet length bytes is not enough if the column on the database is encoded in UTF-8
+/// since all codepoints in range from U+0000 to U+007F take two bytes in UTF-16 but only one byte
+/// in UTF-8. We test this with the letter a (U+0061).
+///
+/// For this test to be meaningful it must run on a windows platform, since only then we do query the
+/// wide column with a narrow buffer and therfore convert UTF-8 to UTF-16 within the dbms.
+#[test]
+fn should_allocate_enough_memory_for_varchar_column_bound_to_u16() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let cursor = query_single_value(table_name, "CHAR(1) NOT NULL", "Ü");
+
+    // Now that we have a cursor, we want to iterate over its rows and fill an arrow batch with it.
+    let mut reader = OdbcReaderBuilder::new()
+        .with_max_num_rows_per_batch(1)
+        // Instantiate reader with Arrow schema and ODBC cursor
+        .build(cursor)
+        .unwrap();
+    // Batch for batch copy values from ODBC buffer into arrow batches
+    let record_batch = reader.next().unwrap().unwrap();
+    let array_any = record_batch.column(0).clone();
+
+    // Assert that the correct values are found within the arrow batch
+    let array_vals = array_any.as_any().downcast_ref::<StringArray>().unwrap();
+    assert_eq!("Ü", array_vals.value(0));
+}
+
+/// Often than VARCHAR(MAX) is used the actual values in these columns are in the range of 100kb and
+/// not several kb. Sadly if we allocate the buffers, we have to assume the largest possible element
+/// this test verifies that users can specify sensible upper limits using their domain knowledge
+/// about the table in order to prevent out of memory issues.
+#[test]
+fn should_allow_to_fetch_from_varchar_max() {
+    // Given
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["VARCHAR(MAX)"]).unwrap();
+    let sql = format!("SELECT a FROM {table_name}");
+    let cursor = conn.execute(&sql, ()).unwrap().unwrap();
+
+    // When
+    let result = OdbcReaderBuilder::new()
+        .with_max_num_rows_per_batch(100)
+        .with_max_text_size(1024)
+        .build(cursor);
+
+    // Then
+    // In particular we do **not** get either a zero sized column or out of memory error.
+    assert!(result.is_ok())
+}
+
+/// If column limits are too small and truncation occurs, we expect an error to be raised.
+#[test]
+fn should_error_for_truncation() {
+    // Given a column with one value of length 9
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["VARCHAR(MAX)"]).unwrap();
+    let sql = format!("INSERT INTO {table_name} (a) VALUES ('123456789')");
+    conn.execute(&sql, ()).unwrap();
+    let sql = format!("SELECT a FROM {table_name}");
+    let cursor = conn.execute(&sql, ()).unwrap().unwrap();
+
+    // When fetching that value with a text limit of 5
+    let mut reader = OdbcReaderBuilder::new()
+        .with_max_num_rows_per_batch(1)
+        .with_max_text_size(5)
+        .build(cursor)
+        .unwrap();
+    let result = reader.next().unwrap();
+
+    // Then we get an error, rather than the truncation only occurring as a warning.
+    assert!(result.is_err())
+}
+
+/// Null count, minimum and maximum are collected for each emitted batch
+#[test]
+fn collect_statistics_of_last_batch() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given
+    let cursor = cursor_over(table_name, "INTEGER", "(3),(NULL),(-1),(7)");
+
+    // When
+    let mut reader = OdbcReaderBuilder::new()
+        .with_statistics(true)
+        .build(cursor)
+        .unwrap();
+    assert!(reader.last_batch_statistics().is_empty());
+    reader.next().unwrap().unwrap();
+
+    // Then
+    let stats = &reader.last_batch_statistics()[0];
+    assert_eq!(1, stats.null_count);
+    let min = stats.min.as_ref().unwrap();
+    let max = stats.max.as_ref().unwrap();
+    assert_eq!(
+        -1,
+        min.as_any().downcast_ref::<Int32Array>().unwrap().value(0)
+    );
+    assert_eq!(
+        7,
+        max.as_any().downcast_ref::<Int32Array>().unwrap().value(0)
+    );
+}
+
+/// Metadata set by the user is attached to the schema of the reader
+#[test]
+fn attach_metadata_to_schema() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given
+    let cursor = cursor_over(table_name, "INTEGER", "(42)");
+
+    // When
+    let metadata = HashMap::from([("source".to_owned(), "MyTable".to_owned())]);
+    let mut reader = OdbcReaderBuilder::new()
+        .with_schema_metadata(metadata)
+        .build(cursor)
+        .unwrap();
+    let batch = reader.next().unwrap().unwrap();
+
+    // Then
+    let expected = Some(&"MyTable".to_owned());
+    assert_eq!(expected, reader.schema().metadata().get("source"));
+    assert_eq!(expected, batch.schema().metadata().get("source"));
+}
+
+/// Count the rows remaining after some have already been emitted
+#[test]
+fn count_remaining_rows() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a result set with 250 rows
+    let values = (1..=250)
+        .map(|i| format!("({i})"))
+        .collect::<Vec<_>>()
+        .join(",");
+    let cursor = cursor_over(table_name, "INTEGER", &values);
+
+    // When counting the remaining rows after emitting one batch of 100 rows
+    let mut reader = OdbcReaderBuilder::new()
+        .with_max_num_rows_per_batch(100)
+        .build(cursor)
+        .unwrap();
+    reader.next().unwrap().unwrap();
+    let count = reader.count_remaining().unwrap();
+
+    // Then
+    assert_eq!(150, count);
+}
+
+/// Counting the remaining rows does not convert the values into arrow arrays, so values which
+/// could not be mapped do not cause an error
+#[test]
+fn count_remaining_rows_without_conversion() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a timestamp outside of the range representable with nanoseconds precision
+    let cursor = cursor_over(
+        table_name,
+        "DATETIME2",
+        "('2300-01-01 00:00:00.1234567'),('2002-09-30 12:43:17.456')",
+    );
+
+    // When
+    let reader = OdbcReaderBuilder::new().build(cursor).unwrap();
+    let count = reader.count_remaining().unwrap();
+
+    // Then
+    assert_eq!(2, count);
+}
+
+#[test]
+fn should_allow_to_fetch_from_varbinary_max() {
+    // Given
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["VARBINARY(MAX)"]).unwrap();
+    let sql = format!("SELECT a FROM {table_name}");
+    let cursor = conn.execute(&sql, ()).unwrap().unwrap();
+
+    // When
+    let result = OdbcReaderBuilder::new()
+        .with_max_num_rows_per_batch(100)
+        .with_max_binary_size(1024)
+        .build(cursor);
+
+    // Then
+    // In particular we do **not** get either a zero sized column or out of memory error.
+    assert!(result.is_ok())
+}
+
+#[test]
+fn fallibale_allocations() {
+    // Given
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["VARBINARY(4096)"]).unwrap();
+    let sql = format!("SELECT a FROM {table_name}");
+    let cursor = conn.execute(&sql, ()).unwrap().unwrap();
+
+    // When
+    let result = OdbcReaderBuilder::new()
+        .with_max_bytes_per_batch(usize::MAX)
+        .with_max_num_rows_per_batch(100_000_000)
+        .with_fallibale_allocations(true)
+        .build(cursor);
+
+    // Then
+    // In particular we do **not** get either a zero sized column or out of memory error.
+    assert!(result.is_err());
+    assert!(matches!(
+        result.err().unwrap(),
+        Error::ColumnFailure {
+            name: _,
+            index: 0,
+            source: ColumnFailure::TooLarge {
+                num_elements: 100_000_000,
+                element_size: 4096
+            }
+        }
+    ));
+}
+
+#[test]
+fn read_multiple_result_sets() {
+    // Given a cursor returning two result sets
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    let cursor = conn
+        .execute("SELECT 1 AS A; SELECT 2 AS B;", ())
+        .unwrap()
+        .unwrap();
+
+    // When
+    // Now that we have a cursor, we want to iterate over its rows and fill an arrow batch with it.
+    let mut reader = OdbcReaderBuilder::new()
+        .with_max_num_rows_per_batch(1)
+        .build(cursor)
+        .unwrap();
+    let first = reader.next().unwrap().unwrap();
+    let cursor = reader.into_cursor().unwrap();
+    let cursor = cursor.more_results().unwrap().unwrap();
+    // Now that we have a cursor, we want to iterate over its rows and fill an arrow batch with it.
+    let mut reader = OdbcReaderBuilder::new()
+        .with_max_num_rows_per_batch(1)
+        .build(cursor)
+        .unwrap();
+    let second = reader.next().unwrap().unwrap();
+
+    // Then
+    let first_vals = first
+        .column(0)
+        .as_any()
+        .downcast_ref::<Int32Array>()
+        .unwrap();
+    assert_eq!(1, first_vals.value(0));
+    let second_vals = second
+        .column(0)
+        .as_any()
+        .downcast_ref::<Int32Array>()
+        .unwrap();
+    assert_eq!(2, second_vals.value(0));
+}
+
+/// Execute statement and create reader in one call
+#[test]
+fn read_from_sql_with_parameters() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a table with some integers
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["INTEGER"]).unwrap();
+    let sql = format!("INSERT INTO {table_name} (a) VALUES (1),(2),(3)");
+    conn.execute(&sql, ()).unwrap();
+
+    // When
+    let sql = format!("SELECT a FROM {table_name} WHERE a > ? ORDER BY id");
+    let mut reader = read(&conn, &sql, &1, &OdbcReaderBuilder::new()).unwrap();
+    let batch = reader.next().unwrap().unwrap();
+
+    // Then
+    let array_vals = batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<Int32Array>()
+        .unwrap();
+    assert_eq!([2, 3], *array_vals.values());
+}
+
+/// Statements not producing a result set are reported as an error
+#[test]
+fn read_from_sql_without_result_set() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["INTEGER"]).unwrap();
+
+    let sql = format!("INSERT INTO {table_name} (a) VALUES (1)");
+    let result = read(&conn, &sql, (), &OdbcReaderBuilder::new());
+
+    assert!(matches!(result, Err(Error::NoResultSet { .. })));
+}
+
+/// A failing later statement of a batch is reported together with its index
+#[test]
+fn next_result_set_reports_failing_statement() {
+    // Given a batch, whose third statement fails
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    let cursor = conn
+        .execute(
+            "SELECT 1 AS A; SELECT 2 AS B INTO #failing_statement_temp; \
+            RAISERROR('Failing statement', 16, 1);",
+            (),
+        )
+        .unwrap()
+        .unwrap();
+
+    // When advancing past the first result set
+    let mut reader = OdbcReaderBuilder::new().build(cursor).unwrap();
+    let first = reader.next().unwrap().unwrap();
+    let result = reader.next_result_set();
+
+    // Then the batch of the first result set is still intact and the error names the statement
+    assert_eq!(1, first.num_rows());
+    assert!(matches!(
+        result,
+        Err(Error::MoreResults {
+            statement_index: 2,
+            ..
+        })
+    ));
+}
+
+/// Chain result sets without manually juggling cursors. Result free statements are skipped.
+#[test]
+fn read_next_result_set() {
+    // Given a batch of three SQL statements, the second being result-free
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    let cursor = conn
+        .execute(
+            "SELECT 1 AS A; SELECT 2 AS B INTO #next_result_set_temp; \
+            SELECT 'three' AS C FROM #next_result_set_temp;",
+            (),
+        )
+        .unwrap()
+        .unwrap();
+
+    // When
+    let mut reader = OdbcReaderBuilder::new().build(cursor).unwrap();
+    let first = reader.next().unwrap().unwrap();
+    let mut reader = reader.next_result_set().unwrap().unwrap();
+    let second = reader.next().unwrap().unwrap();
+    let after_last = reader.next_result_set().unwrap();
+
+    // Then
+    let first_vals = first
+        .column(0)
+        .as_any()
+        .downcast_ref::<Int32Array>()
+        .unwrap();
+    assert_eq!(1, first_vals.value(0));
+    let second_vals = second
+        .column(0)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap();
+    assert_eq!("three", second_vals.value(0));
+    assert!(after_last.is_none());
+}
+
+/// Describe the mapping of relational types to arrow and whether it is lossless
+#[test]
+fn describe_mapping_fidelity() {
+    // Given a result set with a decimal, a time, a timestamp with seven fractional digits and a
+    // timestamp with offset
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    let mut cursor = conn
+        .execute(
+            "SELECT CAST(1.5 AS DECIMAL(5,2)) AS a, CAST('12:00' AS TIME) AS b, \
+            CAST('2020-01-01' AS DATETIME2) AS c, \
+            CAST('2020-01-01 12:00 +02:00' AS DATETIMEOFFSET) AS d",
+            (),
+        )
+        .unwrap()
+        .unwrap();
+
+    // When
+    let mapping = describe_mapping(&mut cursor).unwrap();
+
+    // Then
+    let summary: Vec<_> = mapping
+        .iter()
+        .map(|column| (column.name.as_str(), &column.arrow_type, column.fidelity))
+        .collect();
+    assert_eq!(
+        vec![
+            ("a", &DataType::Decimal128(5, 2), Fidelity::Lossless),
+            ("b", &DataType::Utf8, Fidelity::Text),
+            (
+                "c",
+                &DataType::Timestamp(TimeUnit::Nanosecond, None),
+                Fidelity::RangeLimited
+            ),
+            (
+                "d",
+                &DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+                Fidelity::Lossy
+            ),
+        ],
+        summary
+    );
+}
+
+/// Report the relational types and nullability as reported by the driver
+#[test]
+fn odbc_schema_of_table() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a table with a nullable integer and a non nullable real column
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["INTEGER", "REAL NOT NULL"]).unwrap();
+    let sql = format!("SELECT a, b FROM {table_name}");
+    let mut prepared = conn.prepare(&sql).unwrap();
+
+    // When
+    let columns = odbc_schema_from(&mut prepared).unwrap();
+
+    // Then
+    assert_eq!(
+        vec![
+            ("a".to_owned(), OdbcDataType::Integer, true),
+            ("b".to_owned(), OdbcDataType::Real, false),
+        ],
+        columns
+    );
+}
+
+/// Refuse to bind more columns than allowed, with a clear error
+#[test]
+fn too_many_columns_to_bind() {
+    // Given a result set with three columns
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    let cursor = conn
+        .execute("SELECT 1 AS a, 2 AS b, 3 AS c", ())
+        .unwrap()
+        .unwrap();
+
+    // When allowing at most two bound columns
+    let result = OdbcReaderBuilder::new()
+        .with_max_bound_columns(2)
+        .build(cursor);
+
+    // Then
+    assert!(matches!(
+        result,
+        Err(Error::TooManyColumns {
+            num_columns: 3,
+            max_bound_columns: 2
+        })
+    ));
+}
+
+/// Duplicate column names, e.g. from a join, are made unique in the inferred schema
+#[test]
+fn dedup_column_names() {
+    // Given a result set with two columns named `id`
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    let cursor = conn
+        .execute("SELECT 1 AS id, 2 AS id", ())
+        .unwrap()
+        .unwrap();
+
+    // When
+    let reader = OdbcReaderBuilder::new()
+        .with_dedup_column_names(true)
+        .build(cursor)
+        .unwrap();
+
+    // Then
+    let schema = reader.schema();
+    assert_eq!("id", schema.field(0).name());
+    assert_eq!("id_1", schema.field(1).name());
+}
+
+/// Names of inferred fields can be normalized, e.g. to snake case
+#[test]
+fn transform_column_names() {
+    // Given a result set with a column named in camel case
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    let cursor = conn.execute("SELECT 1 AS MyColumn", ()).unwrap().unwrap();
+
+    // When
+    let reader = OdbcReaderBuilder::new()
+        .with_column_name_transform(|name| {
+            let mut snake_case = String::new();
+            for (index, c) in name.chars().enumerate() {
+                if c.is_uppercase() && index != 0 {
+                    snake_case.push('_');
+                }
+                snake_case.extend(c.to_lowercase());
+            }
+            snake_case
+        })
+        .build(cursor)
+        .unwrap();
+
+    // Then
+    assert_eq!("my_column", reader.schema().field(0).name());
+}
+
+#[test]
+fn read_multiple_result_sets_with_second_no_schema() {
+    // Given a batch of three SQL statements, the second being result-free
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    let cursor = conn
+        .execute(
+            "SELECT 1 AS A; SELECT 1 AS A INTO #local_temp_table; SELECT A FROM #local_temp_table;",
+            (),
+        )
+        .unwrap()
+        .unwrap();
+
+    // When
+    // Now that we have a cursor, we want to iterate over its rows and fill an arrow batch with it.
+    let mut reader = OdbcReaderBuilder::new()
+        .with_max_num_rows_per_batch(1)
+        .build(cursor)
+        .unwrap();
+    let first = reader.next().unwrap().unwrap();
+    let cursor = reader.into_cursor().unwrap();
+    let cursor = cursor.more_results().unwrap().unwrap();
+    // Now that we have a cursor, we want to iterate over its rows and fill an arrow batch with it.
+    let reader = OdbcReaderBuilder::new()
+        .with_max_num_rows_per_batch(1)
+        .build(cursor)
+        .unwrap();
+    let second_schema = reader.schema();
+
+    let cursor = reader.into_cursor().unwrap();
+    let cursor = cursor.more_results().unwrap().unwrap();
+    // Now that we have a cursor, we want to iterate over its rows and fill an arrow batch with it.
+    let mut reader = OdbcReaderBuilder::new()
+        .with_max_num_rows_per_batch(1)
+        .build(cursor)
+        .unwrap();
+    let third = reader.next().unwrap().unwrap();
+
+    // Then
+    let first_vals = first
+        .column(0)
+        .as_any()
+        .downcast_ref::<Int32Array>()
+        .unwrap();
+    assert_eq!(1, first_vals.value(0));
+    let second_column_count = second_schema.fields().len();
+    assert_eq!(0, second_column_count);
+    let third_vals = third
+        .column(0)
+        .as_any()
+        .downcast_ref::<Int32Array>()
+        .unwrap();
+    assert_eq!(1, third_vals.value(0));
+}
+
+/// A result set without columns binds no buffer and yields no batches
+#[test]
+fn reader_over_result_set_without_columns() {
+    // Given a cursor positioned on the result of a statement without result set
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    let cursor = conn
+        .execute(
+            "SELECT 1 AS A; SELECT 2 AS A INTO #local_temp_table; SELECT A FROM #local_temp_table;",
+            (),
+        )
+        .unwrap()
+        .unwrap();
+    let cursor = cursor.more_results().unwrap().unwrap();
+
+    // When
+    let mut reader = OdbcReaderBuilder::new().build(cursor).unwrap();
+
+    // Then
+    assert_eq!(0, reader.schema().fields().len());
+    assert_eq!(0, reader.buffer_size_in_bytes());
+    assert!(reader.next().is_none());
+    // The cursor can still be advanced to the following result set
+    let cursor = reader.into_cursor().unwrap();
+    let cursor = cursor.more_results().unwrap().unwrap();
+    let mut reader = OdbcReaderBuilder::new().build(cursor).unwrap();
+    let batch = reader.next().unwrap().unwrap();
+    let vals = batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<Int32Array>()
+        .unwrap();
+    assert_eq!(2, vals.value(0));
+}
+
+#[test]
+fn infer_all_columns_as_nullable() {
+    // Given a cursor over a column which is not nullable
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let cursor = cursor_over(table_name, "INTEGER NOT NULL", "(42)");
+
+    // When
+    let mut reader = OdbcReaderBuilder::new()
+        .with_all_columns_nullable(true)
+        .build(cursor)
+        .unwrap();
+
+    // Then the field is nullable, and the values are fetched nevertheless
+    assert!(reader.schema().field(0).is_nullable());
+    let batch = reader.next().unwrap().unwrap();
+    let array = batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<Int32Array>()
+        .unwrap();
+    assert_eq!(42, array.value(0));
+    assert_eq!(0, array.null_count());
+}
+
+#[test]
+fn applies_row_limit_for_default_constructed_readers() {
+    // Given a cursor over a datascheme with a small per row memory footprint
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let cursor = cursor_over(table_name, "INTEGER", "(42)");
+
+    // When constructing a reader from that cursor without specifying an explicity memory or row
+    // limit
+    let reader = OdbcReaderBuilder::new().build(cursor).unwrap();
+
+    // Then the row limit is set to u16::MAX
+    assert_eq!(reader.max_rows_per_batch(), 65535)
+}
+
+#[test]
+fn report_buffer_size_in_bytes() {
+    // Given a cursor over two integer columns
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["INTEGER NOT NULL", "BIGINT"]).unwrap();
+    let sql = format!("SELECT a, b FROM {table_name}");
+    let cursor = conn.execute(&sql, ()).unwrap().unwrap();
+
+    // When
+    let reader = OdbcReaderBuilder::new()
+        .with_max_num_rows_per_batch(100)
+        .build(cursor)
+        .unwrap();
+
+    // Then the buffer size is the number of rows times the bytes of both columns per row
+    let bytes_per_row = BufferDesc::I32 { nullable: false }.bytes_per_row()
+        + BufferDesc::I64 { nullable: true }.bytes_per_row();
+    assert_eq!(100 * bytes_per_row, reader.buffer_size_in_bytes());
+}
+
+/// Inspecting the schema leaves the cursor unbound, so it can still be used to build a reader
+#[test]
+fn infer_schema_before_build() {
+    // Given
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let mut cursor = cursor_over(table_name, "INTEGER NOT NULL", "(42)");
+    let builder = OdbcReaderBuilder::new();
+
+    // When
+    let schema = builder.infer_schema(&mut cursor).unwrap();
+    let mut reader = builder.build(cursor).unwrap();
+
+    // Then
+    assert_eq!(&DataType::Int32, schema.field(0).data_type());
+    assert_eq!(schema, reader.schema());
+    let batch = reader.next().unwrap().unwrap();
+    let values = batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<Int32Array>()
+        .unwrap();
+    assert_eq!([42], values.values().as_ref());
+}
+
+#[test]
+fn applies_memory_size_limit() {
+    // Given a cursor over a datascheme with a small per row memory footprint
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let cursor = cursor_over(table_name, "VARCHAR(512)", "('Hello')");
+
+    // When constructing a reader from that cursor without specifying an explicity memory or row
+    // limit
+    let reader = OdbcReaderBuilder::new()
+        // Limit buffer to 10 MiB
+        .with_max_bytes_per_batch(10 * 1024 * 1024)
+        .build(cursor)
+        .unwrap();
+
+    // Buffer now holds less than 65535 rows due to size limit
+    assert!(reader.max_rows_per_batch() < 65535)
+}
+
+#[test]
+fn memory_size_limit_can_not_hold_a_single_row() {
+    // Given a cursor over a datascheme with a small per row memory footprint
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let cursor = cursor_over(table_name, "VARCHAR(512)", "('Hello')");
+
+    // When constructing a reader from that cursor without specifying an explicity memory or row
+    // limit
+    let result = OdbcReaderBuilder::new()
+        // Limit buffer to 1 Byte
+        .with_max_bytes_per_batch(1)
+        .build(cursor);
+
+    // Then
+    assert!(matches!(
+        result,
+        Err(Error::OdbcBufferTooSmall {
+            max_bytes_per_batch: 1,
+            bytes_per_row: _
+        })
+    ))
+}
+
+#[test]
+fn insert_does_not_support_list_type() {
+    // Given a table and a db connection.
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["VARCHAR(4096)"]).unwrap();
+
+    // When we try to create an OdbcWriter inserting an Arrow List
+    let schema = Arc::new(Schema::new(vec![Field::new(
+        "a",
+        DataType::List(Arc::new(Field::new("b", DataType::Utf8, true))),
+        true,
+    )]));
+
+    let insert = format!("INSERT INTO {table_name} (a) VALUES (?)");
+    let prepared = conn.prepare(&insert).unwrap();
+    let result = OdbcWriter::new(10, schema.as_ref(), prepared);
+
+    // Then we recive an unsupported error
+    assert!(matches!(
+        result,
+        Err(WriterError::UnsupportedArrowDataType(_))
+    ))
+}
+
+#[test]
+fn insert_text() {
+    // Given a table and a record batch reader returning a batch with a text column.
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["VARCHAR(4096)"]).unwrap();
+    let array = StringArray::from(vec![Some("Hello"), None, Some("World")]);
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Utf8, true)]));
+    let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(array)]).unwrap();
+    let mut reader = StubBatchReader::new(schema, vec![batch]);
+
+    // When
+    insert_into_table(&conn, &mut reader, table_name, 5).unwrap();
 
-    assert_eq!(
-        "External error: Timestamp is not representable in arrow: 2300-01-01 00:00:00.123456700\n\
-        Timestamps with nanoseconds precision are represented using a signed 64 Bit integer. This \
-        limits their range to values between 1677-09-21 00:12:44 and \
-        2262-04-11 23:47:16.854775807. The value returned from the database is outside of this \
-        range. Suggestions to fix this error either reduce the precision or fetch the values as \
-        text.",
-        error.to_string()
-    )
+    // Then
+    let actual = table_to_string(&conn, table_name, &["a"]);
+    let expected = "Hello\nNULL\nWorld";
+    assert_eq!(expected, actual);
 }
 
-/// Precision 7 timestamps need to be mapped to nanoseconds. Nanoseconds timestamps have a valid
-/// range in arrow between 1677-09-21 00:12:44 and 2262-04-11 23:47:16.854775807 due to be
-/// represented as a signed 64Bit Integer. Default behaviour is to emit an error. In this case we
-/// want to map such values to NULL though.
+/// Apply a transformation to a column before its values are written into the database
 #[test]
-fn map_out_of_range_date_time_to_null() {
+fn insert_text_with_column_transform() {
+    // Given a table and a record batch with a text column.
     let table_name = function_name!().rsplit_once(':').unwrap().1;
-    let cursor = cursor_over(
-        table_name,
-        "DATETIME2 NOT NULL",
-        "('2300-01-01 00:00:00.1234567'),('2002-09-30 12:43:17.456')",
-    );
-
-    // Now that we have a cursor, we want to iterate over its rows and fill an arrow batch with it.
-    let mut reader = OdbcReaderBuilder::new()
-        .with_max_num_rows_per_batch(100)
-        .value_errors_as_null(true)
-        .build(cursor)
-        .unwrap()
-        .into_concurrent()
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
         .unwrap();
-    // Batch for batch copy values from ODBC buffer into arrow batches
-    let record_batch = reader.next().unwrap().unwrap();
-    let array = record_batch.column(0).clone();
+    setup_empty_table(&conn, table_name, &["VARCHAR(50)"]).unwrap();
+    let array = StringArray::from(vec![Some("Hello"), None, Some("World")]);
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Utf8, true)]));
+    let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(array)]).unwrap();
 
-    // Assert that the correct values are found within the arrow batch
-    let array_vals = array
-        .as_any()
-        .downcast_ref::<TimestampNanosecondArray>()
+    // When inserting it with a transformation uppercasing the text
+    let mut writer = OdbcWriter::with_connection(&conn, &schema, table_name, 5).unwrap();
+    writer
+        .with_column_transform(
+            "a",
+            Box::new(|array: &dyn Array| {
+                let strings = array.as_any().downcast_ref::<StringArray>().unwrap();
+                let upper: StringArray = strings
+                    .iter()
+                    .map(|opt| opt.map(str::to_uppercase))
+                    .collect();
+                Ok(Arc::new(upper) as ArrayRef)
+            }),
+        )
         .unwrap();
-    assert!(array_vals.is_null(0));
-    assert_eq!(
-        Some(
-            NaiveDate::from_ymd_opt(2002, 9, 30)
-                .unwrap()
-                .and_hms_nano_opt(12, 43, 17, 456_000_000)
-                .unwrap()
-        ),
-        array_vals.value_as_datetime(1)
-    );
+    writer.write_batch(&batch).unwrap();
+    writer.flush().unwrap();
+
+    // Then
+    let actual = table_to_string(&conn, table_name, &["a"]);
+    let expected = "HELLO\nNULL\nWORLD";
+    assert_eq!(expected, actual);
 }
 
-/// Fill a record batch of Decimals
+/// Insert timestamps as integers counting milliseconds since epoch
 #[test]
-fn fetch_decimals() {
-    // Given a cursor over a table with one decimal column
+fn insert_timestamp_as_epoch() {
+    // Given a BIGINT column and a batch with millisecond timestamps
     let table_name = function_name!().rsplit_once(':').unwrap().1;
-    let cursor = cursor_over(table_name, "DECIMAL(5,2) NOT NULL", "(123.45),(678.90)");
-
-    // When fetching it in batches of five
-    let mut reader = OdbcReaderBuilder::new()
-        .with_max_num_rows_per_batch(5)
-        .build(cursor)
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
         .unwrap();
-    let record_batch = reader.next().unwrap().unwrap();
+    setup_empty_table(&conn, table_name, &["BIGINT"]).unwrap();
+    let schema = Arc::new(Schema::new(vec![Field::new(
+        "a",
+        DataType::Timestamp(TimeUnit::Millisecond, None),
+        true,
+    )]));
+    let array = TimestampMillisecondArray::from(vec![Some(1_700_000_000_123), None, Some(-1)]);
+    let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(array)]).unwrap();
 
-    // Then the elements in the first column of the first batch must match the decimals in the
-    // database.
-    let column = record_batch.column(0).clone();
-    let array_vals = column.as_any().downcast_ref::<Decimal128Array>().unwrap();
+    // When inserting them as epoch in seconds
+    let mut writer = OdbcWriterBuilder::new()
+        .with_timestamp_as_epoch(TimeUnit::Second)
+        .build_with_connection(&conn, &schema, table_name, 10)
+        .unwrap();
+    writer.write_batch(&batch).unwrap();
+    writer.flush().unwrap();
 
-    // Assert that the correct values are found within the arrow batch
-    assert_eq!("123.45", array_vals.value_as_string(0));
-    assert_eq!("678.90", array_vals.value_as_string(1));
+    // Then values are rescaled and rounded down
+    let actual = table_to_string(&conn, table_name, &["a"]);
+    let expected = "1700000000\nNULL\n-1";
+    assert_eq!(expected, actual);
 }
 
-/// Ensure we do not drop sign in Decimal parsing
+/// Timestamps with a timezone are inserted as the units passed since epoch in UTC
 #[test]
-fn fetch_negative_decimal() {
-    // Given a cursor over a table with one decimal column
+fn insert_timestamp_with_timezone_as_epoch() {
+    // Given a BIGINT column and a batch with second timestamps in a timezone other than UTC
     let table_name = function_name!().rsplit_once(':').unwrap().1;
-    let cursor = cursor_over(table_name, "DECIMAL(5,2) NOT NULL", "(-123.45)");
-
-    // When fetching it in batches of five
-    let mut reader = OdbcReaderBuilder::new()
-        .with_max_num_rows_per_batch(5)
-        .build(cursor)
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
         .unwrap();
-    let record_batch = reader.next().unwrap().unwrap();
+    setup_empty_table(&conn, table_name, &["BIGINT"]).unwrap();
+    let schema = Arc::new(Schema::new(vec![Field::new(
+        "a",
+        DataType::Timestamp(TimeUnit::Second, Some("+02:00".into())),
+        true,
+    )]));
+    let array = TimestampSecondArray::from(vec![Some(1_700_000_000), None]).with_timezone("+02:00");
+    let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(array)]).unwrap();
 
-    // Then the elements in the first column of the first batch must match the decimals in the
-    // database.
-    let column = record_batch.column(0).clone();
-    let array_vals = column.as_any().downcast_ref::<Decimal128Array>().unwrap();
+    // When inserting them as epoch in milliseconds
+    let mut writer = OdbcWriterBuilder::new()
+        .with_timestamp_as_epoch(TimeUnit::Millisecond)
+        .build_with_connection(&conn, &schema, table_name, 10)
+        .unwrap();
+    writer.write_batch(&batch).unwrap();
+    writer.flush().unwrap();
 
-    // Assert that the correct values are found within the arrow batch
-    assert_eq!("-123.45", array_vals.value_as_string(0));
+    // Then the values are not shifted by the offset of the timezone
+    let actual = table_to_string(&conn, table_name, &["a"]);
+    let expected = "1700000000000\nNULL";
+    assert_eq!(expected, actual);
 }
 
-/// Fetch variable sized binary data binary data
+/// Insert rows using a statement with a multi row VALUES clause instead of array parameters
 #[test]
-fn fetch_varbinary_data() {
+fn insert_with_multi_row_values() {
+    // Given a table and a record batch with five rows
     let table_name = function_name!().rsplit_once(':').unwrap().1;
-
-    // Setup a table on the database with some values (so we can fetch them)
     let conn = ENV
         .connect_with_connection_string(MSSQL, Default::default())
         .unwrap();
-    setup_empty_table(&conn, table_name, &["VARBINARY(30) NOT NULL"]).unwrap();
-    let sql = format!("INSERT INTO {table_name} (a) VALUES (?)");
-    // Use prepared query and arguments for insertion, since literal representation depends a lot
-    // on the DB under test.
-    let mut insert = conn.prepare(&sql).unwrap();
-    insert.execute(&b"Hello".into_parameter()).unwrap();
-    insert.execute(&b"World".into_parameter()).unwrap();
-
-    // Query column with values to get a cursor
-    let sql = format!("SELECT a FROM {table_name} ORDER BY id");
-    let cursor = conn.execute(&sql, ()).unwrap().unwrap();
-
-    // Now that we have a cursor, we want to iterate over its rows and fill an arrow batch with it.
-    let mut reader = OdbcReaderBuilder::new()
-        // Batches will contain at most 100 entries.
-        .with_max_num_rows_per_batch(100)
-        // Instantiate reader with Arrow schema and ODBC cursor
-        .build(cursor)
-        .unwrap();
+    setup_empty_table(&conn, table_name, &["INTEGER", "VARCHAR(10)"]).unwrap();
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]));
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            Arc::new(Int32Array::from(vec![
+                Some(1),
+                Some(2),
+                None,
+                Some(4),
+                Some(5),
+            ])),
+            Arc::new(StringArray::from(vec![
+                Some("one"),
+                None,
+                Some("three"),
+                Some("four"),
+                Some("five"),
+            ])),
+        ],
+    )
+    .unwrap();
 
-    // Batch for batch copy values from ODBC buffer into arrow batches
-    let arrow_batch = reader.next().unwrap().unwrap();
+    // When inserting them with two rows per statement
+    let mut writer = OdbcWriter::with_multi_row_values(&conn, &schema, table_name, 2).unwrap();
+    writer.write_batch(&batch).unwrap();
+    writer.flush().unwrap();
 
-    // Assert that the correct values are found within the arrow batch
-    let array_vals = arrow_batch
-        .column(0)
-        .as_any()
-        .downcast_ref::<BinaryArray>()
-        .unwrap();
-    assert_eq!(b"Hello", array_vals.value(0));
-    assert_eq!(b"World", array_vals.value(1));
+    // Then all rows, including the remainder, are in the table
+    let actual = table_to_string(&conn, table_name, &["a", "b"]);
+    let expected = "1,one\n2,NULL\nNULL,three\n4,four\n5,five";
+    assert_eq!(expected, actual);
 }
 
-/// Fetch fixed sized binary data binary data
+/// Reduce the number of rows per statement, so the parameters do not exceed the limit
 #[test]
-fn fetch_fixed_sized_binary_data() {
+fn multi_row_values_within_parameter_limit() {
+    // Given a table and a record batch with five rows
     let table_name = function_name!().rsplit_once(':').unwrap().1;
-
-    // Setup a table on the database with some values (so we can fetch them)
     let conn = ENV
         .connect_with_connection_string(MSSQL, Default::default())
         .unwrap();
-    setup_empty_table(&conn, table_name, &["BINARY(5) NOT NULL"]).unwrap();
-    let sql = format!("INSERT INTO {table_name} (a) VALUES (?)");
-    // Use prepared query and arguments for insertion, since literal representation depends a lot
-    // on the DB under test.
-    let mut insert = conn.prepare(&sql).unwrap();
-    insert.execute(&b"Hello".into_parameter()).unwrap();
-    insert.execute(&b"World".into_parameter()).unwrap();
-
-    // Query column with values to get a cursor
-    let sql = format!("SELECT a FROM {table_name} ORDER BY id");
-    let cursor = conn.execute(&sql, ()).unwrap().unwrap();
+    setup_empty_table(&conn, table_name, &["INTEGER", "INTEGER"]).unwrap();
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b", DataType::Int32, false),
+    ]));
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            Arc::new(Int32Array::from(vec![1, 2, 3, 4, 5])),
+            Arc::new(Int32Array::from(vec![6, 7, 8, 9, 10])),
+        ],
+    )
+    .unwrap();
 
-    // Now that we have a cursor, we want to iterate over its rows and fill an arrow batch with it.
-    let mut reader = OdbcReaderBuilder::new()
-        // Batches will contain at most 100 entries.
-        .with_max_num_rows_per_batch(100)
-        // Instantiate reader with Arrow schema and ODBC cursor
-        .build(cursor)
+    // When requesting five rows per statement, with a limit of four parameters
+    let mut writer = OdbcWriterBuilder::new()
+        .with_parameter_limit(4)
+        .build_with_multi_row_values(&conn, &schema, table_name, 5)
         .unwrap();
+    writer.write_batch(&batch).unwrap();
+    writer.flush().unwrap();
 
-    // Batch for batch copy values from ODBC buffer into arrow batches
-    let arrow_batch = reader.next().unwrap().unwrap();
-
-    // Assert that the correct values are found within the arrow batch
-    let array_vals = arrow_batch
-        .column(0)
-        .as_any()
-        .downcast_ref::<FixedSizeBinaryArray>()
-        .unwrap();
-    assert_eq!(b"Hello", array_vals.value(0));
-    assert_eq!(b"World", array_vals.value(1));
+    // Then all rows are inserted nevertheless
+    let actual = table_to_string(&conn, table_name, &["a", "b"]);
+    let expected = "1,6\n2,7\n3,8\n4,9\n5,10";
+    assert_eq!(expected, actual);
 }
 
-/// Like [`fetch_32bit_floating_point`], but utilizing a prepared query instead of a one shot.
+/// Reuse the same writer to insert into two tables with identical columns
 #[test]
-fn prepared_query() {
+fn retarget_writer_to_another_table() {
+    // Given two tables with the same columns
     let table_name = function_name!().rsplit_once(':').unwrap().1;
-
-    // Setup a table on the database with some floats (so we can fetch them)
+    let table_a = format!("{table_name}_a");
+    let table_b = format!("{table_name}_b");
     let conn = ENV
         .connect_with_connection_string(MSSQL, Default::default())
         .unwrap();
-    setup_empty_table(&conn, table_name, &["REAL NOT NULL"]).unwrap();
-    let sql = format!("INSERT INTO {table_name} (a) VALUES (1),(2),(3)");
-    conn.execute(&sql, ()).unwrap();
+    setup_empty_table(&conn, &table_a, &["INTEGER", "VARCHAR(10)"]).unwrap();
+    setup_empty_table(&conn, &table_b, &["INTEGER", "VARCHAR(10)"]).unwrap();
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]));
+    let batch_a = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            Arc::new(Int32Array::from(vec![Some(1), None])),
+            Arc::new(StringArray::from(vec![Some("one"), Some("two")])),
+        ],
+    )
+    .unwrap();
+    let batch_b = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            Arc::new(Int32Array::from(vec![3])),
+            Arc::new(StringArray::from(vec![None::<&str>])),
+        ],
+    )
+    .unwrap();
 
-    // Query column with values to get a cursor
-    let sql = format!("SELECT a FROM {table_name}");
-    let mut prepared = conn.prepare(&sql).unwrap();
-    let cursor = prepared.execute(()).unwrap().unwrap();
+    // When inserting one batch into each table, retargeting the writer in between without
+    // flushing explicitly
+    let mut writer = OdbcWriter::with_connection(&conn, &schema, &table_a, 10).unwrap();
+    writer.write_batch(&batch_a).unwrap();
+    writer.retarget(&conn, &table_b).unwrap();
+    writer.write_batch(&batch_b).unwrap();
+    writer.flush().unwrap();
+
+    // Then each table holds its own rows
+    let actual_a = table_to_string(&conn, &table_a, &["a", "b"]);
+    let actual_b = table_to_string(&conn, &table_b, &["a", "b"]);
+    assert_eq!("1,one\nNULL,two", actual_a);
+    assert_eq!("3,NULL", actual_b);
+}
 
-    // Now that we have a cursor, we want to iterate over its rows and fill an arrow batch with it.
-    let mut reader = OdbcReaderBuilder::new()
-        // Batches will contain at most 100 entries.
-        .with_max_num_rows_per_batch(100)
-        // Instantiate reader with Arrow schema and ODBC cursor
-        .build(cursor)
+/// Retargeting a writer to a table missing a column of the schema is reported as an error
+#[test]
+fn retarget_writer_to_incompatible_table() {
+    // Given a table lacking the column `b`
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let table_a = format!("{table_name}_a");
+    let table_b = format!("{table_name}_b");
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
         .unwrap();
+    setup_empty_table(&conn, &table_a, &["INTEGER", "INTEGER"]).unwrap();
+    setup_empty_table(&conn, &table_b, &["INTEGER"]).unwrap();
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b", DataType::Int32, false),
+    ]));
+    let mut writer = OdbcWriter::with_connection(&conn, &schema, &table_a, 10).unwrap();
 
-    // Batch for batch copy values from ODBC buffer into arrow batches
-    let arrow_batch = reader.next().unwrap().unwrap();
+    // When
+    let result = writer.retarget(&conn, &table_b);
 
-    // Assert that the correct values are found within the arrow batch
-    let array_vals = arrow_batch
-        .column(0)
-        .as_any()
-        .downcast_ref::<Float32Array>()
-        .unwrap();
-    assert_float_eq!([1., 2., 3.][..], array_vals.values(), abs_all <= 000.1);
+    // Then
+    assert!(matches!(
+        result,
+        Err(WriterError::DescribeParameters { .. })
+    ));
 }
 
+/// A writer inserting into explicitly named columns keeps targeting these columns after
+/// retargeting
 #[test]
-fn infer_schema() {
+fn retarget_writer_for_columns() {
+    // Given two tables and a schema whose field names differ from the column names
     let table_name = function_name!().rsplit_once(':').unwrap().1;
-
-    // Setup a table on the database with some floats (so we can fetch them)
+    let table_a = format!("{table_name}_a");
+    let table_b = format!("{table_name}_b");
     let conn = ENV
         .connect_with_connection_string(MSSQL, Default::default())
         .unwrap();
-    setup_empty_table(&conn, table_name, &["REAL NOT NULL"]).unwrap();
+    setup_empty_table(&conn, &table_a, &["INTEGER", "INTEGER"]).unwrap();
+    setup_empty_table(&conn, &table_b, &["INTEGER", "INTEGER"]).unwrap();
+    let schema = Arc::new(Schema::new(vec![Field::new(
+        "number",
+        DataType::Int32,
+        false,
+    )]));
+    let batch = |value: i32| {
+        RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(Int32Array::from(vec![value]))],
+        )
+        .unwrap()
+    };
 
-    // Prepare query to get metadata
-    let sql = format!("SELECT a FROM {table_name}");
-    let cursor = conn.execute(&sql, ()).unwrap().unwrap();
+    // When inserting the field into column `b` of both tables
+    let mut writer =
+        OdbcWriter::with_connection_for_columns(&conn, &schema, &table_a, &["b"], 5).unwrap();
+    writer.write_batch(&batch(1)).unwrap();
+    writer.retarget(&conn, &table_b).unwrap();
+    writer.write_batch(&batch(2)).unwrap();
+    writer.flush().unwrap();
 
-    // Now that we have a cursor, we want to iterate over its rows and fill an arrow batch with it.
-    let reader = OdbcReaderBuilder::new()
-        .with_max_num_rows_per_batch(1)
-        // Instantiate reader with Arrow schema and ODBC cursor
-        .build(cursor)
+    // Then
+    let actual_a = table_to_string(&conn, &table_a, &["a", "b"]);
+    let actual_b = table_to_string(&conn, &table_b, &["a", "b"]);
+    assert_eq!("NULL,1", actual_a);
+    assert_eq!("NULL,2", actual_b);
+}
+
+/// A writer created from a statement of the application can not derive a statement for another
+/// table
+#[test]
+fn retarget_writer_with_user_statement() {
+    // Given a writer created from a prepared statement
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let table_a = format!("{table_name}_a");
+    let table_b = format!("{table_name}_b");
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
         .unwrap();
+    setup_empty_table(&conn, &table_a, &["INTEGER"]).unwrap();
+    setup_empty_table(&conn, &table_b, &["INTEGER"]).unwrap();
+    let schema = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
+    let prepared = conn
+        .prepare(&format!("INSERT INTO {table_a} (a) VALUES (?)"))
+        .unwrap();
+    let mut writer = OdbcWriter::new(10, &schema, prepared).unwrap();
 
-    let actual = reader.schema();
-    let expected = Arc::new(Schema::new(vec![Field::new("a", DataType::Float32, false)]));
+    // When
+    let result = writer.retarget(&conn, &table_b);
 
-    assert_eq!(expected, actual)
+    // Then
+    assert!(matches!(result, Err(WriterError::RetargetUserStatement)));
 }
 
+/// A schema with more columns than parameters allowed per statement is reported as such
 #[test]
-fn fetch_schema_for_table() {
+fn too_many_columns_for_parameter_limit() {
+    // Given a table with two columns
     let table_name = function_name!().rsplit_once(':').unwrap().1;
-
-    // Setup a table on the database with some floats (so we can fetch them)
     let conn = ENV
         .connect_with_connection_string(MSSQL, Default::default())
         .unwrap();
-    setup_empty_table(&conn, table_name, &["REAL NOT NULL"]).unwrap();
-
-    // Prepare query to get metadata
-    let sql = format!("SELECT a FROM {table_name}");
-    let mut prepared = conn.prepare(&sql).unwrap();
+    setup_empty_table(&conn, table_name, &["INTEGER", "INTEGER"]).unwrap();
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b", DataType::Int32, false),
+    ]));
 
-    // Now that we have prepared statement, we want to use it to query metadata.
-    let schema = arrow_schema_from(&mut prepared, false).unwrap();
+    // When creating a writer with a limit of one parameter per statement
+    let result = OdbcWriterBuilder::new()
+        .with_parameter_limit(1)
+        .build_with_connection(&conn, &schema, table_name, 1000);
 
-    assert_eq!(
-        "Field { \
-            name: \"a\", \
-            data_type: Float32, \
-            nullable: false, \
-            dict_id: 0, \
-            dict_is_ordered: false, \
-            metadata: {} \
-        }",
-        schema.to_string()
-    )
+    // Then
+    assert!(matches!(
+        result,
+        Err(WriterError::ParameterLimitExceeded {
+            limit: 1,
+            requested: 2
+        })
+    ));
 }
 
-/// Allocating octet length bytes is not enough if the column on the database is encoded in UTF-16
-/// since all codepoints in range from U+0800 to U+FFFF take three bytes in UTF-8 but only two bytes
-/// in UTF-16. We test this with the 'Trade Mark Sign' (`™`) (U+2122).
-///
-/// For this test to be meaningful it must run on a Linux platform, since only then we do query the
-/// wide column with a narrow buffer and therfore convert UTF-16 to UTF-8 within the dbms.
+/// Report differences between an arrow schema and the table it is supposed to be inserted into
 #[test]
-fn should_allocate_enough_memory_for_wchar_column_bound_to_u8() {
+fn check_schema_against_table() {
+    // Given a table with an identity column, an integer and a date column
     let table_name = function_name!().rsplit_once(':').unwrap().1;
-    let cursor = query_single_value(table_name, "NCHAR(1) NOT NULL", "™");
-
-    // Now that we have a cursor, we want to iterate over its rows and fill an arrow batch with it.
-    let mut reader = OdbcReaderBuilder::new()
-        .with_max_num_rows_per_batch(1)
-        // Instantiate reader with Arrow schema and ODBC cursor
-        .build(cursor)
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
         .unwrap();
-    // Batch for batch copy values from ODBC buffer into arrow batches
-    let record_batch = reader.next().unwrap().unwrap();
-    let array_any = record_batch.column(0).clone();
+    setup_empty_table(&conn, table_name, &["INTEGER", "DATE"]).unwrap();
+
+    // When checking a schema with a wider integer, binary data for the date and an additional
+    // column
+    let schema = Schema::new(vec![
+        Field::new("A", DataType::Int64, true),
+        Field::new("b", DataType::Binary, true),
+        Field::new("c", DataType::Utf8, true),
+    ]);
+    let incompatibilities = check_insert_compatibility(&conn, &schema, table_name).unwrap();
 
-    // Assert that the correct values are found within the arrow batch
-    let array_vals = array_any.as_any().downcast_ref::<StringArray>().unwrap();
-    assert_eq!("™", array_vals.value(0));
+    // Then
+    assert_eq!(
+        vec![
+            Incompatibility::TypeMismatch {
+                column: "b".to_owned(),
+                arrow: DataType::Binary,
+                table: DataType::Date32
+            },
+            Incompatibility::MissingColumn("c".to_owned()),
+            Incompatibility::ExtraColumn("id".to_owned()),
+        ],
+        incompatibilities
+    );
 }
 
-/// Allocating octet length bytes is not enough if the column on the database is encoded in UTF-8
-/// since all codepoints in range from U+0000 to U+007F take two bytes in UTF-16 but only one byte
-/// in UTF-8. We test this with the letter a (U+0061).
-///
-/// For this test to be meaningful it must run on a windows platform, since only then we do query the
-/// wide column with a narrow buffer and therfore convert UTF-8 to UTF-16 within the dbms.
+/// The table name is quoted, rather than being interpolated into the query as is
 #[test]
-fn should_allocate_enough_memory_for_varchar_column_bound_to_u16() {
+fn check_insert_compatibility_quotes_table_name() {
+    // Given a table and a table name which would alter the query if it were not quoted
     let table_name = function_name!().rsplit_once(':').unwrap().1;
-    let cursor = query_single_value(table_name, "CHAR(1) NOT NULL", "Ü");
-
-    // Now that we have a cursor, we want to iterate over its rows and fill an arrow batch with it.
-    let mut reader = OdbcReaderBuilder::new()
-        .with_max_num_rows_per_batch(1)
-        // Instantiate reader with Arrow schema and ODBC cursor
-        .build(cursor)
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
         .unwrap();
-    // Batch for batch copy values from ODBC buffer into arrow batches
-    let record_batch = reader.next().unwrap().unwrap();
-    let array_any = record_batch.column(0).clone();
+    setup_empty_table(&conn, table_name, &["INTEGER"]).unwrap();
+    let schema = Schema::new(vec![Field::new("a", DataType::Int32, true)]);
+    let malicious_name = format!("{table_name}; DELETE FROM {table_name}; --");
 
-    // Assert that the correct values are found within the arrow batch
-    let array_vals = array_any.as_any().downcast_ref::<StringArray>().unwrap();
-    assert_eq!("Ü", array_vals.value(0));
+    // When
+    let result = check_insert_compatibility(&conn, &schema, &malicious_name);
+
+    // Then no table of that name exists. The name is not executed as part of the statement.
+    assert!(matches!(result, Err(Error::Execute { .. })));
 }
 
-/// Often than VARCHAR(MAX) is used the actual values in these columns are in the range of 100kb and
-/// not several kb. Sadly if we allocate the buffers, we have to assume the largest possible element
-/// this test verifies that users can specify sensible upper limits using their domain knowledge
-/// about the table in order to prevent out of memory issues.
+/// A single writer can be reused for many streams, without a failed stream leaking rows into the
+/// next one
 #[test]
-fn should_allow_to_fetch_from_varchar_max() {
-    // Given
+fn write_multiple_streams_with_one_writer() {
+    // Given a table and a writer with room for ten rows
     let table_name = function_name!().rsplit_once(':').unwrap().1;
     let conn = ENV
         .connect_with_connection_string(MSSQL, Default::default())
         .unwrap();
-    setup_empty_table(&conn, table_name, &["VARCHAR(MAX)"]).unwrap();
-    let sql = format!("SELECT a FROM {table_name}");
-    let cursor = conn.execute(&sql, ()).unwrap().unwrap();
+    setup_empty_table(&conn, table_name, &["INTEGER"]).unwrap();
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+    let batch = |values: Vec<i32>| {
+        RecordBatch::try_new(schema.clone(), vec![Arc::new(Int32Array::from(values))]).unwrap()
+    };
+    let mut writer = OdbcWriter::with_connection(&conn, &schema, table_name, 10).unwrap();
 
-    // When
-    let result = OdbcReaderBuilder::new()
-        .with_max_num_rows_per_batch(100)
-        .with_max_text_size(1024)
-        .build(cursor);
+    // When writing three streams, the second one failing after its first batch
+    writer
+        .write_stream(vec![Ok(batch(vec![1, 2]))].into_iter())
+        .unwrap();
+    let result = writer.write_stream(
+        vec![
+            Ok(batch(vec![3])),
+            Err(ArrowError::ComputeError("Broken stream".to_owned())),
+        ]
+        .into_iter(),
+    );
+    writer
+        .write_stream(vec![Ok(batch(vec![4]))].into_iter())
+        .unwrap();
 
-    // Then
-    // In particular we do **not** get either a zero sized column or out of memory error.
-    assert!(result.is_ok())
+    // Then the rows of the successful streams are inserted, and the rows of the failed stream
+    // which had not been sent yet are discarded.
+    assert!(matches!(result, Err(WriterError::ReadingRecordBatch(_))));
+    let actual = table_to_string(&conn, table_name, &["a"]);
+    assert_eq!("1\n2\n4", actual);
 }
 
-/// If column limits are too small and truncation occurs, we expect an error to be raised.
+/// Insert booleans into a character column as 'Y' and 'N'
 #[test]
-fn should_error_for_truncation() {
-    // Given a column with one value of length 9
+fn insert_boolean_as_text() {
+    // Given a table with a CHAR(1) column
     let table_name = function_name!().rsplit_once(':').unwrap().1;
     let conn = ENV
         .connect_with_connection_string(MSSQL, Default::default())
         .unwrap();
-    setup_empty_table(&conn, table_name, &["VARCHAR(MAX)"]).unwrap();
-    let sql = format!("INSERT INTO {table_name} (a) VALUES ('123456789')");
-    conn.execute(&sql, ()).unwrap();
-    let sql = format!("SELECT a FROM {table_name}");
-    let cursor = conn.execute(&sql, ()).unwrap().unwrap();
+    setup_empty_table(&conn, table_name, &["CHAR(1)"]).unwrap();
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Boolean, true)]));
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![Arc::new(BooleanArray::from(vec![
+            Some(true),
+            Some(false),
+            None,
+        ]))],
+    )
+    .unwrap();
 
-    // When fetching that value with a text limit of 5
-    let mut reader = OdbcReaderBuilder::new()
-        .with_max_num_rows_per_batch(1)
-        .with_max_text_size(5)
-        .build(cursor)
+    // When inserting booleans using the YN encoding
+    let mut writer = OdbcWriterBuilder::new()
+        .with_boolean_as_text(BoolEncoding::YN)
+        .build_with_connection(&conn, &schema, table_name, 10)
         .unwrap();
-    let result = reader.next().unwrap();
+    writer.write_batch(&batch).unwrap();
+    writer.flush().unwrap();
 
-    // Then we get an error, rather than the truncation only occurring as a warning.
-    assert!(result.is_err())
+    // Then
+    let actual = table_to_string(&conn, table_name, &["a"]);
+    assert_eq!("Y\nN\nNULL", actual);
 }
 
+/// Insert unsigned 32 Bit integers exceeding the range of signed 32 Bit integers
 #[test]
-fn should_allow_to_fetch_from_varbinary_max() {
-    // Given
+fn insert_uint32_into_bigint() {
+    // Given a table with a BIGINT column
     let table_name = function_name!().rsplit_once(':').unwrap().1;
     let conn = ENV
         .connect_with_connection_string(MSSQL, Default::default())
         .unwrap();
-    setup_empty_table(&conn, table_name, &["VARBINARY(MAX)"]).unwrap();
-    let sql = format!("SELECT a FROM {table_name}");
-    let cursor = conn.execute(&sql, ()).unwrap().unwrap();
+    setup_empty_table(&conn, table_name, &["BIGINT"]).unwrap();
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::UInt32, true)]));
+    let array = UInt32Array::from(vec![Some(1), Some(3_000_000_000), None]);
+    let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(array)]).unwrap();
 
     // When
-    let result = OdbcReaderBuilder::new()
-        .with_max_num_rows_per_batch(100)
-        .with_max_binary_size(1024)
-        .build(cursor);
+    let mut writer = OdbcWriter::with_connection(&conn, &schema, table_name, 10).unwrap();
+    writer.write_batch(&batch).unwrap();
+    writer.flush().unwrap();
 
     // Then
-    // In particular we do **not** get either a zero sized column or out of memory error.
-    assert!(result.is_ok())
+    let actual = table_to_string(&conn, table_name, &["a"]);
+    assert_eq!("1\n3000000000\nNULL", actual);
 }
 
+/// Insert unsigned 64 Bit integers exceeding the range of signed 64 Bit integers
 #[test]
-fn fallibale_allocations() {
-    // Given
+fn insert_uint64_into_decimal() {
+    // Given a table with a DECIMAL column wide enough for any unsigned 64 Bit integer
     let table_name = function_name!().rsplit_once(':').unwrap().1;
     let conn = ENV
         .connect_with_connection_string(MSSQL, Default::default())
         .unwrap();
-    setup_empty_table(&conn, table_name, &["VARBINARY(4096)"]).unwrap();
-    let sql = format!("SELECT a FROM {table_name}");
-    let cursor = conn.execute(&sql, ()).unwrap().unwrap();
+    setup_empty_table(&conn, table_name, &["DECIMAL(20,0)"]).unwrap();
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::UInt64, true)]));
+    let array = UInt64Array::from(vec![Some(1), Some(u64::MAX), None]);
+    let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(array)]).unwrap();
 
     // When
-    let result = OdbcReaderBuilder::new()
-        .with_max_bytes_per_batch(usize::MAX)
-        .with_max_num_rows_per_batch(100_000_000)
-        .with_fallibale_allocations(true)
-        .build(cursor);
+    let mut writer = OdbcWriter::with_connection(&conn, &schema, table_name, 10).unwrap();
+    writer.write_batch(&batch).unwrap();
+    writer.flush().unwrap();
 
     // Then
-    // In particular we do **not** get either a zero sized column or out of memory error.
-    assert!(result.is_err());
-    assert!(matches!(
-        result.err().unwrap(),
-        Error::ColumnFailure {
-            name: _,
-            index: 0,
-            source: ColumnFailure::TooLarge {
-                num_elements: 100_000_000,
-                element_size: 4096
-            }
-        }
-    ));
+    let actual = table_to_string(&conn, table_name, &["a"]);
+    assert_eq!("1\n18446744073709551615\nNULL", actual);
 }
 
+/// Insert decimals into a floating point column as 64 Bit floats
 #[test]
-fn read_multiple_result_sets() {
-    // Given a cursor returning two result sets
+fn insert_decimal_as_float() {
+    // Given a table with a FLOAT column
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
     let conn = ENV
         .connect_with_connection_string(MSSQL, Default::default())
         .unwrap();
-    let cursor = conn
-        .execute("SELECT 1 AS A; SELECT 2 AS B;", ())
-        .unwrap()
+    setup_empty_table(&conn, table_name, &["FLOAT"]).unwrap();
+    let schema = Arc::new(Schema::new(vec![Field::new(
+        "a",
+        DataType::Decimal128(5, 2),
+        true,
+    )]));
+    let array = Decimal128Array::from(vec![Some(12345), Some(-50), None])
+        .with_precision_and_scale(5, 2)
         .unwrap();
+    let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(array)]).unwrap();
 
-    // When
-    // Now that we have a cursor, we want to iterate over its rows and fill an arrow batch with it.
-    let mut reader = OdbcReaderBuilder::new()
-        .with_max_num_rows_per_batch(1)
-        .build(cursor)
-        .unwrap();
-    let first = reader.next().unwrap().unwrap();
-    let cursor = reader.into_cursor().unwrap();
-    let cursor = cursor.more_results().unwrap().unwrap();
-    // Now that we have a cursor, we want to iterate over its rows and fill an arrow batch with it.
-    let mut reader = OdbcReaderBuilder::new()
-        .with_max_num_rows_per_batch(1)
-        .build(cursor)
+    // When inserting the decimals as floats
+    let mut writer = OdbcWriterBuilder::new()
+        .with_decimal_as_float(true)
+        .build_with_connection(&conn, &schema, table_name, 10)
         .unwrap();
-    let second = reader.next().unwrap().unwrap();
+    writer.write_batch(&batch).unwrap();
+    writer.flush().unwrap();
 
     // Then
-    let first_vals = first
-        .column(0)
-        .as_any()
-        .downcast_ref::<Int32Array>()
-        .unwrap();
-    assert_eq!(1, first_vals.value(0));
-    let second_vals = second
+    let sql = format!("SELECT a FROM {table_name} ORDER BY id");
+    let cursor = conn.execute(&sql, ()).unwrap().unwrap();
+    let mut reader = OdbcReaderBuilder::new().build(cursor).unwrap();
+    let arrow_batch = reader.next().unwrap().unwrap();
+    let array_vals = arrow_batch
         .column(0)
         .as_any()
-        .downcast_ref::<Int32Array>()
+        .downcast_ref::<Float64Array>()
         .unwrap();
-    assert_eq!(2, second_vals.value(0));
+    assert_float_eq!(123.45, array_vals.value(0), abs <= 1e-9);
+    assert_float_eq!(-0.5, array_vals.value(1), abs <= 1e-9);
+    assert!(array_vals.is_null(2));
 }
 
+/// Insert batches, which are already in memory, without wrapping them in a reader
 #[test]
-fn read_multiple_result_sets_with_second_no_schema() {
-    // Given a batch of three SQL statements, the second being result-free
+fn insert_batches_from_slice() {
+    // Given a table and two batches
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
     let conn = ENV
         .connect_with_connection_string(MSSQL, Default::default())
         .unwrap();
-    let cursor = conn
-        .execute(
-            "SELECT 1 AS A; SELECT 1 AS A INTO #local_temp_table; SELECT A FROM #local_temp_table;",
-            (),
-        )
-        .unwrap()
-        .unwrap();
-
-    // When
-    // Now that we have a cursor, we want to iterate over its rows and fill an arrow batch with it.
-    let mut reader = OdbcReaderBuilder::new()
-        .with_max_num_rows_per_batch(1)
-        .build(cursor)
-        .unwrap();
-    let first = reader.next().unwrap().unwrap();
-    let cursor = reader.into_cursor().unwrap();
-    let cursor = cursor.more_results().unwrap().unwrap();
-    // Now that we have a cursor, we want to iterate over its rows and fill an arrow batch with it.
-    let reader = OdbcReaderBuilder::new()
-        .with_max_num_rows_per_batch(1)
-        .build(cursor)
-        .unwrap();
-    let second_schema = reader.schema();
+    setup_empty_table(&conn, table_name, &["INTEGER"]).unwrap();
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+    let first = RecordBatch::try_new(
+        schema.clone(),
+        vec![Arc::new(Int32Array::from(vec![Some(1), Some(2)]))],
+    )
+    .unwrap();
+    let second = RecordBatch::try_new(
+        schema.clone(),
+        vec![Arc::new(Int32Array::from(vec![Some(3), None, Some(5)]))],
+    )
+    .unwrap();
 
-    let cursor = reader.into_cursor().unwrap();
-    let cursor = cursor.more_results().unwrap().unwrap();
-    // Now that we have a cursor, we want to iterate over its rows and fill an arrow batch with it.
-    let mut reader = OdbcReaderBuilder::new()
-        .with_max_num_rows_per_batch(1)
-        .build(cursor)
-        .unwrap();
-    let third = reader.next().unwrap().unwrap();
+    // When inserting them in chunks of two rows
+    insert_batches(&conn, &schema, &[first, second], table_name, 2).unwrap();
 
-    // Then
-    let first_vals = first
-        .column(0)
-        .as_any()
-        .downcast_ref::<Int32Array>()
-        .unwrap();
-    assert_eq!(1, first_vals.value(0));
-    let second_column_count = second_schema.fields().len();
-    assert_eq!(0, second_column_count);
-    let third_vals = third
-        .column(0)
-        .as_any()
-        .downcast_ref::<Int32Array>()
-        .unwrap();
-    assert_eq!(1, third_vals.value(0));
+    // Then
+    let actual = table_to_string(&conn, table_name, &["a"]);
+    assert_eq!("1\n2\n3\nNULL\n5", actual);
 }
 
+/// Insert two arrow columns into explicitly named columns of a wider table
 #[test]
-fn applies_row_limit_for_default_constructed_readers() {
-    // Given a cursor over a datascheme with a small per row memory footprint
+fn insert_into_subset_of_columns() {
+    // Given a table with an identity column, a column with a default value and two more columns
     let table_name = function_name!().rsplit_once(':').unwrap().1;
-    let cursor = cursor_over(table_name, "INTEGER", "(42)");
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    setup_empty_table(
+        &conn,
+        table_name,
+        &["VARCHAR(10)", "INTEGER DEFAULT 42", "INTEGER"],
+    )
+    .unwrap();
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("number", DataType::Int32, false),
+        Field::new("text", DataType::Utf8, false),
+    ]));
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            Arc::new(Int32Array::from(vec![1, 2])),
+            Arc::new(StringArray::from(vec!["one", "two"])),
+        ],
+    )
+    .unwrap();
 
-    // When constructing a reader from that cursor without specifying an explicity memory or row
-    // limit
-    let reader = OdbcReaderBuilder::new().build(cursor).unwrap();
+    // When inserting the fields into the columns `c` and `a`
+    let mut writer =
+        OdbcWriter::with_connection_for_columns(&conn, &schema, table_name, &["c", "a"], 5)
+            .unwrap();
+    writer.write_batch(&batch).unwrap();
+    writer.flush().unwrap();
 
-    // Then the row limit is set to u16::MAX
-    assert_eq!(reader.max_rows_per_batch(), 65535)
+    // Then the identity and the default value are filled in by the database
+    let actual = table_to_string(&conn, table_name, &["id", "a", "b", "c"]);
+    assert_eq!("1,one,42,1\n2,two,42,2", actual);
 }
 
+/// Rows are upserted using a `MERGE` statement generated from a template
 #[test]
-fn applies_memory_size_limit() {
-    // Given a cursor over a datascheme with a small per row memory footprint
+fn upsert_with_statement_template() {
+    // Given a table and a template merging rows on column `a`
     let table_name = function_name!().rsplit_once(':').unwrap().1;
-    let cursor = cursor_over(table_name, "VARCHAR(512)", "('Hello')");
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["INTEGER", "VARCHAR(10)"]).unwrap();
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b", DataType::Utf8, false),
+    ]));
+    let template = "MERGE INTO {table} AS target \
+        USING (VALUES ({placeholders})) AS source ({columns}) ON target.a = source.a \
+        WHEN MATCHED THEN UPDATE SET b = source.b \
+        WHEN NOT MATCHED THEN INSERT ({columns}) VALUES (source.a, source.b);";
+    let batch = |b: &str| {
+        RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int32Array::from(vec![1])),
+                Arc::new(StringArray::from(vec![b])),
+            ],
+        )
+        .unwrap()
+    };
 
-    // When constructing a reader from that cursor without specifying an explicity memory or row
-    // limit
-    let reader = OdbcReaderBuilder::new()
-        // Limit buffer to 10 MiB
-        .with_max_bytes_per_batch(10 * 1024 * 1024)
-        .build(cursor)
+    // When writing a row and overwriting it afterwards
+    let mut writer =
+        OdbcWriter::with_statement_template(&conn, &schema, table_name, template, 5).unwrap();
+    writer.write_batch(&batch("first")).unwrap();
+    writer.flush().unwrap();
+    writer.write_batch(&batch("second")).unwrap();
+    writer.flush().unwrap();
+
+    // Then
+    let actual = table_to_string(&conn, table_name, &["a", "b"]);
+    assert_eq!("1,second", actual);
+}
+
+/// A template repeating the placeholders would bind the parameters of each field more than once
+#[test]
+fn reject_statement_template_with_repeated_placeholders() {
+    // Given
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
         .unwrap();
+    setup_empty_table(&conn, table_name, &["INTEGER"]).unwrap();
+    let schema = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
+    let template = "INSERT INTO {table} ({columns}) VALUES ({placeholders}), ({placeholders})";
 
-    // Buffer now holds less than 65535 rows due to size limit
-    assert!(reader.max_rows_per_batch() < 65535)
+    // When
+    let result = OdbcWriter::with_statement_template(&conn, &schema, table_name, template, 5);
+
+    // Then
+    assert!(matches!(
+        result,
+        Err(WriterError::InvalidStatementTemplate { occurrences: 2 })
+    ));
 }
 
+/// The number of column names must match the number of fields
 #[test]
-fn memory_size_limit_can_not_hold_a_single_row() {
-    // Given a cursor over a datascheme with a small per row memory footprint
+fn insert_into_subset_of_columns_with_wrong_number_of_names() {
+    // Given a table and a schema with two fields
     let table_name = function_name!().rsplit_once(':').unwrap().1;
-    let cursor = cursor_over(table_name, "VARCHAR(512)", "('Hello')");
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["INTEGER", "INTEGER"]).unwrap();
+    let schema = Schema::new(vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b", DataType::Int32, false),
+    ]);
 
-    // When constructing a reader from that cursor without specifying an explicity memory or row
-    // limit
-    let result = OdbcReaderBuilder::new()
-        // Limit buffer to 1 Byte
-        .with_max_bytes_per_batch(1)
-        .build(cursor);
+    // When naming only one column
+    let result = OdbcWriter::with_connection_for_columns(&conn, &schema, table_name, &["a"], 5);
 
     // Then
     assert!(matches!(
         result,
-        Err(Error::OdbcBufferTooSmall {
-            max_bytes_per_batch: 1,
-            bytes_per_row: _
+        Err(WriterError::ColumnCountMismatch {
+            num_fields: 2,
+            num_columns: 1
         })
-    ))
+    ));
 }
 
+/// Inserting reports the total number of rows sent to the database
 #[test]
-fn insert_does_not_support_list_type() {
-    // Given a table and a db connection.
+fn insert_into_table_counted_returns_number_of_rows() {
+    // Given a table and three batches with two, three and one rows
     let table_name = function_name!().rsplit_once(':').unwrap().1;
     let conn = ENV
         .connect_with_connection_string(MSSQL, Default::default())
         .unwrap();
-    setup_empty_table(&conn, table_name, &["VARCHAR(4096)"]).unwrap();
+    setup_empty_table(&conn, table_name, &["INTEGER"]).unwrap();
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+    let batches = [vec![1, 2], vec![3, 4, 5], vec![6]]
+        .into_iter()
+        .map(|values| {
+            RecordBatch::try_new(schema.clone(), vec![Arc::new(Int32Array::from(values))]).unwrap()
+        })
+        .collect();
+    let mut reader = StubBatchReader::new(schema, batches);
 
-    // When we try to create an OdbcWriter inserting an Arrow List
-    let schema = Arc::new(Schema::new(vec![Field::new(
-        "a",
-        DataType::List(Arc::new(Field::new("b", DataType::Utf8, true))),
-        true,
-    )]));
+    // When inserting them in chunks of four rows
+    let num_rows = insert_into_table_counted(&conn, &mut reader, table_name, 4).unwrap();
 
-    let insert = format!("INSERT INTO {table_name} (a) VALUES (?)");
-    let prepared = conn.prepare(&insert).unwrap();
-    let result = OdbcWriter::new(10, schema.as_ref(), prepared);
+    // Then
+    assert_eq!(6, num_rows);
+    let actual = table_to_string(&conn, table_name, &["a"]);
+    assert_eq!("1\n2\n3\n4\n5\n6", actual);
+}
 
-    // Then we recive an unsupported error
-    assert!(matches!(
-        result,
-        Err(WriterError::UnsupportedArrowDataType(_))
-    ))
+#[test]
+fn copy_cursor_to_table_between_connections() {
+    // Given a source table with integer and text values, and an empty target table
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let source_table = format!("{table_name}_source");
+    let target_table = format!("{table_name}_target");
+    let source = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    let target = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    setup_empty_table(&source, &source_table, &["INTEGER", "VARCHAR(50)"]).unwrap();
+    setup_empty_table(&target, &target_table, &["INTEGER", "VARCHAR(50)"]).unwrap();
+    let insert =
+        format!("INSERT INTO {source_table} (a, b) VALUES (1, 'one'), (NULL, 'two'), (3, NULL)");
+    source.execute(&insert, ()).unwrap();
+
+    // When copying the result set of the source table into the target table
+    let query = format!("SELECT a, b FROM {source_table} ORDER BY id");
+    let cursor = source.execute(&query, ()).unwrap().unwrap();
+    let reader = OdbcReaderBuilder::new()
+        .with_max_num_rows_per_batch(2)
+        .build(cursor)
+        .unwrap();
+    let num_rows = copy_cursor_to_table(reader, &target, &target_table, 2).unwrap();
+
+    // Then the target table holds the same contents
+    assert_eq!(3, num_rows);
+    let expected = table_to_string(&source, &source_table, &["a", "b"]);
+    let actual = table_to_string(&target, &target_table, &["a", "b"]);
+    assert_eq!("1,one\nNULL,two\n3,NULL", actual);
+    assert_eq!(expected, actual);
 }
 
+/// Batches not matching the schema are rejected before anything is inserted
 #[test]
-fn insert_text() {
-    // Given a table and a record batch reader returning a batch with a text column.
+fn insert_batches_with_mismatching_schema() {
+    // Given a table and two batches, the second one with a different data type
     let table_name = function_name!().rsplit_once(':').unwrap().1;
     let conn = ENV
         .connect_with_connection_string(MSSQL, Default::default())
         .unwrap();
-    setup_empty_table(&conn, table_name, &["VARCHAR(4096)"]).unwrap();
-    let array = StringArray::from(vec![Some("Hello"), None, Some("World")]);
-    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Utf8, true)]));
-    let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(array)]).unwrap();
-    let mut reader = StubBatchReader::new(schema, vec![batch]);
+    setup_empty_table(&conn, table_name, &["INTEGER"]).unwrap();
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+    let first = RecordBatch::try_new(
+        schema.clone(),
+        vec![Arc::new(Int32Array::from(vec![Some(1)]))],
+    )
+    .unwrap();
+    let other_schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, true)]));
+    let second = RecordBatch::try_new(
+        other_schema,
+        vec![Arc::new(Int64Array::from(vec![Some(2)]))],
+    )
+    .unwrap();
 
     // When
-    insert_into_table(&conn, &mut reader, table_name, 5).unwrap();
+    let result = insert_batches(&conn, &schema, &[first, second], table_name, 10);
 
     // Then
+    assert!(matches!(
+        result,
+        Err(WriterError::BatchSchemaMismatch { batch_index: 1 })
+    ));
     let actual = table_to_string(&conn, table_name, &["a"]);
-    let expected = "Hello\nNULL\nWorld";
-    assert_eq!(expected, actual);
+    assert_eq!("", actual);
 }
 
 /// Insert multiple batches into the database using only one roundtrip.
@@ -1725,6 +4893,93 @@ fn insert_timestamp_with_nanoseconds_precisions() {
     assert_eq!(expected, actual);
 }
 
+/// Fractions finer than the chosen precision are truncated
+#[test]
+fn insert_timestamp_with_custom_precision() {
+    // Given a table and a record batch with a nanosecond timestamp
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["DATETIME2(7)"]).unwrap();
+    let schema = Arc::new(Schema::new(vec![Field::new(
+        "a",
+        DataType::Timestamp(TimeUnit::Nanosecond, None),
+        false,
+    )]));
+    // Corresponds to single element array with entry 1970-05-09T14:25:11.111111111
+    let array = TimestampNanosecondArray::from(vec![11111111111111111]);
+    let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(array)]).unwrap();
+
+    // When inserting with a precision of three fractional digits
+    let mut writer = OdbcWriterBuilder::new()
+        .with_timestamp_precision(3)
+        .build_with_connection(&conn, &schema, table_name, 10)
+        .unwrap();
+    writer.write_batch(&batch).unwrap();
+    writer.flush().unwrap();
+
+    // Then
+    let actual = table_to_string(&conn, table_name, &["a"]);
+    let expected = "1970-05-09 14:25:11.1110000";
+    assert_eq!(expected, actual);
+}
+
+/// Nanosecond timestamps with a precision of 9 are bound as text, without losing digits
+#[test]
+fn insert_timestamp_with_nanoseconds_precision_nine() {
+    // Given a text column, so the database does not truncate the value
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["VARCHAR(30)"]).unwrap();
+    let schema = Arc::new(Schema::new(vec![Field::new(
+        "a",
+        DataType::Timestamp(TimeUnit::Nanosecond, None),
+        true,
+    )]));
+    let array = TimestampNanosecondArray::from(vec![Some(11111111111111111), None]);
+    let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(array)]).unwrap();
+
+    // When
+    let mut writer = OdbcWriterBuilder::new()
+        .with_timestamp_precision(9)
+        .build_with_connection(&conn, &schema, table_name, 10)
+        .unwrap();
+    writer.write_batch(&batch).unwrap();
+    writer.flush().unwrap();
+
+    // Then
+    let actual = table_to_string(&conn, table_name, &["a"]);
+    let expected = "1970-05-09 14:25:11.111111111\nNULL";
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn reject_timestamp_precision_above_nine() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["DATETIME2"]).unwrap();
+    let schema = Schema::new(vec![Field::new(
+        "a",
+        DataType::Timestamp(TimeUnit::Nanosecond, None),
+        true,
+    )]);
+
+    let result = OdbcWriterBuilder::new()
+        .with_timestamp_precision(10)
+        .build_with_connection(&conn, &schema, table_name, 10)
+        .map(|_| ());
+
+    assert!(matches!(
+        result,
+        Err(WriterError::InvalidTimestampPrecision(10))
+    ));
+}
+
 #[test]
 fn insert_date32_array() {
     // Given a table and a record batch reader returning a batch with a text column.
@@ -1924,12 +5179,131 @@ fn insert_fixed_binary() {
     let mut reader = StubBatchReader::new(schema, vec![batch]);
 
     // When
-    insert_into_table(&conn, &mut reader, table_name, 5).unwrap();
+    insert_into_table(&conn, &mut reader, table_name, 5).unwrap();
+
+    // Then
+    let actual = table_to_string(&conn, table_name, &["a"]);
+    let expected = "0102\nNULL\n0304050607";
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn insert_large_binary() {
+    // Given a table and a record batch reader returning a batch with a large binary column.
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["VARBINARY(50)"]).unwrap();
+    let array = LargeBinaryArray::from(vec![
+        Some([1, 2].as_slice()),
+        None,
+        Some([3, 4, 5, 6, 7].as_slice()),
+    ]);
+    let schema = Arc::new(Schema::new(vec![Field::new(
+        "a",
+        DataType::LargeBinary,
+        true,
+    )]));
+    let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(array)]).unwrap();
+    let mut reader = StubBatchReader::new(schema, vec![batch]);
+
+    // When
+    insert_into_table(&conn, &mut reader, table_name, 5).unwrap();
+
+    // Then
+    let actual = table_to_string(&conn, table_name, &["a"]);
+    let expected = "0102\nNULL\n0304050607";
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn insert_null_array() {
+    // Given a table and a record batch reader returning a batch with an arrow `Null` column.
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["INTEGER"]).unwrap();
+    let array = NullArray::new(3);
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Null, true)]));
+    let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(array)]).unwrap();
+    let mut reader = StubBatchReader::new(schema, vec![batch]);
+
+    // When
+    insert_into_table(&conn, &mut reader, table_name, 5).unwrap();
+
+    // Then
+    let actual = table_to_string(&conn, table_name, &["a"]);
+    let expected = "NULL\nNULL\nNULL";
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn insert_dictionary_encoded_arrays() {
+    // Given a table and a record batch reader returning dictionary encoded strings and integers
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["VARCHAR(10)", "BIGINT"]).unwrap();
+    let text: DictionaryArray<Int32Type> = vec![Some("red"), None, Some("blue"), Some("red")]
+        .into_iter()
+        .collect();
+    let numbers = DictionaryArray::<Int32Type>::try_new(
+        Int32Array::from(vec![Some(1), Some(0), Some(1), None]),
+        Arc::new(Int64Array::from(vec![-5, 42])),
+    )
+    .unwrap();
+    let schema = Arc::new(Schema::new(vec![
+        Field::new(
+            "a",
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
+            true,
+        ),
+        Field::new(
+            "b",
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Int64)),
+            true,
+        ),
+    ]));
+    let batch =
+        RecordBatch::try_new(schema.clone(), vec![Arc::new(text), Arc::new(numbers)]).unwrap();
+    let mut reader = StubBatchReader::new(schema, vec![batch]);
+
+    // When
+    insert_into_table(&conn, &mut reader, table_name, 5).unwrap();
+
+    // Then the values are inserted, rather than the keys
+    let actual = table_to_string(&conn, table_name, &["a", "b"]);
+    let expected = "red,42\nNULL,-5\nblue,42\nred,NULL";
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn insert_large_binary_exceeding_column_size() {
+    // Given a table and a record batch reader returning a value too large for the column
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["VARBINARY(2)"]).unwrap();
+    let array = LargeBinaryArray::from(vec![Some([1, 2, 3, 4, 5].as_slice())]);
+    let schema = Arc::new(Schema::new(vec![Field::new(
+        "a",
+        DataType::LargeBinary,
+        true,
+    )]));
+    let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(array)]).unwrap();
+    let mut reader = StubBatchReader::new(schema, vec![batch]);
+
+    // When
+    let result = insert_into_table(&conn, &mut reader, table_name, 5);
 
-    // Then
+    // Then the value is not silently truncated, but the insert fails
+    assert!(matches!(result, Err(WriterError::ExecuteStatment(_))));
     let actual = table_to_string(&conn, table_name, &["a"]);
-    let expected = "0102\nNULL\n0304050607";
-    assert_eq!(expected, actual);
+    assert_eq!("", actual);
 }
 
 #[test]
@@ -1961,6 +5335,81 @@ fn insert_decimal_128() {
     assert_eq!(expected, actual);
 }
 
+/// Round decimals to the scale of the target column before inserting them
+#[test]
+fn insert_decimal_with_smaller_target_scale() {
+    // Given a table with a scale of one and decimals with a scale of three
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    let array: Decimal128Array = [Some(1250), Some(1350), Some(-1260), None]
+        .into_iter()
+        .collect();
+    let array = array.with_precision_and_scale(5, 3).unwrap();
+    let schema = Arc::new(Schema::new(vec![Field::new(
+        "a",
+        DataType::Decimal128(5, 3),
+        true,
+    )]));
+    let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(array)]).unwrap();
+
+    // When inserting the batch with either policy
+    let insert = |policy| {
+        let table_name = format!("{table_name}_{policy:?}");
+        setup_empty_table(&conn, &table_name, &["NUMERIC(5,1)"]).unwrap();
+        let mut writer = OdbcWriterBuilder::new()
+            .with_decimal_scale("a", 1, policy)
+            .build_with_connection(&conn, &schema, &table_name, 10)
+            .unwrap();
+        writer
+            .write_batch(&batch)
+            .and_then(|()| writer.flush())
+            .map(|()| table_to_string(&conn, &table_name, &["a"]))
+    };
+    let rounded = insert(DecimalInsertPolicy::RoundHalfEven);
+    let truncated = insert(DecimalInsertPolicy::Truncate);
+    let rejected = insert(DecimalInsertPolicy::Error);
+
+    // Then
+    assert_eq!("1.2\n1.4\n-1.3\nNULL", rounded.unwrap());
+    assert_eq!("1.2\n1.3\n-1.2\nNULL", truncated.unwrap());
+    assert!(matches!(
+        rejected,
+        Err(WriterError::DecimalScaleExceeded { value, scale: 1 }) if value == "1.250"
+    ));
+}
+
+/// The target scale only applies to the column it has been specified for
+#[test]
+fn insert_decimal_with_target_scale_for_one_column() {
+    // Given a table with a scale of one for the first column and a scale of three for the second
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["NUMERIC(5,1)", "NUMERIC(5,3)"]).unwrap();
+    let array: Decimal128Array = [Some(1250)].into_iter().collect();
+    let array = Arc::new(array.with_precision_and_scale(5, 3).unwrap());
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("a", DataType::Decimal128(5, 3), true),
+        Field::new("b", DataType::Decimal128(5, 3), true),
+    ]));
+    let batch = RecordBatch::try_new(schema.clone(), vec![array.clone(), array]).unwrap();
+
+    // When truncating only the first column
+    let mut writer = OdbcWriterBuilder::new()
+        .with_decimal_scale("a", 1, DecimalInsertPolicy::Truncate)
+        .build_with_connection(&conn, &schema, table_name, 10)
+        .unwrap();
+    writer.write_batch(&batch).unwrap();
+    writer.flush().unwrap();
+
+    // Then
+    let actual = table_to_string(&conn, table_name, &["a", "b"]);
+    assert_eq!("1.2,1.250", actual);
+}
+
 #[test]
 fn insert_decimal_256() {
     // Given a table and a record batch reader returning a batch with a text column.
@@ -2163,6 +5612,41 @@ fn fetch_integer_concurrently() {
     assert_eq!([1, 0, 3], *array_vals.values());
 }
 
+/// Split the byte budget between the two transit buffers of a concurrent reader
+#[test]
+fn byte_budget_includes_concurrent_buffer() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a budget of 120 bytes, enough for 10 rows of nullable 32 Bit integers
+    let cursor = cursor_over(table_name, "INTEGER", "(1),(2),(3),(4),(5),(6),(7)");
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    let sequential_cursor = conn
+        .into_cursor(&format!("SELECT a FROM {table_name}"), ())
+        .unwrap()
+        .unwrap();
+    let mut builder = OdbcReaderBuilder::new();
+    builder.with_max_bytes_per_batch(120);
+
+    // When
+    let sequential = builder.build(sequential_cursor).unwrap();
+    let reader = builder
+        .with_total_byte_budget_includes_concurrency(true)
+        .build(cursor)
+        .unwrap();
+    let rows_per_buffer = reader.max_rows_per_batch();
+    let batch_sizes: Vec<_> = reader
+        .into_concurrent()
+        .unwrap()
+        .map(|batch| batch.unwrap().num_rows())
+        .collect();
+
+    // Then
+    assert_eq!(10, sequential.max_rows_per_batch());
+    assert_eq!(5, rows_per_buffer);
+    assert_eq!(vec![5, 2], batch_sizes);
+}
+
 #[test]
 fn fetch_empty_cursor_concurrently() {
     let table_name = function_name!().rsplit_once(':').unwrap().1;
@@ -2201,6 +5685,395 @@ fn fetch_with_error_concurrently() {
     assert!(record_batch.is_err())
 }
 
+/// After emitting an error the concurrent reader ends the iteration. The error can be downcast to
+/// the error of the ODBC data source.
+#[test]
+fn iterate_concurrent_reader_after_error() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a concurrent reader, which fails with a truncation error
+    let cursor = cursor_over(table_name, "VARCHAR(50)", "('Hello, World!'),('Hello')");
+    let mut reader = OdbcReaderBuilder::new()
+        .with_max_num_rows_per_batch(1)
+        .with_max_text_size(1)
+        .build(cursor)
+        .unwrap()
+        .into_concurrent()
+        .unwrap();
+
+    // When iterating past the error
+    let first = reader.next().unwrap();
+    let second = reader.next();
+    let third = reader.next();
+
+    // Then the error is emitted once and the iteration ends afterwards
+    let Err(ArrowError::ExternalError(source)) = first else {
+        panic!("Expected external error");
+    };
+    assert!(source.downcast_ref::<odbc_api::Error>().is_some());
+    assert!(second.is_none());
+    assert!(third.is_none());
+}
+
+/// Fetch fewer rows from the data source at once, than each record batch holds
+#[test]
+fn accumulate_fetches_smaller_than_batch() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a result set with 100 rows
+    let values = (1..=100)
+        .map(|i| format!("({i})"))
+        .collect::<Vec<_>>()
+        .join(",");
+    let cursor = cursor_over(table_name, "INTEGER", &values);
+
+    // When fetching ten rows at once, into batches of 100 rows
+    let reader = OdbcReaderBuilder::new()
+        .with_max_num_rows_per_batch(100)
+        .with_odbc_fetch_rows(10)
+        .build(cursor)
+        .unwrap();
+    let max_rows_per_batch = reader.max_rows_per_batch();
+    let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+
+    // Then a single batch with all rows is emitted
+    assert_eq!(100, max_rows_per_batch);
+    assert_eq!(1, batches.len());
+    let array_vals = batches[0]
+        .column(0)
+        .as_any()
+        .downcast_ref::<Int32Array>()
+        .unwrap();
+    assert_eq!((1..=100).collect::<Vec<_>>(), array_vals.values().to_vec());
+}
+
+/// The progress callback is invoked after each row group with the number of rows fetched so far
+#[test]
+fn report_progress_for_each_row_group() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a result set with five rows, fetched in row groups of two
+    let cursor = cursor_over(table_name, "INTEGER", "(1),(2),(3),(4),(5)");
+    let reported = Arc::new(Mutex::new(Vec::new()));
+    let sink = reported.clone();
+
+    // When iterating the reader
+    let reader = OdbcReaderBuilder::new()
+        .with_max_num_rows_per_batch(2)
+        .with_progress_callback(move |num_rows| sink.lock().unwrap().push(num_rows))
+        .build(cursor)
+        .unwrap();
+    for batch in reader {
+        batch.unwrap();
+    }
+
+    // Then the cumulative number of rows is reported once for each row group
+    assert_eq!([2, 4, 5], reported.lock().unwrap().as_slice());
+}
+
+/// The progress callback is invoked by the concurrent reader, too
+#[test]
+fn report_progress_of_concurrent_reader() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a result set with five rows, fetched concurrently in row groups of two
+    let cursor = cursor_over(table_name, "INTEGER", "(1),(2),(3),(4),(5)");
+    let reported = Arc::new(Mutex::new(Vec::new()));
+    let sink = reported.clone();
+
+    // When iterating the reader
+    let reader = OdbcReaderBuilder::new()
+        .with_max_num_rows_per_batch(2)
+        .with_progress_callback(move |num_rows| sink.lock().unwrap().push(num_rows))
+        .build(cursor)
+        .unwrap()
+        .into_concurrent()
+        .unwrap();
+    for batch in reader {
+        batch.unwrap();
+    }
+
+    // Then the cumulative number of rows is reported once for each row group
+    assert_eq!([2, 4, 5], reported.lock().unwrap().as_slice());
+}
+
+/// Calling `into_cursor` on a concurrent reader, while the fetch thread still has row groups to
+/// fetch, must not deadlock.
+#[test]
+fn concurrent_reader_into_cursor_after_partial_consumption() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a batch of two statements, the first one producing several row groups
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, ConnectionOptions::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["INTEGER"]).unwrap();
+    let insert = format!("INSERT INTO {table_name} (a) VALUES (1),(2),(3),(4),(5)");
+    conn.execute(&insert, ()).unwrap();
+    let sql = format!("SELECT a FROM {table_name}; SELECT 42 AS b;");
+    let cursor = conn.into_cursor(&sql, ()).unwrap().unwrap();
+    let mut reader = OdbcReaderBuilder::new()
+        .with_max_num_rows_per_batch(1)
+        .build(cursor)
+        .unwrap()
+        .into_concurrent()
+        .unwrap();
+
+    // When reading only the first row group, before taking back the cursor
+    reader.next().unwrap().unwrap();
+    let cursor = reader.into_cursor().unwrap();
+
+    // Then the cursor moves on to the next result set
+    let cursor = cursor.more_results().unwrap().unwrap();
+    let mut reader = OdbcReaderBuilder::new().build(cursor).unwrap();
+    let batch = reader.next().unwrap().unwrap();
+    let array_vals = batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<Int32Array>()
+        .unwrap();
+    assert_eq!(42, array_vals.value(0));
+}
+
+/// Poll a small result set to completion using the asynchronous reader
+#[test]
+fn fetch_batches_asynchronously() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a table with three rows
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, ConnectionOptions::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["INTEGER"]).unwrap();
+    let sql = format!("INSERT INTO {table_name} (a) VALUES (1),(2),(3)");
+    conn.execute(&sql, ()).unwrap();
+
+    // When polling the result set in batches of two rows
+    let sleep = || std::future::ready(());
+    let values = block_on(async {
+        let sql = format!("SELECT a FROM {table_name}");
+        let cursor = conn
+            .execute_polling(&sql, (), sleep)
+            .await
+            .unwrap()
+            .unwrap();
+        let mut reader = OdbcReaderBuilder::new()
+            .with_max_num_rows_per_batch(2)
+            .build_async(cursor)
+            .unwrap();
+        assert_eq!(&DataType::Int32, reader.schema().field(0).data_type());
+        let mut values = Vec::new();
+        while let Some(batch) = reader.next(sleep).await {
+            let batch = batch.unwrap();
+            let array = batch
+                .column(0)
+                .as_any()
+                .downcast_ref::<Int32Array>()
+                .unwrap();
+            values.push(array.values().to_vec());
+        }
+        values
+    });
+
+    // Then
+    assert_eq!(vec![vec![1, 2], vec![3]], values);
+}
+
+/// The asynchronous reader stops after emitting the maximum total number of rows
+#[test]
+fn fetch_batches_asynchronously_with_max_total_rows() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a table with three rows
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, ConnectionOptions::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["INTEGER"]).unwrap();
+    let sql = format!("INSERT INTO {table_name} (a) VALUES (1),(2),(3)");
+    conn.execute(&sql, ()).unwrap();
+
+    // When polling at most two rows
+    let sleep = || std::future::ready(());
+    let num_rows = block_on(async {
+        let sql = format!("SELECT a FROM {table_name}");
+        let cursor = conn
+            .execute_polling(&sql, (), sleep)
+            .await
+            .unwrap()
+            .unwrap();
+        let mut reader = OdbcReaderBuilder::new()
+            .with_max_total_rows(2)
+            .build_async(cursor)
+            .unwrap();
+        let mut num_rows = 0;
+        while let Some(batch) = reader.next(sleep).await {
+            num_rows += batch.unwrap().num_rows();
+        }
+        num_rows
+    });
+
+    // Then
+    assert_eq!(2, num_rows);
+}
+
+/// Options the asynchronous reader does not support are rejected, rather than ignored
+#[test]
+fn build_async_rejects_exact_batch_rows() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, ConnectionOptions::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["INTEGER"]).unwrap();
+
+    // When
+    let sleep = || std::future::ready(());
+    let result = block_on(async {
+        let sql = format!("SELECT a FROM {table_name}");
+        let cursor = conn
+            .execute_polling(&sql, (), sleep)
+            .await
+            .unwrap()
+            .unwrap();
+        OdbcReaderBuilder::new()
+            .with_exact_batch_rows(2)
+            .build_async(cursor)
+            .map(|_| ())
+    });
+
+    // Then
+    assert!(matches!(
+        result,
+        Err(Error::UnsupportedByAsyncReader {
+            option: "with_exact_batch_rows"
+        })
+    ));
+}
+
+/// Merge two readers, each sorted by the same column, into one sorted stream
+#[test]
+fn merge_sorted_readers_by_integer_column() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given two sorted result sets, fetched in batches of two rows
+    let first = cursor_over(&format!("{table_name}_1"), "INTEGER", "(1),(4),(5)");
+    let second = cursor_over(&format!("{table_name}_2"), "INTEGER", "(2),(3),(6),(7)");
+    let readers = [first, second]
+        .into_iter()
+        .map(|cursor| {
+            OdbcReaderBuilder::new()
+                .with_max_num_rows_per_batch(2)
+                .build(cursor)
+                .unwrap()
+        })
+        .collect();
+
+    // When merging them by the sort column
+    let merged = merge_sorted_readers(readers, "a").unwrap();
+    let batches = merged.collect::<Result<Vec<_>, _>>().unwrap();
+
+    // Then all rows are emitted in order, in batches of two rows
+    let values: Vec<i32> = batches
+        .iter()
+        .flat_map(|batch| {
+            let array = batch
+                .column(0)
+                .as_any()
+                .downcast_ref::<Int32Array>()
+                .unwrap();
+            array.values().to_vec()
+        })
+        .collect();
+    assert_eq!(vec![1, 2, 3, 4, 5, 6, 7], values);
+    assert_eq!(4, batches.len());
+}
+
+/// Readers can only be merged by a column which is part of their schema
+#[test]
+fn merge_sorted_readers_by_unknown_column() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a reader
+    let cursor = cursor_over(table_name, "INTEGER", "(1)");
+    let reader = OdbcReaderBuilder::new().build(cursor).unwrap();
+
+    // When merging by a column, which is not part of the schema
+    let result = merge_sorted_readers(vec![reader], "b");
+
+    // Then
+    assert!(matches!(result, Err(Error::MergeSortColumnNotFound { .. })));
+}
+
+/// Transit buffers are returned to the pool and reused by the next query of the same shape
+#[test]
+fn reuse_buffers_from_pool() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a table and an empty buffer pool
+    let conn = ENV
+        .connect_with_connection_string(MSSQL, Default::default())
+        .unwrap();
+    setup_empty_table(&conn, table_name, &["INTEGER"]).unwrap();
+    let sql = format!("INSERT INTO {table_name} (a) VALUES (1),(2)");
+    conn.execute(&sql, ()).unwrap();
+    let pool = BufferPool::new();
+    let query = format!("SELECT a FROM {table_name}");
+
+    // When querying the table twice
+    let cursor = conn.execute(&query, ()).unwrap().unwrap();
+    let mut reader = OdbcReaderBuilder::new()
+        .with_max_num_rows_per_batch(10)
+        .with_buffer_pool(&pool)
+        .build(cursor)
+        .unwrap();
+    reader.next().unwrap().unwrap();
+    drop(reader);
+    let idle_after_first_query = pool.num_idle_buffers();
+    let cursor = conn.execute(&query, ()).unwrap().unwrap();
+    let reader = OdbcReaderBuilder::new()
+        .with_max_num_rows_per_batch(10)
+        .with_buffer_pool(&pool)
+        .build(cursor)
+        .unwrap();
+    let idle_during_second_query = pool.num_idle_buffers();
+    let second: Vec<_> = reader.collect::<Result<_, _>>().unwrap();
+
+    // Then the buffer of the first query is reused for the second
+    assert_eq!(1, idle_after_first_query);
+    assert_eq!(0, idle_during_second_query);
+    assert_eq!(1, pool.num_idle_buffers());
+    let values = second[0]
+        .column(0)
+        .as_any()
+        .downcast_ref::<Int32Array>()
+        .unwrap();
+    assert_eq!([1, 2], *values.values());
+}
+
+/// Batches can be streamed through a bounded channel to a consumer on another thread
+#[test]
+fn stream_batches_through_channel() {
+    let table_name = function_name!().rsplit_once(':').unwrap().1;
+    // Given a table with three rows
+    let cursor = cursor_over(table_name, "INTEGER", "(1),(2),(3)");
+
+    // When streaming it through a channel with capacity for one batch
+    let (receiver, handle) = OdbcReaderBuilder::new()
+        .with_max_num_rows_per_batch(1)
+        .build(cursor)
+        .unwrap()
+        .into_concurrent()
+        .unwrap()
+        .into_channel(1);
+    let values: Vec<i32> = receiver
+        .iter()
+        .map(|batch| {
+            batch
+                .unwrap()
+                .column(0)
+                .as_any()
+                .downcast_ref::<Int32Array>()
+                .unwrap()
+                .value(0)
+        })
+        .collect();
+
+    // Then all batches arrive in order and the named producer thread finishes
+    assert_eq!([1, 2, 3], values[..]);
+    assert_eq!(Some("arrow-odbc-channel"), handle.thread().name());
+    handle.join().unwrap();
+}
+
 #[test]
 fn fetch_row_groups_repeatedly_concurrently() {
     let table_name = function_name!().rsplit_once(':').unwrap().1;
@@ -2337,6 +6210,26 @@ fn concurrent_reader_is_send() {
     assert_eq!([42], *array_vals.values());
 }
 
+/// Minimal executor driving a future to completion on the current thread. Allows testing the
+/// asynchronous reader without depending on an async runtime.
+fn block_on<F: Future>(future: F) -> F::Output {
+    struct ThreadWaker(thread::Thread);
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark()
+        }
+    }
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut context = Context::from_waker(&waker);
+    let mut future = pin!(future);
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
 /// Creates the table and assures it is empty. Columns are named a,b,c, etc.
 fn setup_empty_table(
     conn: &Connection,
@@ -2436,6 +6329,16 @@ fn cursor_over(
     cursor
 }
 
+/// Cursor over a text column, whose first value is not valid UTF-8. The column uses an UTF-8
+/// collation, so the driver passes the bytes through without transcoding them.
+fn invalid_utf8_cursor(table_name: &str) -> CursorImpl<StatementConnection<'static>> {
+    cursor_over(
+        table_name,
+        "VARCHAR(2) COLLATE Latin1_General_100_BIN2_UTF8 NOT NULL",
+        "(CAST(0xFF41 AS VARCHAR(2))),('ok')",
+    )
+}
+
 fn empty_cursor(table_name: &str, column_type: &str) -> CursorImpl<StatementConnection<'static>> {
     // Setup a table on the database
     let conn = ENV